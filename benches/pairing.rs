@@ -0,0 +1,51 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use ff::Field;
+use group::Group;
+use halo2curves::bn256::{Bn256, Fq12, G1Affine, G2Affine, G2Prepared, BN_X, G1, G2};
+use halo2curves::pairing::{MillerLoopResult, MultiMillerLoop};
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+/// Binary exponentiation by x with cyclotomic squarings, as previously used by
+/// the final exponentiation.
+fn exp_by_x_binary(f: &Fq12) -> Fq12 {
+    let mut res = Fq12::ONE;
+    for i in (0..64).rev() {
+        res.cyclotomic_square();
+        if ((BN_X >> i) & 1) == 1 {
+            res *= f;
+        }
+    }
+    res
+}
+
+pub fn final_exponentiation(c: &mut Criterion) {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    // f^((p^6 - 1)(p^2 + 1)) lies in the cyclotomic subgroup.
+    let f = Fq12::random(&mut rng);
+    let mut g = f;
+    g.conjugate();
+    g *= f.invert().unwrap();
+    let mut t = g;
+    t.frobenius_map(2);
+    g *= t;
+
+    let p = G1Affine::from(G1::random(&mut rng));
+    let q = G2Prepared::from(G2Affine::from(G2::random(&mut rng)));
+    let ml = Bn256::multi_miller_loop(&[(&p, &q)]);
+
+    let mut group = c.benchmark_group("Final exponentiation");
+    group.bench_function("exp_by_x binary", |b| b.iter(|| exp_by_x_binary(&g)));
+    group.bench_function("exp_by_x naf", |b| b.iter(|| g.cyclotomic_exp(BN_X)));
+    group.bench_function("final_exponentiation", |b| {
+        b.iter(|| ml.final_exponentiation())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, final_exponentiation);
+criterion_main!(benches);
@@ -4,6 +4,12 @@
 //! This module is temporary, and the extension traits defined here are expected to be
 //! upstreamed into the `ff` and `group` crates after some refactoring.
 
+use core::ops::Neg;
+use ff::{Field, PrimeField};
+use group::Group;
+use pasta_curves::arithmetic::CurveExt;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
 pub trait CurveAffineExt: pasta_curves::arithmetic::CurveAffine {
     fn batch_add<const COMPLETE: bool, const LOAD_POINTS: bool>(
         points: &mut [Self],
@@ -22,6 +28,347 @@ pub trait CurveAffineExt: pasta_curves::arithmetic::CurveAffine {
     }
 }
 
+/// Curves with an efficiently computable endomorphism `CurveExt::endo` acting
+/// as multiplication by a scalar `λ`, used for GLV scalar multiplication.
+///
+/// Only implemented for curves of prime order, where `λ` describes the action
+/// of the endomorphism on every point and not just on a subgroup.
+pub trait CurveEndo: CurveExt {
+    /// Decomposes `k` into `(|k1|, k1 < 0, |k2|, k2 < 0)` such that
+    /// `k = k1 + k2 * λ` with `k1` and `k2` of at most 128 bits.
+    fn decompose_scalar(k: &Self::ScalarExt) -> (u128, Choice, u128, Choice);
+}
+
+/// Parameters of the GLV decomposition of scalars, given by a short basis
+/// `(a1, b1), (a2, b2)` of the lattice `{(x, y) : x + y * λ = 0 (mod r)}`
+/// with `b1 < 0 < b2`.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct EndoParameters {
+    /// `round(2^256 * b2 / r)`
+    pub(crate) gamma1: [u64; 4],
+    /// `round(-2^256 * b1 / r)`
+    pub(crate) gamma2: [u64; 4],
+    /// `a1 mod r`
+    pub(crate) a1: [u64; 4],
+    /// `b1 mod r`
+    pub(crate) b1: [u64; 4],
+    /// `a2 mod r`
+    pub(crate) a2: [u64; 4],
+    /// `b2 mod r`
+    pub(crate) b2: [u64; 4],
+}
+
+/// Computes `round(k * gamma / 2^256)` for 256-bit `k` and `gamma`.
+pub(crate) fn mul_shr_256_round(k: &[u64; 4], gamma: &[u64; 4]) -> [u64; 4] {
+    let mut t = [0u64; 8];
+    for i in 0..4 {
+        let mut carry = 0;
+        for j in 0..4 {
+            let (lo, hi) = mac(t[i + j], k[i], gamma[j], carry);
+            t[i + j] = lo;
+            carry = hi;
+        }
+        t[i + 4] = carry;
+    }
+
+    // Add 2^255 to round to the nearest integer.
+    let (r3, carry) = adc(t[3], 1 << 63, 0);
+    t[3] = r3;
+    let mut carry = carry;
+    for limb in t[4..].iter_mut() {
+        let (r, c) = adc(*limb, 0, carry);
+        *limb = r;
+        carry = c;
+    }
+
+    [t[4], t[5], t[6], t[7]]
+}
+
+/// Width of the signed windows used by constant time scalar multiplication.
+pub(crate) const MUL_WINDOW: usize = 4;
+
+/// Number of signed digits of a 128-bit half of a GLV decomposition, with one
+/// extra digit for the carry out of the top window.
+pub(crate) const MUL_NUM_DIGITS: usize = 128 / MUL_WINDOW + 1;
+
+/// Number of signed digits of a scalar of at most 256 bits, with one extra
+/// digit for the carry out of the top window.
+pub(crate) const MUL_NUM_DIGITS_FULL: usize = 256 / MUL_WINDOW + 1;
+
+/// Recodes the little endian integer `bytes` in constant time into
+/// `digits.len()` signed digits of `window` bits, each in
+/// `[-2^(window - 1), 2^(window - 1)]`, least significant first.
+///
+/// The integer must be below `2^(window * digits.len() - 1)`, so that the top
+/// digit takes the final carry.
+pub(crate) fn recode_signed_ct(bytes: &[u8], window: usize, digits: &mut [i8]) {
+    assert!(0 < window && window < 8, "digits must fit in an i8");
+    let half = 1i16 << (window - 1);
+
+    let mut carry = 0i16;
+    for (w, digit) in digits.iter_mut().enumerate() {
+        let mut bits = 0i16;
+        for i in 0..window {
+            let bit = w * window + i;
+            if let Some(byte) = bytes.get(bit / 8) {
+                bits |= (((byte >> (bit % 8)) & 1) as i16) << i;
+            }
+        }
+        // A digit above half borrows from the next window.
+        let d = bits + carry;
+        carry = (d + half - 1) >> window;
+        *digit = (d - (carry << window)) as i8;
+    }
+}
+
+/// Returns `[[1]P, [2]P, ..., [2^(MUL_WINDOW - 1)]P]`.
+pub(crate) fn mul_table<G: Group>(p: &G) -> [G; 1 << (MUL_WINDOW - 1)] {
+    let mut table = [*p; 1 << (MUL_WINDOW - 1)];
+    for j in 1..table.len() {
+        table[j] = table[j - 1] + p;
+    }
+    table
+}
+
+/// Returns `[digit]P` for a signed digit, given `table = [[1]P, [2]P, ...,
+/// [2^(window - 1)]P]` for the window the digit was recoded with, such as
+/// `mul_table(P)`. `G::default()` must be the identity.
+///
+/// Scans the whole table so that the memory access pattern does not depend on
+/// the digit.
+pub(crate) fn lookup_ct<G: ConditionallySelectable + Default + Neg<Output = G>>(
+    table: &[G],
+    digit: i8,
+) -> G {
+    let sign = (digit as u8) >> 7;
+    let abs = ((digit as u8) ^ 0u8.wrapping_sub(sign)).wrapping_add(sign);
+    let mut t = G::default();
+    for (j, point) in table.iter().enumerate() {
+        t = G::conditional_select(&t, point, abs.ct_eq(&(j as u8 + 1)));
+    }
+    G::conditional_select(&t, &-t, Choice::from(sign))
+}
+
+/// Multiplies `p` by `scalar` in constant time with a signed fixed window of
+/// `MUL_WINDOW` bits, using one addition per window and a constant time table
+/// lookup. This is correct for every point, whatever the order of the group
+/// it lies in.
+pub(crate) fn mul_ct<G: Group + ConditionallySelectable + Default>(p: &G, scalar: &G::Scalar) -> G {
+    let table = mul_table(p);
+    let mut digits = [0i8; MUL_NUM_DIGITS_FULL];
+    recode_signed_ct(scalar.to_repr().as_ref(), MUL_WINDOW, &mut digits);
+
+    let mut acc = G::identity();
+    for digit in digits.iter().rev() {
+        for _ in 0..MUL_WINDOW {
+            acc = acc.double();
+        }
+        acc += lookup_ct(&table, *digit);
+    }
+    acc
+}
+
+/// Width of the NAF used by variable time scalar multiplication.
+const WNAF_WINDOW: usize = 5;
+
+/// Recodes little endian scalar bytes into their width-`w` non-adjacent form,
+/// least significant digit first. Every nonzero digit is odd, lies in
+/// `(-2^(w - 1), 2^(w - 1))` and is followed by at least `w - 1` zeros.
+pub(crate) fn wnaf_form(repr: &[u8], w: usize) -> Vec<i64> {
+    let bit = |i: usize| -> u64 { repr.get(i / 8).map_or(0, |b| ((b >> (i % 8)) & 1) as u64) };
+    let num_bits = repr.len() * 8;
+    let window = 1u64 << w;
+
+    let mut wnaf = Vec::with_capacity(num_bits + 1);
+    let mut carry = 0u64;
+    let mut pos = 0;
+    while pos < num_bits || carry != 0 {
+        if bit(pos) == carry {
+            wnaf.push(0);
+            pos += 1;
+            continue;
+        }
+
+        let mut bits = 0u64;
+        for i in 0..w {
+            bits |= bit(pos + i) << i;
+        }
+        let value = bits + carry;
+        if value >= window / 2 {
+            carry = 1;
+            wnaf.push(value as i64 - window as i64);
+        } else {
+            carry = 0;
+            wnaf.push(value as i64);
+        }
+        wnaf.extend((1..w).map(|_| 0));
+        pos += w;
+    }
+    wnaf
+}
+
+/// Computes `base * scalar` with a width-5 NAF of the scalar and the odd
+/// multiples `base, [3]base, ..., [15]base`.
+///
+/// This is not constant time: the running time and memory access pattern
+/// depend on the scalar, which must therefore be public.
+pub(crate) fn mul_vartime<G: Group>(base: &G, scalar: &G::Scalar) -> G {
+    let double = base.double();
+    let mut odd_multiples = [*base; 1 << (WNAF_WINDOW - 2)];
+    for i in 1..odd_multiples.len() {
+        odd_multiples[i] = odd_multiples[i - 1] + double;
+    }
+
+    let wnaf = wnaf_form(scalar.to_repr().as_ref(), WNAF_WINDOW);
+    let mut acc = G::identity();
+    let mut started = false;
+    for digit in wnaf.iter().rev() {
+        if started {
+            acc = acc.double();
+        }
+        if *digit > 0 {
+            acc += odd_multiples[(*digit / 2) as usize];
+            started = true;
+        } else if *digit < 0 {
+            acc -= odd_multiples[(-*digit / 2) as usize];
+            started = true;
+        }
+    }
+    acc
+}
+
+/// Smallest chunk handed to a thread by `parallelize`, so that small inputs
+/// are not split into chunks which are not worth the overhead.
+#[cfg(feature = "multicore")]
+const MIN_CHUNK_SIZE: usize = 1 << 10;
+
+/// Splits `v` into chunks and calls `f(chunk, start)` on each of them, where
+/// `start` is the index of the first element of `chunk` in `v`.
+///
+/// With the `multicore` feature the chunks are processed on the rayon thread
+/// pool, otherwise `f` is called once on the whole slice.
+pub(crate) fn parallelize<T, F>(v: &mut [T], f: F)
+where
+    T: Send,
+    F: Fn(&mut [T], usize) + Send + Sync + Clone,
+{
+    #[cfg(feature = "multicore")]
+    {
+        let num_threads = rayon::current_num_threads();
+        let chunk_size = core::cmp::max(v.len() / num_threads + 1, MIN_CHUNK_SIZE);
+        rayon::scope(|scope| {
+            for (i, chunk) in v.chunks_mut(chunk_size).enumerate() {
+                let f = f.clone();
+                scope.spawn(move |_| f(chunk, i * chunk_size));
+            }
+        });
+    }
+
+    #[cfg(not(feature = "multicore"))]
+    f(v, 0);
+}
+
+/// In-place inversion of a slice of field elements with Montgomery's trick,
+/// which trades all but one of the inversions for three multiplications each.
+pub trait BatchInvertSlice<F: Field> {
+    /// Inverts every element in place, leaving zeros unchanged, and returns the
+    /// inverse of the product of the nonzero elements.
+    ///
+    /// Runs in constant time with respect to the values of the elements.
+    fn batch_invert(self) -> F;
+
+    /// Same as [`BatchInvertSlice::batch_invert`], but skips zeros with a branch,
+    /// so it must only be used on public values.
+    fn batch_invert_vartime(self) -> F;
+
+    /// Same as [`BatchInvertSlice::batch_invert`], but keeps the prefix products
+    /// in `scratch` rather than in a new allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scratch` is shorter than the slice.
+    fn batch_invert_with_scratch(self, scratch: &mut [F]) -> F;
+
+    /// Same as [`BatchInvertSlice::batch_invert_vartime`], but keeps the prefix
+    /// products in `scratch` rather than in a new allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scratch` is shorter than the slice.
+    fn batch_invert_vartime_with_scratch(self, scratch: &mut [F]) -> F;
+}
+
+impl<F: Field> BatchInvertSlice<F> for &mut [F] {
+    fn batch_invert(self) -> F {
+        let mut scratch = vec![F::ZERO; self.len()];
+        self.batch_invert_with_scratch(&mut scratch)
+    }
+
+    fn batch_invert_vartime(self) -> F {
+        let mut scratch = vec![F::ZERO; self.len()];
+        self.batch_invert_vartime_with_scratch(&mut scratch)
+    }
+
+    fn batch_invert_with_scratch(self, scratch: &mut [F]) -> F {
+        assert!(scratch.len() >= self.len());
+
+        // scratch[i] is the product of the nonzero elements before i.
+        let mut acc = F::ONE;
+        for (x, prefix) in self.iter().zip(scratch.iter_mut()) {
+            *prefix = acc;
+            acc = F::conditional_select(&(acc * x), &acc, x.is_zero());
+        }
+
+        // A product of nonzero elements is nonzero.
+        let product_inv = acc.invert().unwrap();
+
+        let mut acc = product_inv;
+        for (x, prefix) in self.iter_mut().zip(scratch.iter()).rev() {
+            let skip = x.is_zero();
+            let inv = acc * prefix;
+            acc = F::conditional_select(&(acc * *x), &acc, skip);
+            *x = F::conditional_select(&inv, x, skip);
+        }
+        product_inv
+    }
+
+    fn batch_invert_vartime_with_scratch(self, scratch: &mut [F]) -> F {
+        assert!(scratch.len() >= self.len());
+
+        let mut acc = F::ONE;
+        for (x, prefix) in self.iter().zip(scratch.iter_mut()) {
+            *prefix = acc;
+            if !x.is_zero_vartime() {
+                acc *= x;
+            }
+        }
+
+        let product_inv = acc.invert().unwrap();
+
+        let mut acc = product_inv;
+        for (x, prefix) in self.iter_mut().zip(scratch.iter()).rev() {
+            if x.is_zero_vartime() {
+                continue;
+            }
+            let inv = acc * prefix;
+            acc *= *x;
+            *x = inv;
+        }
+        product_inv
+    }
+}
+
+/// Inverts every element of `v` in place using Montgomery's trick, leaving
+/// zeros unchanged. Runs in constant time with respect to the values in `v`.
+///
+/// With the `multicore` feature, `v` is split across threads and each chunk
+/// shares a single field inversion.
+pub fn batch_invert<F: Field>(v: &mut [F]) {
+    parallelize(v, |v, _| {
+        v.batch_invert();
+    });
+}
+
 /// Compute a + b + carry, returning the result and the new carry over.
 #[inline(always)]
 pub(crate) const fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
@@ -42,3 +389,180 @@ pub(crate) const fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
     let ret = (a as u128) + ((b as u128) * (c as u128)) + (carry as u128);
     (ret as u64, (ret >> 64) as u64)
 }
+
+/// Returns `m^-1 mod 2^64` for an odd `m`.
+pub(crate) const fn inv_u64(m: u64) -> u64 {
+    // Every odd `m` is its own inverse modulo 8, and each Newton step
+    // `x = x * (2 - m * x)` doubles the number of correct low bits.
+    let mut inv = m;
+    let mut i = 0;
+    while i < 5 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(m.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        batch_invert, mul_vartime, recode_signed_ct, wnaf_form, BatchInvertSlice, MUL_NUM_DIGITS,
+        MUL_WINDOW,
+    };
+    use crate::bn256::{Bn256, Fr, G1Affine, G2Affine, G1, G2};
+    use crate::pairing::Engine;
+    use crate::secp256k1::Secp256k1;
+    use ff::{Field, PrimeField};
+    use group::Group;
+    use rand_core::{OsRng, RngCore};
+
+    fn run_batch_invert_test<F: Field>() {
+        for n in [0, 1, 2, 100] {
+            let mut v: Vec<F> = (0..n).map(|_| F::random(OsRng)).collect();
+            for i in (0..n).step_by(7) {
+                v[i] = F::ZERO;
+            }
+            let expected: Vec<F> = v.iter().map(|x| x.invert().unwrap_or(F::ZERO)).collect();
+            let product_inv = v
+                .iter()
+                .filter(|x| !x.is_zero_vartime())
+                .fold(F::ONE, |acc, x| acc * x)
+                .invert()
+                .unwrap();
+
+            let mut w = v.clone();
+            assert_eq!(w.as_mut_slice().batch_invert(), product_inv);
+            assert_eq!(w, expected);
+
+            let mut w = v.clone();
+            assert_eq!(w.as_mut_slice().batch_invert_vartime(), product_inv);
+            assert_eq!(w, expected);
+
+            // The scratch buffer may be longer than the slice.
+            let mut scratch = vec![F::ONE; n + 1];
+            let mut w = v.clone();
+            assert_eq!(
+                w.as_mut_slice().batch_invert_with_scratch(&mut scratch),
+                product_inv
+            );
+            assert_eq!(w, expected);
+
+            let mut w = v.clone();
+            assert_eq!(
+                w.as_mut_slice()
+                    .batch_invert_vartime_with_scratch(&mut scratch),
+                product_inv
+            );
+            assert_eq!(w, expected);
+        }
+    }
+
+    #[test]
+    fn test_batch_invert() {
+        run_batch_invert_test::<Fr>();
+        run_batch_invert_test::<crate::bn256::Fq2>();
+        run_batch_invert_test::<crate::bn256::Fq6>();
+        run_batch_invert_test::<crate::bn256::Fq12>();
+        run_batch_invert_test::<crate::secp256k1::Fp>();
+        run_batch_invert_test::<crate::secp256k1::Fq>();
+
+        for n in [0, 1, 2, 100, 5000] {
+            let mut v: Vec<Fr> = (0..n).map(|_| Fr::random(OsRng)).collect();
+            for i in (0..n).step_by(7) {
+                v[i] = Fr::ZERO;
+            }
+            let expected: Vec<Fr> = v.iter().map(|x| x.invert().unwrap_or(Fr::ZERO)).collect();
+
+            batch_invert(&mut v);
+            assert_eq!(v, expected);
+        }
+    }
+
+    #[test]
+    fn test_recode_signed_ct() {
+        let half = 1i8 << (MUL_WINDOW - 1);
+        let mut ks = vec![0, 1, u128::MAX, u128::MAX >> 1, 0x8888_8888, 1 << 127];
+        ks.extend((0..1000).map(|_| (OsRng.next_u64() as u128) << 64 | OsRng.next_u64() as u128));
+        for k in ks {
+            let mut digits = [0i8; MUL_NUM_DIGITS];
+            recode_signed_ct(&k.to_le_bytes(), MUL_WINDOW, &mut digits);
+            assert!(digits.iter().all(|d| -half <= *d && *d <= half));
+
+            // The top digit only holds the final carry, which falls off modulo 2^128.
+            let recomposed = digits.iter().rev().fold(0u128, |acc, d| {
+                (acc << MUL_WINDOW).wrapping_add(*d as i128 as u128)
+            });
+            assert_eq!(recomposed, k);
+        }
+    }
+
+    #[test]
+    fn test_recode_signed_ct_window() {
+        for window in 2..8 {
+            let num_digits = Fr::NUM_BITS as usize / window + 1;
+            for _ in 0..100 {
+                let mut repr = [0u8; 32];
+                OsRng.fill_bytes(&mut repr[..31]);
+                let mut expected = vec![0; num_digits];
+                crate::msm::signed_digits(&repr, window, &mut expected);
+
+                let mut digits = vec![0; num_digits];
+                recode_signed_ct(&repr, window, &mut digits);
+                let digits: Vec<i32> = digits.into_iter().map(i32::from).collect();
+                assert_eq!(digits, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_wnaf_form() {
+        for w in 2..8 {
+            for _ in 0..100 {
+                let mut repr = [0u8; 32];
+                OsRng.fill_bytes(&mut repr[..31]);
+                let wnaf = wnaf_form(&repr, w);
+
+                let bound = 1i64 << (w - 1);
+                for (i, digit) in wnaf.iter().enumerate() {
+                    if *digit != 0 {
+                        assert!(digit % 2 != 0 && -bound < *digit && *digit < bound);
+                        assert!(wnaf[i + 1..].iter().take(w - 1).all(|d| *d == 0));
+                    }
+                }
+
+                let recomposed = wnaf.iter().rev().fold(Fr::ZERO, |acc, d| {
+                    let d = if *d < 0 {
+                        -Fr::from(d.unsigned_abs())
+                    } else {
+                        Fr::from(*d as u64)
+                    };
+                    acc.double() + d
+                });
+                assert_eq!(Fr::from_repr(repr).unwrap(), recomposed);
+            }
+        }
+    }
+
+    fn run_mul_vartime_test<G: Group>(base: G) {
+        let mut scalars = vec![G::Scalar::ZERO, G::Scalar::ONE, -G::Scalar::ONE];
+        scalars.extend((0..20).map(|_| G::Scalar::random(OsRng)));
+        for scalar in scalars {
+            assert_eq!(mul_vartime(&base, &scalar), base * scalar);
+        }
+        assert_eq!(
+            mul_vartime(&G::identity(), &G::Scalar::random(OsRng)),
+            G::identity()
+        );
+    }
+
+    #[test]
+    fn test_mul_vartime() {
+        run_mul_vartime_test(G1::random(OsRng));
+        run_mul_vartime_test(G2::random(OsRng));
+        run_mul_vartime_test(Secp256k1::random(OsRng));
+        run_mul_vartime_test(Bn256::pairing(
+            &G1Affine::generator(),
+            &G2Affine::generator(),
+        ));
+    }
+}
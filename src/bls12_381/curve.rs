@@ -0,0 +1,1472 @@
+use crate::bls12_381::Fq;
+use crate::bls12_381::Fq2;
+use crate::bls12_381::Fr;
+use crate::bls12_381::BLS_X;
+use crate::ff::Field;
+use crate::ff::WithSmallOrderMulGroup;
+use crate::fixed_base::FixedBaseTable;
+use crate::group::Curve;
+use crate::group::{cofactor::CofactorGroup, prime::PrimeCurveAffine, Group, GroupEncoding};
+use crate::hash_to_curve::{sswu_hash_to_curve, Isogeny};
+use crate::{Coordinates, CurveAffine, CurveAffineExt, CurveExt};
+use core::cmp;
+use core::fmt::Debug;
+use core::iter::Sum;
+use core::ops::{Add, Mul, Neg, Sub};
+use rand::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+#[cfg(feature = "derive_serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    batch_add, impl_add_binop_specify_output, impl_binops_additive,
+    impl_binops_additive_specify_output, impl_binops_multiplicative,
+    impl_binops_multiplicative_mixed, impl_sub_binop_specify_output, new_curve_impl,
+};
+
+new_curve_impl!(
+    (pub),
+    G1,
+    G1Affine,
+    false,
+    Fq,
+    Fr,
+    (G1_GENERATOR_X,G1_GENERATOR_Y),
+    G1_B,
+    "bls12_381_g1",
+    fixed_window,
+    |domain_prefix| sswu_hash_to_curve::<G1>(
+        G1_SSWU_SUITE,
+        domain_prefix,
+        G1_SSWU_Z,
+        ISO_G1_A,
+        ISO_G1_B,
+        ISO_G1_MAP,
+    ),
+    zcash,
+);
+
+new_curve_impl!(
+    (pub),
+    G2,
+    G2Affine,
+    false,
+    Fq2,
+    Fr,
+    (G2_GENERATOR_X, G2_GENERATOR_Y),
+    G2_B,
+    "bls12_381_g2",
+    fixed_window,
+    |domain_prefix| sswu_hash_to_curve::<G2>(
+        G2_SSWU_SUITE,
+        domain_prefix,
+        G2_SSWU_Z,
+        ISO_G2_A,
+        ISO_G2_B,
+        ISO_G2_MAP,
+    ),
+    zcash,
+);
+
+impl CurveAffineExt for G1Affine {
+    batch_add!();
+
+    fn into_coordinates(self) -> (Self::Base, Self::Base) {
+        (self.x, self.y)
+    }
+}
+
+impl CurveAffineExt for G2Affine {
+    batch_add!();
+
+    fn into_coordinates(self) -> (Self::Base, Self::Base) {
+        (self.x, self.y)
+    }
+}
+
+lazy_static::lazy_static! {
+    /// Precomputed multiples of the `G1` generator, built on first use.
+    pub static ref G1_GENERATOR_TABLE: FixedBaseTable<G1Affine> =
+        FixedBaseTable::new(G1Affine::generator());
+
+    /// Precomputed multiples of the `G2` generator, built on first use.
+    pub static ref G2_GENERATOR_TABLE: FixedBaseTable<G2Affine> =
+        FixedBaseTable::new(G2Affine::generator());
+}
+
+const G1_GENERATOR_X: Fq = Fq::from_raw([
+    0xfb3af00adb22c6bb,
+    0x6c55e83ff97a1aef,
+    0xa14e3a3f171bac58,
+    0xc3688c4f9774b905,
+    0x2695638c4fa9ac0f,
+    0x17f1d3a73197d794,
+]);
+const G1_GENERATOR_Y: Fq = Fq::from_raw([
+    0x0caa232946c5e7e1,
+    0xd03cc744a2888ae4,
+    0x00db18cb2c04b3ed,
+    0xfcf5e095d5d00af6,
+    0xa09e30ed741d8ae4,
+    0x08b3f481e3aaa0f1,
+]);
+const G1_B: Fq = Fq::from_raw([4, 0, 0, 0, 0, 0]);
+
+/// Suite of RFC 9380 used by `G1::hash_to_curve`.
+const G1_SSWU_SUITE: &str = "BLS12381G1_XMD:SHA-256_SSWU_RO_";
+
+/// `Z` parameter of the simplified SWU map to `G1`, which is `11`.
+const G1_SSWU_Z: Fq = Fq::from_raw([11, 0, 0, 0, 0, 0]);
+
+/// `A'` of the curve E' that is 11-isogenous to `G1`.
+const ISO_G1_A: Fq = Fq::from_raw([
+    0x5cf428082d584c1d,
+    0x98936f8da0e0f97f,
+    0xd8e8981aefd881ac,
+    0xb0ea985383ee66a8,
+    0x3d693a02c96d4982,
+    0x00144698a3b8e943,
+]);
+
+/// `B'` of the curve E' that is 11-isogenous to `G1`.
+const ISO_G1_B: Fq = Fq::from_raw([
+    0xd1cc48e98e172be0,
+    0x5a23215a316ceaa5,
+    0xa0b9c14fcef35ef5,
+    0x2016c1f0f24f4070,
+    0x018b12e8753eee3b,
+    0x12e2908d11688030,
+]);
+
+/// Coefficients of the 11-isogeny from E' to `G1`, see appendix E.2 of
+/// RFC 9380.
+const ISO_G1_MAP: Isogeny<Fq> = Isogeny {
+    x_num: &[
+        // k_(1,0)
+        Fq::from_raw([
+            0xaeac1662734649b7,
+            0x5610c2d5f2e62d6e,
+            0xf2627b56cdb4e2c8,
+            0x6b303e88a2d7005f,
+            0xb809101dd9981585,
+            0x11a05f2b1e833340,
+        ]),
+        // k_(1,1)
+        Fq::from_raw([
+            0xe834eef1b3cb83bb,
+            0x4838f2a6f318c356,
+            0xf565e33c70d1e86b,
+            0x7c17e75b2f6a8417,
+            0x0588bab22147a81c,
+            0x17294ed3e943ab2f,
+        ]),
+        // k_(1,2)
+        Fq::from_raw([
+            0xe0179f9dac9edcb0,
+            0x958c3e3d2a09729f,
+            0x6878e501ec68e25c,
+            0xce032473295983e5,
+            0x1d1048c5d10a9a1b,
+            0x0d54005db97678ec,
+        ]),
+        // k_(1,3)
+        Fq::from_raw([
+            0xc5b388641d9b6861,
+            0x5336e25ce3107193,
+            0xf1b33289f1b33083,
+            0xd7f5e4656a8dbf25,
+            0x4e0609d307e55412,
+            0x1778e7166fcc6db7,
+        ]),
+        // k_(1,4)
+        Fq::from_raw([
+            0x51154ce9ac8895d9,
+            0x985a286f301e77c4,
+            0x086eeb65982fac18,
+            0x99db995a1257fb3f,
+            0x6642b4b3e4118e54,
+            0x0e99726a3199f443,
+        ]),
+        // k_(1,5)
+        Fq::from_raw([
+            0xcd13c1c66f652983,
+            0xa0870d2dcae73d19,
+            0x9ed3ab9097e68f90,
+            0xdb3cb17dd952799b,
+            0x01d1201bf7a74ab5,
+            0x1630c3250d7313ff,
+        ]),
+        // k_(1,6)
+        Fq::from_raw([
+            0xddd7f225a139ed84,
+            0x8da25128c1052eca,
+            0x9008e218f9c86b2a,
+            0xb11586264f0f8ce1,
+            0x6a3726c38ae652bf,
+            0x0d6ed6553fe44d29,
+        ]),
+        // k_(1,7)
+        Fq::from_raw([
+            0x9ccb5618e3f0c88e,
+            0x39b7c8f8c8f475af,
+            0xa682c62ef0f27533,
+            0x356de5ab275b4db1,
+            0xe8743884d1117e53,
+            0x17b81e7701abdbe2,
+        ]),
+        // k_(1,8)
+        Fq::from_raw([
+            0x6d71986a8497e317,
+            0x4fa295f296b74e95,
+            0xa2c596c928c5d1de,
+            0xc43b756ce79f5574,
+            0x7b90b33563be990d,
+            0x080d3cf1f9a78fc4,
+        ]),
+        // k_(1,9)
+        Fq::from_raw([
+            0x7f241067be390c9e,
+            0xa3190b2edc032779,
+            0x676314baf4bb1b7f,
+            0xdd2ecb803a0c5c99,
+            0x2e0c37515d138f22,
+            0x169b1f8e1bcfa7c4,
+        ]),
+        // k_(1,10)
+        Fq::from_raw([
+            0xca67df3f1605fb7b,
+            0xf69b771f8c285dec,
+            0xd50af36003b14866,
+            0xfa7dccdde6787f96,
+            0x72d8ec09d2565b0d,
+            0x10321da079ce07e2,
+        ]),
+        // k_(1,11)
+        Fq::from_raw([
+            0xa9c8ba2e8ba2d229,
+            0xc24b1b80b64d391f,
+            0x23c0bf1bc24c6b68,
+            0x31d79d7e22c837bc,
+            0xbd1e962381edee3d,
+            0x06e08c248e260e70,
+        ]),
+    ],
+    x_den: &[
+        // k_(2,0)
+        Fq::from_raw([
+            0x993cf9fa40d21b1c,
+            0xb558d681be343df8,
+            0x9c9588617fc8ac62,
+            0x01d5ef4ba35b48ba,
+            0x18b2e62f4bd3fa6f,
+            0x08ca8d548cff19ae,
+        ]),
+        // k_(2,1)
+        Fq::from_raw([
+            0xe5c8276ec82b3bff,
+            0x13daa8846cb026e9,
+            0x0126c2588c48bf57,
+            0x7041e8ca0cf0800c,
+            0x48b4711298e53636,
+            0x12561a5deb559c43,
+        ]),
+        // k_(2,2)
+        Fq::from_raw([
+            0xfcc239ba5cb83e19,
+            0xd6a3d0967c94fedc,
+            0xfca64e00b11aceac,
+            0x6f89416f5a718cd1,
+            0x8137e629bff2991f,
+            0x0b2962fe57a3225e,
+        ]),
+        // k_(2,3)
+        Fq::from_raw([
+            0x130de8938dc62cd8,
+            0x4976d5243eecf5c4,
+            0x54cca8abc28d6fd0,
+            0x5b08243f16b16551,
+            0xc83aafef7c40eb54,
+            0x03425581a58ae2fe,
+        ]),
+        // k_(2,4)
+        Fq::from_raw([
+            0x539d395b3532a21e,
+            0x9bd29ba81f35781d,
+            0x8d6b44e833b306da,
+            0xffdfc759a12062bb,
+            0x0a6f1d5f43e7a07d,
+            0x13a8e162022914a8,
+        ]),
+        // k_(2,5)
+        Fq::from_raw([
+            0xc02df9a29f6304a5,
+            0x7400d24bc4228f11,
+            0x0a43bcef24b8982f,
+            0x395735e9ce9cad4d,
+            0x55390f7f0506c6e9,
+            0x0e7355f8e4e667b9,
+        ]),
+        // k_(2,6)
+        Fq::from_raw([
+            0xec2574496ee84a3a,
+            0xea73b3538f0de06c,
+            0x4e2e073062aede9c,
+            0x570f5799af53a189,
+            0x0f3e0c63e0596721,
+            0x0772caacf1693619,
+        ]),
+        // k_(2,7)
+        Fq::from_raw([
+            0x11f7d99bbdcc5a5e,
+            0x0fa5b9489d11e2d3,
+            0x1996e1cdf9822c58,
+            0x6e7f63c21bca68a8,
+            0x30b3f5b074cf0199,
+            0x14a7ac2a9d64a8b2,
+        ]),
+        // k_(2,8)
+        Fq::from_raw([
+            0x4776ec3a79a1d641,
+            0x03826692abba4370,
+            0x74100da67f398835,
+            0xe07f8d1d7161366b,
+            0x5e920b3dafc7a3cc,
+            0x0a10ecf6ada54f82,
+        ]),
+        // k_(2,9)
+        Fq::from_raw([
+            0x2d6384d168ecdd0a,
+            0x93174e4b4b786500,
+            0x76df533978f31c15,
+            0xf682b4ee96f7d037,
+            0x476d6e3eb3a56680,
+            0x095fc13ab9e92ad4,
+        ]),
+    ],
+    y_num: &[
+        // k_(3,0)
+        Fq::from_raw([
+            0xbe9845719707bb33,
+            0xcd0c7aee9b3ba3c2,
+            0x2b52af6c956543d3,
+            0x11ad138e48a86952,
+            0x259d1f094980dcfa,
+            0x090d97c81ba24ee0,
+        ]),
+        // k_(3,1)
+        Fq::from_raw([
+            0xe097e75a2e41c696,
+            0xd6c56711962fa8bf,
+            0x0f906343eb67ad34,
+            0x1223e96c254f383d,
+            0xd51036d776fb4683,
+            0x134996a104ee5811,
+        ]),
+        // k_(3,2)
+        Fq::from_raw([
+            0xb8dfe240c72de1f6,
+            0xd26d521628b00523,
+            0xc344be4b91400da7,
+            0x2552e2d658a31ce2,
+            0xf4a384c86a3b4994,
+            0x00cc786baa966e66,
+        ]),
+        // k_(3,3)
+        Fq::from_raw([
+            0xa6355c77b0e5f4cb,
+            0xde405aba9ec61dec,
+            0x09e4a3ec03251cf9,
+            0xd42aa7b90eeb791c,
+            0x7898751ad8746757,
+            0x01f86376e8981c21,
+        ]),
+        // k_(3,4)
+        Fq::from_raw([
+            0x41b6daecf2e8fedb,
+            0x2ee7f8dc099040a8,
+            0x79833fd221351adc,
+            0x195536fbe3ce50b8,
+            0x5caf4fe2a21529c4,
+            0x08cc03fdefe0ff13,
+        ]),
+        // k_(3,5)
+        Fq::from_raw([
+            0x99b23ab13633a5f0,
+            0x203f6326c95a8072,
+            0x76505c3d3ad5544e,
+            0x74a7d0d4afadb7bd,
+            0x2211e11db8f0a6a0,
+            0x16603fca40634b6a,
+        ]),
+        // k_(3,6)
+        Fq::from_raw([
+            0xc961f8855fe9d6f2,
+            0x47a87ac2460f415e,
+            0x5231413c4d634f37,
+            0xe75bb8ca2be184cb,
+            0xb2c977d027796b3c,
+            0x04ab0b9bcfac1bbc,
+        ]),
+        // k_(3,7)
+        Fq::from_raw([
+            0xa15e4ca31870fb29,
+            0x42f64550fedfe935,
+            0xfd038da6c26c8426,
+            0x170a05bfe3bdd81f,
+            0xde9926bd2ca6c674,
+            0x0987c8d5333ab86f,
+        ]),
+        // k_(3,8)
+        Fq::from_raw([
+            0x60370e577bdba587,
+            0x69d65201c78607a3,
+            0x1e8b6e6a1f20cabe,
+            0x8f3abd16679dc26c,
+            0xe88c9e221e4da1bb,
+            0x09fc4018bd96684b,
+        ]),
+        // k_(3,9)
+        Fq::from_raw([
+            0x2bafaaebca731c30,
+            0x9b3f7055dd4eba6f,
+            0x06985e7ed1e4d43b,
+            0xc42a0ca7915af6fe,
+            0x223abde7ada14a23,
+            0x0e1bba7a1186bdb5,
+        ]),
+        // k_(3,10)
+        Fq::from_raw([
+            0xe813711ad011c132,
+            0x31bf3a5cce3fbafc,
+            0xd1183e416389e610,
+            0xcd2fcbcb6caf493f,
+            0x0dfd0b8f1d43fb93,
+            0x19713e47937cd1be,
+        ]),
+        // k_(3,11)
+        Fq::from_raw([
+            0xce07c8a4d0074d8e,
+            0x49d9cdf41b44d606,
+            0x2e6bfe7f911f6432,
+            0x523559b8aaf0c246,
+            0xb918c143fed2edcc,
+            0x18b46a908f36f6de,
+        ]),
+        // k_(3,12)
+        Fq::from_raw([
+            0x0d4c04f00b971ef8,
+            0x06c851c1919211f2,
+            0xc02710e807b4633f,
+            0x7aa7b12a3426b08e,
+            0xd155096004f53f44,
+            0x0b182cac101b9399,
+        ]),
+        // k_(3,13)
+        Fq::from_raw([
+            0x42d9d3f5db980133,
+            0xc6cf90ad1c232a64,
+            0x13e6632d3c40659c,
+            0x757b3b080d4c1580,
+            0x72fc00ae7be315dc,
+            0x0245a394ad1eca9b,
+        ]),
+        // k_(3,14)
+        Fq::from_raw([
+            0x866b1e715475224b,
+            0x6ba1049b6579afb7,
+            0xd9ab0f5d396a7ce4,
+            0x5e673d81d7e86568,
+            0x02a159f748c4a3fc,
+            0x05c129645e44cf11,
+        ]),
+        // k_(3,15)
+        Fq::from_raw([
+            0x04b456be69c8b604,
+            0xb665027efec01c77,
+            0x57add4fa95af01b2,
+            0xcb181d8f84965a39,
+            0x4ea50b3b42df2eb5,
+            0x15e6be4e990f03ce,
+        ]),
+    ],
+    y_den: &[
+        // k_(4,0)
+        Fq::from_raw([
+            0x01479253b03663c1,
+            0x07f3688ef60c206d,
+            0xeec3232b5be72e7a,
+            0x601a6de578980be6,
+            0x52181140fad0eae9,
+            0x16112c4c3a9c98b2,
+        ]),
+        // k_(4,1)
+        Fq::from_raw([
+            0x32f6102c2e49a03d,
+            0x78a4260763529e35,
+            0xa4a10356f453e01f,
+            0x85c84ff731c4d59c,
+            0x1a0cbd6c43c348b8,
+            0x1962d75c2381201e,
+        ]),
+        // k_(4,2)
+        Fq::from_raw([
+            0x1e2538b53dbf67f2,
+            0xa6757cd636f96f89,
+            0x0c35a5dd279cd2ec,
+            0x78c4855551ae7f31,
+            0x6faaae7d6e8eb157,
+            0x058df3306640da27,
+        ]),
+        // k_(4,3)
+        Fq::from_raw([
+            0xa8d26d98445f5416,
+            0x727364f2c28297ad,
+            0x123da489e726af41,
+            0xd115c5dbddbcd30e,
+            0xf20d23bf89edb4d1,
+            0x16b7d288798e5395,
+        ]),
+        // k_(4,4)
+        Fq::from_raw([
+            0xda39142311a5001d,
+            0xa20b15dc0fd2eded,
+            0x542eda0fc9dec916,
+            0xc6d19c9f0f69bbb0,
+            0xb00cc912f8228ddc,
+            0x0be0e079545f43e4,
+        ]),
+        // k_(4,5)
+        Fq::from_raw([
+            0x02c6477faaf9b7ac,
+            0x49f38db9dfa9cce2,
+            0xc5ecd87b6f0f5a64,
+            0xb70152c65550d881,
+            0x9fb266eaac783182,
+            0x08d9e5297186db2d,
+        ]),
+        // k_(4,6)
+        Fq::from_raw([
+            0x3d1a1399126a775c,
+            0xd5fa9c01a58b1fb9,
+            0x5dd365bc400a0051,
+            0x5eecfdfa8d0cf8ef,
+            0xc3ba8734ace9824b,
+            0x166007c08a99db2f,
+        ]),
+        // k_(4,7)
+        Fq::from_raw([
+            0x60ee415a15812ed9,
+            0xb920f5b00801dee4,
+            0xfeb34fd206357132,
+            0xe5a4375efa1f4fd7,
+            0x03bcddfabba6ff6e,
+            0x16a3ef08be3ea7ea,
+        ]),
+        // k_(4,8)
+        Fq::from_raw([
+            0x6b233d9d55535d4a,
+            0x52cfe2f7bb924883,
+            0xabc5750c4bf39b48,
+            0xf9fb0ce4c6af5920,
+            0x1a1be54fd1d74cc4,
+            0x1866c8ed336c6123,
+        ]),
+        // k_(4,9)
+        Fq::from_raw([
+            0x346ef48bb8913f55,
+            0xc7385ea3d529b35e,
+            0x5308592e7ea7d4fb,
+            0x3216f763e13d87bb,
+            0xea820597d94a8490,
+            0x167a55cda70a6e1c,
+        ]),
+        // k_(4,10)
+        Fq::from_raw([
+            0x00f8b49cba8f6aa8,
+            0x71a5c29f4f830604,
+            0x0e591b36e636a5c8,
+            0x9c6dd039bb61a629,
+            0x48f010a01ad2911d,
+            0x04d2f259eea405bd,
+        ]),
+        // k_(4,11)
+        Fq::from_raw([
+            0x9684b529e2561092,
+            0x16f968986f7ebbea,
+            0x8c0f9a88cea79135,
+            0x7f94ff8aefce42d2,
+            0xf5852c1e48c50c47,
+            0x0accbb67481d033f,
+        ]),
+        // k_(4,12)
+        Fq::from_raw([
+            0x1e99b138573345cc,
+            0x93000763e3b90ac1,
+            0x7d5ceef9a00d9b86,
+            0x543346d98adf0226,
+            0xc3613144b45f1496,
+            0x0ad6b9514c767fe3,
+        ]),
+        // k_(4,13)
+        Fq::from_raw([
+            0xd1fadc1326ed06f7,
+            0x420517bd8714cc80,
+            0xcb748df27942480e,
+            0xbf565b94e72927c1,
+            0x628bdd0d53cd76f2,
+            0x02660400eb2e4f3b,
+        ]),
+        // k_(4,14)
+        Fq::from_raw([
+            0x4415473a1d634b8f,
+            0x5ca2f570f1349780,
+            0x324efcd6356caa20,
+            0x71c40f65e273b853,
+            0x6b24255e0d7819c1,
+            0x0e0fa1d816ddc03e,
+        ]),
+    ],
+};
+
+const G2_B: Fq2 = Fq2 {
+    c0: Fq::from_raw([4, 0, 0, 0, 0, 0]),
+    c1: Fq::from_raw([4, 0, 0, 0, 0, 0]),
+};
+
+/// Suite of RFC 9380 used by `G2::hash_to_curve`.
+const G2_SSWU_SUITE: &str = "BLS12381G2_XMD:SHA-256_SSWU_RO_";
+
+/// `Z` parameter of the simplified SWU map to `G2`, which is `-(2 + u)`.
+const G2_SSWU_Z: Fq2 = Fq2 {
+    c0: Fq::from_raw([
+        0xb9feffffffffaaa9,
+        0x1eabfffeb153ffff,
+        0x6730d2a0f6b0f624,
+        0x64774b84f38512bf,
+        0x4b1ba7b6434bacd7,
+        0x1a0111ea397fe69a,
+    ]),
+    c1: Fq::from_raw([
+        0xb9feffffffffaaaa,
+        0x1eabfffeb153ffff,
+        0x6730d2a0f6b0f624,
+        0x64774b84f38512bf,
+        0x4b1ba7b6434bacd7,
+        0x1a0111ea397fe69a,
+    ]),
+};
+
+/// `A'` of the curve E' that is 3-isogenous to `G2`, which is `240 u`.
+const ISO_G2_A: Fq2 = Fq2 {
+    c0: Fq::zero(),
+    c1: Fq::from_raw([240, 0, 0, 0, 0, 0]),
+};
+
+/// `B'` of the curve E' that is 3-isogenous to `G2`, which is `1012 (1 + u)`.
+const ISO_G2_B: Fq2 = Fq2 {
+    c0: Fq::from_raw([1012, 0, 0, 0, 0, 0]),
+    c1: Fq::from_raw([1012, 0, 0, 0, 0, 0]),
+};
+
+/// Coefficients of the 3-isogeny from E' to `G2`, see appendix E.3 of
+/// RFC 9380.
+const ISO_G2_MAP: Isogeny<Fq2> = Isogeny {
+    x_num: &[
+        // k_(1,0)
+        Fq2 {
+            c0: Fq::from_raw([
+                0x6238aaaaaaaa97d6,
+                0x5c2638e343d9c71c,
+                0x88b58423c50ae15d,
+                0x32c52d39fd3a042a,
+                0xbb5b7a9a47d7ed85,
+                0x05c759507e8e333e,
+            ]),
+            c1: Fq::from_raw([
+                0x6238aaaaaaaa97d6,
+                0x5c2638e343d9c71c,
+                0x88b58423c50ae15d,
+                0x32c52d39fd3a042a,
+                0xbb5b7a9a47d7ed85,
+                0x05c759507e8e333e,
+            ]),
+        },
+        // k_(1,1)
+        Fq2 {
+            c0: Fq::zero(),
+            c1: Fq::from_raw([
+                0x26a9ffffffffc71a,
+                0x1472aaa9cb8d5555,
+                0x9a208c6b4f20a418,
+                0x984f87adf7ae0c7f,
+                0x32126fced787c88f,
+                0x11560bf17baa99bc,
+            ]),
+        },
+        // k_(1,2)
+        Fq2 {
+            c0: Fq::from_raw([
+                0x26a9ffffffffc71e,
+                0x1472aaa9cb8d5555,
+                0x9a208c6b4f20a418,
+                0x984f87adf7ae0c7f,
+                0x32126fced787c88f,
+                0x11560bf17baa99bc,
+            ]),
+            c1: Fq::from_raw([
+                0x9354ffffffffe38d,
+                0x0a395554e5c6aaaa,
+                0xcd104635a790520c,
+                0xcc27c3d6fbd7063f,
+                0x190937e76bc3e447,
+                0x08ab05f8bdd54cde,
+            ]),
+        },
+        // k_(1,3)
+        Fq2 {
+            c0: Fq::from_raw([
+                0x88e2aaaaaaaa5ed1,
+                0x7098e38d0f671c71,
+                0x22d6108f142b8575,
+                0xcb14b4e7f4e810aa,
+                0xed6dea691f5fb614,
+                0x171d6541fa38ccfa,
+            ]),
+            c1: Fq::zero(),
+        },
+    ],
+    x_den: &[
+        // k_(2,0)
+        Fq2 {
+            c0: Fq::zero(),
+            c1: Fq::from_raw([
+                0xb9feffffffffaa63,
+                0x1eabfffeb153ffff,
+                0x6730d2a0f6b0f624,
+                0x64774b84f38512bf,
+                0x4b1ba7b6434bacd7,
+                0x1a0111ea397fe69a,
+            ]),
+        },
+        // k_(2,1)
+        Fq2 {
+            c0: Fq::from_raw([12, 0, 0, 0, 0, 0]),
+            c1: Fq::from_raw([
+                0xb9feffffffffaa9f,
+                0x1eabfffeb153ffff,
+                0x6730d2a0f6b0f624,
+                0x64774b84f38512bf,
+                0x4b1ba7b6434bacd7,
+                0x1a0111ea397fe69a,
+            ]),
+        },
+    ],
+    y_num: &[
+        // k_(3,0)
+        Fq2 {
+            c0: Fq::from_raw([
+                0x12cfc71c71c6d706,
+                0xfc8c25ebf8c92f68,
+                0xf54439d87d27e500,
+                0x0f7da5d4a07f649b,
+                0x59a4c18b076d1193,
+                0x1530477c7ab4113b,
+            ]),
+            c1: Fq::from_raw([
+                0x12cfc71c71c6d706,
+                0xfc8c25ebf8c92f68,
+                0xf54439d87d27e500,
+                0x0f7da5d4a07f649b,
+                0x59a4c18b076d1193,
+                0x1530477c7ab4113b,
+            ]),
+        },
+        // k_(3,1)
+        Fq2 {
+            c0: Fq::zero(),
+            c1: Fq::from_raw([
+                0x6238aaaaaaaa97be,
+                0x5c2638e343d9c71c,
+                0x88b58423c50ae15d,
+                0x32c52d39fd3a042a,
+                0xbb5b7a9a47d7ed85,
+                0x05c759507e8e333e,
+            ]),
+        },
+        // k_(3,2)
+        Fq2 {
+            c0: Fq::from_raw([
+                0x26a9ffffffffc71c,
+                0x1472aaa9cb8d5555,
+                0x9a208c6b4f20a418,
+                0x984f87adf7ae0c7f,
+                0x32126fced787c88f,
+                0x11560bf17baa99bc,
+            ]),
+            c1: Fq::from_raw([
+                0x9354ffffffffe38f,
+                0x0a395554e5c6aaaa,
+                0xcd104635a790520c,
+                0xcc27c3d6fbd7063f,
+                0x190937e76bc3e447,
+                0x08ab05f8bdd54cde,
+            ]),
+        },
+        // k_(3,3)
+        Fq2 {
+            c0: Fq::from_raw([
+                0xe1b371c71c718b10,
+                0x4e79097a56dc4bd9,
+                0xb0e977c69aa27452,
+                0x761b0f37a1e26286,
+                0xfbf7043de3811ad0,
+                0x124c9ad43b6cf79b,
+            ]),
+            c1: Fq::zero(),
+        },
+    ],
+    y_den: &[
+        // k_(4,0)
+        Fq2 {
+            c0: Fq::from_raw([
+                0xb9feffffffffa8fb,
+                0x1eabfffeb153ffff,
+                0x6730d2a0f6b0f624,
+                0x64774b84f38512bf,
+                0x4b1ba7b6434bacd7,
+                0x1a0111ea397fe69a,
+            ]),
+            c1: Fq::from_raw([
+                0xb9feffffffffa8fb,
+                0x1eabfffeb153ffff,
+                0x6730d2a0f6b0f624,
+                0x64774b84f38512bf,
+                0x4b1ba7b6434bacd7,
+                0x1a0111ea397fe69a,
+            ]),
+        },
+        // k_(4,1)
+        Fq2 {
+            c0: Fq::zero(),
+            c1: Fq::from_raw([
+                0xb9feffffffffa9d3,
+                0x1eabfffeb153ffff,
+                0x6730d2a0f6b0f624,
+                0x64774b84f38512bf,
+                0x4b1ba7b6434bacd7,
+                0x1a0111ea397fe69a,
+            ]),
+        },
+        // k_(4,2)
+        Fq2 {
+            c0: Fq::from_raw([18, 0, 0, 0, 0, 0]),
+            c1: Fq::from_raw([
+                0xb9feffffffffaa99,
+                0x1eabfffeb153ffff,
+                0x6730d2a0f6b0f624,
+                0x64774b84f38512bf,
+                0x4b1ba7b6434bacd7,
+                0x1a0111ea397fe69a,
+            ]),
+        },
+    ],
+};
+
+const G2_GENERATOR_X: Fq2 = Fq2 {
+    c0: Fq::from_raw([
+        0xd48056c8c121bdb8,
+        0x0bac0326a805bbef,
+        0xb4510b647ae3d177,
+        0xc6e47ad4fa403b02,
+        0x260805272dc51051,
+        0x024aa2b2f08f0a91,
+    ]),
+    c1: Fq::from_raw([
+        0xe5ac7d055d042b7e,
+        0x334cf11213945d57,
+        0xb5da61bbdc7f5049,
+        0x596bd0d09920b61a,
+        0x7dacd3a088274f65,
+        0x13e02b6052719f60,
+    ]),
+};
+
+const G2_GENERATOR_Y: Fq2 = Fq2 {
+    c0: Fq::from_raw([
+        0xe193548608b82801,
+        0x923ac9cc3baca289,
+        0x6d429a695160d12c,
+        0xadfd9baa8cbdd3a7,
+        0x8cc9cdc6da2e351a,
+        0x0ce5d527727d6e11,
+    ]),
+    c1: Fq::from_raw([
+        0xaaa9075ff05f79be,
+        0x3f370d275cec1da1,
+        0x267492ab572e99ab,
+        0xcb3e287e85a763af,
+        0x32acd2b02bc28b99,
+        0x0606c4a02ea734cc,
+    ]),
+};
+
+/// `1 / ξ^((p - 1) / 3)`, the `x` coefficient of ψ.
+const PSI_X: Fq2 = Fq2 {
+    c0: Fq::zero(),
+    c1: Fq([
+        0x890dc9e4867545c3,
+        0x2af322533285a5d5,
+        0x50880866309b7e2c,
+        0xa20d1b8c7e881024,
+        0x14e4f04fe2db9068,
+        0x14e56d3f1564853a,
+    ]),
+};
+
+/// `1 / ξ^((p - 1) / 2)`, the `y` coefficient of ψ.
+const PSI_Y: Fq2 = Fq2 {
+    c0: Fq([
+        0x3e2f585da55c9ad1,
+        0x4294213d86c18183,
+        0x382844c88b623732,
+        0x92ad2afd19103e18,
+        0x1d794e4fac7cf0b9,
+        0x0bd592fc7d825ec8,
+    ]),
+    c1: Fq([
+        0x7bcfa7a25aa30fda,
+        0xdc17dec12a927e7c,
+        0x2f088dd86b4ebef1,
+        0xd1ca2087da74d4a7,
+        0x2da2596696cebc1d,
+        0x0e2b7eedbbfd87d2,
+    ]),
+};
+
+impl G1 {
+    /// Multiplies by `|x|`, the absolute value of the BLS parameter, which is
+    /// public.
+    fn mul_by_x(&self) -> Self {
+        let mut acc = G1::identity();
+        for i in (0..64 - BLS_X.leading_zeros()).rev() {
+            acc = acc.double();
+            if (BLS_X >> i) & 1 == 1 {
+                acc += self;
+            }
+        }
+        acc
+    }
+}
+
+impl CofactorGroup for G1 {
+    type Subgroup = G1;
+
+    fn clear_cofactor(&self) -> Self {
+        // Wahby and Boneh, "Fast and simple constant-time hashing to the BLS12-381
+        // elliptic curve", section 5: [1 - x]P, where x is negative.
+        self.mul_by_x() + self
+    }
+
+    fn into_subgroup(self) -> CtOption<Self::Subgroup> {
+        CtOption::new(self, self.is_torsion_free())
+    }
+
+    fn is_torsion_free(&self) -> Choice {
+        // Scott, "A note on group membership tests for G1, G2 and GT on BLS
+        // pairing-friendly curves": P is in G1 iff φ²(P) = [-x²]P. Since
+        // φ² + φ + 1 = 0, this is φ(P) + P = [x²]P.
+        let x2_p = self.mul_by_x().mul_by_x();
+        (self.endo() + self).ct_eq(&x2_p)
+    }
+}
+
+impl G2 {
+    /// Applies the untwist-Frobenius-twist endomorphism
+    /// ψ(x, y) = (conj(x) / ξ^((p - 1) / 3), conj(y) / ξ^((p - 1) / 2)).
+    fn psi(&self) -> Self {
+        let mut x = self.x;
+        let mut y = self.y;
+        let mut z = self.z;
+        x.conjugate();
+        y.conjugate();
+        z.conjugate();
+        G2 {
+            x: x * PSI_X,
+            y: y * PSI_Y,
+            z,
+        }
+    }
+
+    /// Multiplies by `|x|`, the absolute value of the BLS parameter, which is
+    /// public.
+    fn mul_by_x(&self) -> Self {
+        let mut acc = G2::identity();
+        for i in (0..64 - BLS_X.leading_zeros()).rev() {
+            acc = acc.double();
+            if (BLS_X >> i) & 1 == 1 {
+                acc += self;
+            }
+        }
+        acc
+    }
+}
+
+impl CofactorGroup for G2 {
+    type Subgroup = G2;
+
+    fn clear_cofactor(&self) -> Self {
+        // Budroni and Pintore, "Efficient hash maps to G2 on BLS curves":
+        // [x² - x - 1]P + [x - 1]ψ(P) + ψ²([2]P), which is the `h_eff` of RFC 9380.
+        // With x negative this is [|x|² + |x| - 1]P - ψ([|x| + 1]P) + ψ²([2]P).
+        let x_p = self.mul_by_x();
+        let x2_p = x_p.mul_by_x();
+        let psi_x1_p = (x_p + self).psi();
+        let psi2_2p = self.double().psi().psi();
+
+        x2_p + x_p - self - psi_x1_p + psi2_2p
+    }
+
+    fn into_subgroup(self) -> CtOption<Self::Subgroup> {
+        CtOption::new(self, self.is_torsion_free())
+    }
+
+    fn is_torsion_free(&self) -> Choice {
+        // Scott, "A note on group membership tests for G1, G2 and GT on BLS
+        // pairing-friendly curves": P is in G2 iff ψ(P) = [x]P.
+        self.psi().ct_eq(&-self.mul_by_x())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        G1_SSWU_SUITE, G1_SSWU_Z, G2_SSWU_SUITE, G2_SSWU_Z, ISO_G1_A, ISO_G1_B, ISO_G1_MAP,
+        ISO_G2_A, ISO_G2_B, ISO_G2_MAP,
+    };
+    use crate::bls12_381::{Fq, Fq2, Fr, G1Affine, G2Affine, G1, G2};
+    use crate::hash_to_curve::{iso_map, sswu_map_to_curve, HashToField, SswuConstants};
+    use crate::{CurveAffine, CurveExt};
+    use ff::{Field, PrimeField};
+    use group::cofactor::CofactorGroup;
+    use group::{prime::PrimeCurveAffine, Curve, Group, GroupEncoding, UncompressedEncoding};
+    use rand_core::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    #[test]
+    fn test_curve() {
+        crate::tests::curve::curve_tests::<G1>();
+        crate::tests::curve::curve_tests::<G2>();
+    }
+
+    #[test]
+    fn test_endo_consistency() {
+        crate::tests::curve::endo_consistency_test::<G1>();
+        crate::tests::curve::endo_consistency_test::<G2>();
+    }
+
+    #[test]
+    fn test_serialization() {
+        crate::tests::curve::random_serialization_test::<G1>();
+        crate::tests::curve::random_serialization_test::<G2>();
+        #[cfg(feature = "derive_serde")]
+        {
+            crate::tests::curve::random_serde_test::<G1>();
+            crate::tests::curve::random_serde_test::<G2>();
+        }
+    }
+
+    fn hex_to_bytes(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_zcash_encoding_g1() {
+        // Compressed and uncompressed encodings of the generator in the Zcash
+        // serialization format.
+        let compressed = hex_to_bytes(
+            "97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
+        );
+        let uncompressed = hex_to_bytes(
+            "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb\
+             08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1",
+        );
+
+        let g = G1Affine::generator();
+        assert_eq!(g.to_bytes().as_ref(), &compressed[..]);
+        assert_eq!(g.to_uncompressed().as_ref(), &uncompressed[..]);
+        assert_eq!(G1Affine::from_bytes(&g.to_bytes()).unwrap(), g);
+        assert_eq!(
+            G1Affine::from_uncompressed(&g.to_uncompressed()).unwrap(),
+            g
+        );
+
+        let mut identity = [0u8; 48];
+        identity[0] = 0xc0;
+        assert_eq!(G1Affine::identity().to_bytes().as_ref(), &identity[..]);
+        let mut identity = [0u8; 96];
+        identity[0] = 0x40;
+        assert_eq!(
+            G1Affine::identity().to_uncompressed().as_ref(),
+            &identity[..]
+        );
+
+        // -G has the sort flag flipped.
+        let mut neg = g.to_bytes();
+        neg.as_mut()[0] ^= 0x20;
+        assert_eq!(G1Affine::from_bytes(&neg).unwrap(), -g);
+        assert_eq!((-g).to_bytes().as_ref(), neg.as_ref());
+
+        // Encodings with inconsistent flags are rejected.
+        let mut bytes = g.to_bytes();
+        bytes.as_mut()[0] &= 0x7f;
+        assert!(bool::from(G1Affine::from_bytes(&bytes).is_none()));
+        let mut bytes = G1Affine::identity().to_bytes();
+        bytes.as_mut()[0] |= 0x20;
+        assert!(bool::from(G1Affine::from_bytes(&bytes).is_none()));
+        let mut bytes = G1Affine::identity().to_bytes();
+        bytes.as_mut()[47] = 1;
+        assert!(bool::from(G1Affine::from_bytes(&bytes).is_none()));
+        let mut bytes = g.to_uncompressed();
+        bytes.as_mut()[0] |= 0x80;
+        assert!(bool::from(G1Affine::from_uncompressed(&bytes).is_none()));
+        let mut bytes = g.to_uncompressed();
+        bytes.as_mut()[0] |= 0x20;
+        assert!(bool::from(G1Affine::from_uncompressed(&bytes).is_none()));
+    }
+
+    #[test]
+    fn test_zcash_encoding_g2() {
+        let compressed = hex_to_bytes(
+            "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e\
+             024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8",
+        );
+        let uncompressed = hex_to_bytes(
+            "13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e\
+             024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8\
+             0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be\
+             0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801",
+        );
+
+        let g = G2Affine::generator();
+        assert_eq!(g.to_bytes().as_ref(), &compressed[..]);
+        assert_eq!(g.to_uncompressed().as_ref(), &uncompressed[..]);
+        assert_eq!(G2Affine::from_bytes(&g.to_bytes()).unwrap(), g);
+        assert_eq!(
+            G2Affine::from_uncompressed(&g.to_uncompressed()).unwrap(),
+            g
+        );
+
+        let mut identity = [0u8; 96];
+        identity[0] = 0xc0;
+        assert_eq!(G2Affine::identity().to_bytes().as_ref(), &identity[..]);
+
+        let mut neg = g.to_bytes();
+        neg.as_mut()[0] ^= 0x20;
+        assert_eq!(G2Affine::from_bytes(&neg).unwrap(), -g);
+        assert_eq!((-g).to_bytes().as_ref(), neg.as_ref());
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+        for _ in 0..100 {
+            let p = G2::random(&mut rng).to_affine();
+            for p in [p, -p] {
+                assert_eq!(G2Affine::from_bytes(&p.to_bytes()).unwrap(), p);
+                assert_eq!(
+                    G2Affine::from_uncompressed(&p.to_uncompressed()).unwrap(),
+                    p
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_hash_to_curve() {
+        fn fq_from_hex(hex: &str) -> Fq {
+            let mut repr = <Fq as PrimeField>::Repr::default();
+            for (i, b) in repr.as_mut().iter_mut().rev().enumerate() {
+                *b = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+            }
+            Fq::from_repr(repr).unwrap()
+        }
+
+        fn fq2_from_hex(c0: &str, c1: &str) -> Fq2 {
+            Fq2::new(fq_from_hex(c0), fq_from_hex(c1))
+        }
+
+        crate::tests::curve::hash_to_curve_test::<G1>();
+        crate::tests::curve::hash_to_curve_test::<G2>();
+
+        const DOMAIN_PREFIX: &str = "QUUX-V01-CS02-with";
+
+        // Test vectors from RFC 9380, appendix J.9.1.
+        let vectors: [(&[u8], [&str; 4]); 5] = [
+            (
+                b"",
+                [
+                    "0ba14bd907ad64a016293ee7c2d276b8eae71f25a4b941eece7b0d89f17f75cb3ae5438a614fb61d6835ad59f29c564f",
+                    "019b9bd7979f12657976de2884c7cce192b82c177c80e0ec604436a7f538d231552f0d96d9f7babe5fa3b19b3ff25ac9",
+                    "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1",
+                    "08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+                ],
+            ),
+            (
+                b"abc",
+                [
+                    "0d921c33f2bad966478a03ca35d05719bdf92d347557ea166e5bba579eea9b83e9afa5c088573c2281410369fbd32951",
+                    "003574a00b109ada2f26a37a91f9d1e740dffd8d69ec0c35e1e9f4652c7dba61123e9dd2e76c655d956e2b3462611139",
+                    "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903",
+                    "0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+                ],
+            ),
+            (
+                b"abcdef0123456789",
+                [
+                    "062d1865eb80ebfa73dcfc45db1ad4266b9f3a93219976a3790ab8d52d3e5f1e62f3b01795e36834b17b70e7b76246d4",
+                    "0cdc3e2f271f29c4ff75020857ce6c5d36008c9b48385ea2f2bf6f96f428a3deb798aa033cd482d1cdc8b30178b08e3a",
+                    "11e0b079dea29a68f0383ee94fed1b940995272407e3bb916bbf268c263ddd57a6a27200a784cbc248e84f357ce82d98",
+                    "03a87ae2caf14e8ee52e51fa2ed8eefe80f02457004ba4d486d6aa1f517c0889501dc7413753f9599b099ebcbbd2d709",
+                ],
+            ),
+            (
+                &[b"q128_".as_slice(), &[b'q'; 128]].concat(),
+                [
+                    "010476f6a060453c0b1ad0b628f3e57c23039ee16eea5e71bb87c3b5419b1255dc0e5883322e563b84a29543823c0e86",
+                    "0b1a912064fb0554b180e07af7e787f1f883a0470759c03c1b6509eb8ce980d1670305ae7b928226bb58fdc0a419f46e",
+                    "15f68eaa693b95ccb85215dc65fa81038d69629f70aeee0d0f677cf22285e7bf58d7cb86eefe8f2e9bc3f8cb84fac488",
+                    "1807a1d50c29f430b8cafc4f8638dfeeadf51211e1602a5f184443076715f91bb90a48ba1e370edce6ae1062f5e6dd38",
+                ],
+            ),
+            (
+                &[b"a512_".as_slice(), &[b'a'; 512]].concat(),
+                [
+                    "0a8ffa7447f6be1c5a2ea4b959c9454b431e29ccc0802bc052413a9c5b4f9aac67a93431bd480d15be1e057c8a08e8c6",
+                    "05d487032f602c90fa7625dbafe0f4a49ef4a6b0b33d7bb349ff4cf5410d297fd6241876e3e77b651cfc8191e40a68b7",
+                    "082aabae8b7dedb0e78aeb619ad3bfd9277a2f77ba7fad20ef6aabdc6c31d19ba5a6d12283553294c1825c4b3ca2dcfe",
+                    "05b84ae5a942248eea39e1d91030458c40153f3b654ab7872d779ad1e942856a20c438e8d99bc8abfbf74729ce1f7ac8",
+                ],
+            ),
+        ];
+
+        let hasher = G1::hash_to_curve(DOMAIN_PREFIX);
+        for (message, [u0, u1, x, y]) in vectors.iter() {
+            let mut us = [Fq::ZERO; 2];
+            Fq::hash_to_field(G1_SSWU_SUITE, DOMAIN_PREFIX, message, &mut us);
+            assert_eq!(us, [fq_from_hex(u0), fq_from_hex(u1)]);
+
+            let expected = G1Affine::from_xy(fq_from_hex(x), fq_from_hex(y)).unwrap();
+            assert_eq!(hasher(message).to_affine(), expected);
+        }
+
+        // Test vectors from RFC 9380, appendix J.10.1.
+        let vectors: [(&[u8], [&str; 8]); 5] = [
+            (
+                b"",
+                [
+                    "03dbc2cce174e91ba93cbb08f26b917f98194a2ea08d1cce75b2b9cc9f21689d80bd79b594a613d0a68eb807dfdc1cf8",
+                    "05a2acec64114845711a54199ea339abd125ba38253b70a92c876df10598bd1986b739cad67961eb94f7076511b3b39a",
+                    "02f99798e8a5acdeed60d7e18e9120521ba1f47ec090984662846bc825de191b5b7641148c0dbc237726a334473eee94",
+                    "145a81e418d4010cc027a68f14391b30074e89e60ee7a22f87217b2f6eb0c4b94c9115b436e6fa4607e95a98de30a435",
+                    "0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a",
+                    "05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d",
+                    "0503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92",
+                    "12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d6",
+                ],
+            ),
+            (
+                b"abc",
+                [
+                    "15f7c0aa8f6b296ab5ff9c2c7581ade64f4ee6f1bf18f55179ff44a2cf355fa53dd2a2158c5ecb17d7c52f63e7195771",
+                    "01c8067bf4c0ba709aa8b9abc3d1cef589a4758e09ef53732d670fd8739a7274e111ba2fcaa71b3d33df2a3a0c8529dd",
+                    "187111d5e088b6b9acfdfad078c4dacf72dcd17ca17c82be35e79f8c372a693f60a033b461d81b025864a0ad051a06e4",
+                    "08b852331c96ed983e497ebc6dee9b75e373d923b729194af8e72a051ea586f3538a6ebb1e80881a082fa2b24df9f566",
+                    "02c2d18e033b960562aae3cab37a27ce00d80ccd5ba4b7fe0e7a210245129dbec7780ccc7954725f4168aff2787776e6",
+                    "139cddbccdc5e91b9623efd38c49f81a6f83f175e80b06fc374de9eb4b41dfe4ca3a230ed250fbe3a2acf73a41177fd8",
+                    "1787327b68159716a37440985269cf584bcb1e621d3a7202be6ea05c4cfe244aeb197642555a0645fb87bf7466b2ba48",
+                    "00aa65dae3c8d732d10ecd2c50f8a1baf3001578f71c694e03866e9f3d49ac1e1ce70dd94a733534f106d4cec0eddd16",
+                ],
+            ),
+            (
+                b"abcdef0123456789",
+                [
+                    "0313d9325081b415bfd4e5364efaef392ecf69b087496973b229303e1816d2080971470f7da112c4eb43053130b785e1",
+                    "062f84cb21ed89406890c051a0e8b9cf6c575cf6e8e18ecf63ba86826b0ae02548d83b483b79e48512b82a6c0686df8f",
+                    "1739123845406baa7be5c5dc74492051b6d42504de008c635f3535bb831d478a341420e67dcc7b46b2e8cba5379cca97",
+                    "01897665d9cb5db16a27657760bbea7951f67ad68f8d55f7113f24ba6ddd82caef240a9bfa627972279974894701d975",
+                    "121982811d2491fde9ba7ed31ef9ca474f0e1501297f68c298e9f4c0028add35aea8bb83d53c08cfc007c1e005723cd0",
+                    "190d119345b94fbd15497bcba94ecf7db2cbfd1e1fe7da034d26cbba169fb3968288b3fafb265f9ebd380512a71c3f2c",
+                    "05571a0f8d3c08d094576981f4a3b8eda0a8e771fcdcc8ecceaf1356a6acf17574518acb506e435b639353c2e14827c8",
+                    "0bb5e7572275c567462d91807de765611490205a941a5a6af3b1691bfe596c31225d3aabdf15faff860cb4ef17c7c3be",
+                ],
+            ),
+            (
+                &[b"q128_".as_slice(), &[b'q'; 128]].concat(),
+                [
+                    "025820cefc7d06fd38de7d8e370e0da8a52498be9b53cba9927b2ef5c6de1e12e12f188bbc7bc923864883c57e49e253",
+                    "034147b77ce337a52e5948f66db0bab47a8d038e712123bb381899b6ab5ad20f02805601e6104c29df18c254b8618c7b",
+                    "0930315cae1f9a6017c3f0c8f2314baa130e1cf13f6532bff0a8a1790cd70af918088c3db94bda214e896e1543629795",
+                    "10c4df2cacf67ea3cb3108b00d4cbd0b3968031ebc8eac4b1ebcefe84d6b715fde66bef0219951ece29d1facc8a520ef",
+                    "19a84dd7248a1066f737cc34502ee5555bd3c19f2ecdb3c7d9e24dc65d4e25e50d83f0f77105e955d78f4762d33c17da",
+                    "0934aba516a52d8ae479939a91998299c76d39cc0c035cd18813bec433f587e2d7a4fef038260eef0cef4d02aae3eb91",
+                    "14f81cd421617428bc3b9fe25afbb751d934a00493524bc4e065635b0555084dd54679df1536101b2c979c0152d09192",
+                    "09bcccfa036b4847c9950780733633f13619994394c23ff0b32fa6b795844f4a0673e20282d07bc69641cee04f5e5662",
+                ],
+            ),
+            (
+                &[b"a512_".as_slice(), &[b'a'; 512]].concat(),
+                [
+                    "190b513da3e66fc9a3587b78c76d1d132b1152174d0b83e3c1114066392579a45824c5fa17649ab89299ddd4bda54935",
+                    "12ab625b0fe0ebd1367fe9fac57bb1168891846039b4216b9d94007b674de2d79126870e88aeef54b2ec717a887dcf39",
+                    "0e6a42010cf435fb5bacc156a585e1ea3294cc81d0ceb81924d95040298380b164f702275892cedd81b62de3aba3f6b5",
+                    "117d9a0defc57a33ed208428cb84e54c85a6840e7648480ae428838989d25d97a0af8e3255be62b25c2a85630d2dddd8",
+                    "01a6ba2f9a11fa5598b2d8ace0fbe0a0eacb65deceb476fbbcb64fd24557c2f4b18ecfc5663e54ae16a84f5ab7f62534",
+                    "11fca2ff525572795a801eed17eb12785887c7b63fb77a42be46ce4a34131d71f7a73e95fee3f812aea3de78b4d01569",
+                    "0b6798718c8aed24bc19cb27f866f1c9effcdbf92397ad6448b5c9db90d2b9da6cbabf48adc1adf59a1a28344e79d57e",
+                    "03a47f8e6d1763ba0cad63d6114c0accbef65707825a511b251a660a9b3994249ae4e63fac38b23da0c398689ee2ab52",
+                ],
+            ),
+        ];
+
+        let hasher = G2::hash_to_curve(DOMAIN_PREFIX);
+        for (message, [u00, u01, u10, u11, x0, x1, y0, y1]) in vectors.iter() {
+            let mut us = [Fq2::ZERO; 2];
+            Fq2::hash_to_field(G2_SSWU_SUITE, DOMAIN_PREFIX, message, &mut us);
+            assert_eq!(us, [fq2_from_hex(u00, u01), fq2_from_hex(u10, u11)]);
+
+            let expected = G2Affine::from_xy(fq2_from_hex(x0, x1), fq2_from_hex(y0, y1)).unwrap();
+            assert_eq!(hasher(message).to_affine(), expected);
+        }
+    }
+
+    /// Maps a field element to a point on the curve of `G1` with the map of
+    /// its hash to curve suite, which is almost never in the subgroup.
+    fn map_to_g1(u: Fq) -> G1 {
+        let (x, y) = sswu_map_to_curve(u, &SswuConstants::new(G1_SSWU_Z, ISO_G1_A, ISO_G1_B));
+        iso_map(x, y, &ISO_G1_MAP)
+    }
+
+    /// Maps a field element to a point on the curve of `G2` with the map of
+    /// its hash to curve suite, which is almost never in the subgroup.
+    fn map_to_g2(u: Fq2) -> G2 {
+        let (x, y) = sswu_map_to_curve(u, &SswuConstants::new(G2_SSWU_Z, ISO_G2_A, ISO_G2_B));
+        iso_map(x, y, &ISO_G2_MAP)
+    }
+
+    #[test]
+    fn test_clear_cofactor() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..100 {
+            let p = map_to_g1(Fq::random(&mut rng));
+            assert!(!bool::from(p.is_torsion_free()));
+
+            let p = p.clear_cofactor();
+            assert!(bool::from(p.is_on_curve()));
+            assert!(bool::from(p.is_torsion_free()));
+        }
+
+        for _ in 0..100 {
+            // Points on the twist, which are almost never in the prime order subgroup.
+            let p = map_to_g2(Fq2::random(&mut rng));
+            assert!(!bool::from(p.is_torsion_free()));
+
+            let p = p.clear_cofactor();
+            assert!(bool::from(p.is_on_curve()));
+            assert!(bool::from(p.is_torsion_free()));
+        }
+    }
+
+    /// [r]P == O, computed with a plain double-and-add.
+    fn is_killed_by_r<G: Group<Scalar = Fr>>(p: G) -> bool {
+        crate::tests::group::reference_mul(p, -Fr::ONE) + p == G::identity()
+    }
+
+    #[test]
+    fn test_is_torsion_free() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        assert!(bool::from(G1::identity().is_torsion_free()));
+        assert!(bool::from(G1::generator().is_torsion_free()));
+        assert!(bool::from(G2::identity().is_torsion_free()));
+        assert!(bool::from(G2::generator().is_torsion_free()));
+
+        for _ in 0..20 {
+            let p = G1::random(&mut rng);
+            assert!(bool::from(p.is_torsion_free()));
+            assert!(bool::from(p.into_subgroup().is_some()));
+
+            let p = map_to_g1(Fq::random(&mut rng));
+            let in_subgroup = is_killed_by_r(p);
+            assert_eq!(bool::from(p.is_torsion_free()), in_subgroup);
+            assert_eq!(bool::from(p.into_subgroup().is_some()), in_subgroup);
+        }
+
+        for _ in 0..20 {
+            let p = G2::random(&mut rng);
+            assert!(bool::from(p.is_torsion_free()));
+            assert!(bool::from(p.into_subgroup().is_some()));
+
+            let p = map_to_g2(Fq2::random(&mut rng));
+            let in_subgroup = is_killed_by_r(p);
+            assert_eq!(bool::from(p.is_torsion_free()), in_subgroup);
+            assert_eq!(bool::from(p.into_subgroup().is_some()), in_subgroup);
+        }
+    }
+
+    #[test]
+    fn test_decoding_rejects_torsion() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..10 {
+            let p = map_to_g1(Fq::random(&mut rng)).to_affine();
+            assert!(!bool::from(p.to_curve().is_torsion_free()));
+
+            let compressed = p.to_bytes();
+            assert!(bool::from(G1Affine::from_bytes(&compressed).is_none()));
+            assert_eq!(G1Affine::from_bytes_unchecked(&compressed).unwrap(), p);
+
+            let uncompressed = p.to_uncompressed();
+            assert!(bool::from(
+                G1Affine::from_uncompressed(&uncompressed).is_none()
+            ));
+            assert_eq!(
+                G1Affine::from_uncompressed_unchecked(&uncompressed).unwrap(),
+                p
+            );
+        }
+
+        for _ in 0..10 {
+            let p = map_to_g2(Fq2::random(&mut rng)).to_affine();
+            assert!(!bool::from(p.to_curve().is_torsion_free()));
+
+            let compressed = p.to_bytes();
+            assert!(bool::from(G2Affine::from_bytes(&compressed).is_none()));
+            assert!(bool::from(G2::from_bytes(&compressed).is_none()));
+            assert_eq!(G2Affine::from_bytes_unchecked(&compressed).unwrap(), p);
+            assert_eq!(G2::from_bytes_unchecked(&compressed).unwrap(), p.to_curve());
+
+            let uncompressed = p.to_uncompressed();
+            assert!(bool::from(
+                G2Affine::from_uncompressed(&uncompressed).is_none()
+            ));
+            assert_eq!(
+                G2Affine::from_uncompressed_unchecked(&uncompressed).unwrap(),
+                p
+            );
+        }
+    }
+}
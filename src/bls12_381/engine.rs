@@ -0,0 +1,1558 @@
+#![allow(clippy::suspicious_arithmetic_impl)]
+use crate::bls12_381::curve::*;
+use crate::bls12_381::fq::*;
+use crate::bls12_381::fq12::*;
+use crate::bls12_381::fq2::*;
+use crate::bls12_381::fq6::Fq6;
+use crate::bls12_381::fr::*;
+use crate::ff::{Field, PrimeField};
+use crate::group::cofactor::CofactorCurveAffine;
+use crate::group::{Group, GroupEncoding};
+use crate::pairing::{Engine, MillerLoopResult, MultiMillerLoop, PairingCurveAffine};
+use crate::serde::SerdeObject;
+use core::borrow::Borrow;
+use core::iter::Sum;
+use core::ops::{Add, Mul, MulAssign, Neg, Sub};
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+#[cfg(feature = "derive_serde")]
+use serde::{Deserialize, Serialize};
+
+/// Absolute value of the curve parameter x, which is negative.
+pub const BLS_X: u64 = 0xd201_0000_0001_0000;
+
+impl PairingCurveAffine for G1Affine {
+    type Pair = G2Affine;
+    type PairingResult = Gt;
+
+    fn pairing_with(&self, other: &Self::Pair) -> Self::PairingResult {
+        pairing(self, other)
+    }
+}
+
+impl PairingCurveAffine for G2Affine {
+    type Pair = G1Affine;
+    type PairingResult = Gt;
+
+    fn pairing_with(&self, other: &Self::Pair) -> Self::PairingResult {
+        pairing(other, self)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Gt(pub(crate) Fq12);
+
+/// Canonical generator of $\mathbb{G}_T$, the pairing of the `G1` and `G2`
+/// generators.
+pub const GT_GENERATOR: Gt = Gt(Fq12 {
+    c0: Fq6 {
+        c0: Fq2 {
+            c0: Fq::from_raw([
+                0x9bdba96e84d54558,
+                0x448299a87dde3a64,
+                0x21d9931438907dfd,
+                0x6ff489dcda25e591,
+                0xb47a15fac1944252,
+                0x11619b45f61edfe3,
+            ]),
+            c1: Fq::from_raw([
+                0x3a394b8448d2be7f,
+                0xf76316218c0dfd58,
+                0xa3bf3bf22f277d70,
+                0x6a566f638b52d34b,
+                0x5ba8f275ef1137c5,
+                0x153ce14a76a53e20,
+            ]),
+        },
+        c1: Fq2 {
+            c0: Fq::from_raw([
+                0xba77bce995f04692,
+                0xff0b05a93e59c71f,
+                0xd4c272e9ac3f3ba6,
+                0x283b1c6ca98c047b,
+                0x0ed44767834c915b,
+                0x095668fb4a02fe93,
+            ]),
+            c1: Fq::from_raw([
+                0x09ea006b2afdeb5f,
+                0x413e7d958d179601,
+                0xfc5e248814782065,
+                0x036b86f53bb5b7f1,
+                0x7260085184d88f7d,
+                0x16deedaa683124fe,
+            ]),
+        },
+        c2: Fq2 {
+            c0: Fq::from_raw([
+                0x8c4bdde256cd6048,
+                0x121edc61839ccc90,
+                0x6a9ec0539be7a86b,
+                0x0314ed44ca5d30ce,
+                0xf9d34bc44eee0dd5,
+                0x09c92cf02f3cd3d2,
+            ]),
+            c1: Fq::from_raw([
+                0xe528781ab9e929c7,
+                0xa4dedced0811c34c,
+                0x0eae7e9b2a38d54f,
+                0x24fd8b93a47e41e6,
+                0x7ff825b04d21089e,
+                0x111061f398efc2a9,
+            ]),
+        },
+    },
+    c1: Fq6 {
+        c0: Fq2 {
+            c0: Fq::from_raw([
+                0x6c26ad9ba68f63bc,
+                0x8cfb4c94225e7f1b,
+                0x735192167ce19705,
+                0x4e007659dd5ffc4a,
+                0xb00b4709c33f1c9c,
+                0x01ecfcf31c86257a,
+            ]),
+            c1: Fq::from_raw([
+                0x645ccf725b32d26f,
+                0xd83f90d873567e9d,
+                0xdb76863e894b7a11,
+                0x7744a8ad8e2f9365,
+                0xa8193a166800b778,
+                0x08890726743a1f94,
+            ]),
+        },
+        c1: Fq2 {
+            c0: Fq::from_raw([
+                0xb0844bcd43646c10,
+                0x260eedf25446a086,
+                0x9556954fb227d3f1,
+                0xec29b3e2c5706266,
+                0xd258e9606bac08da,
+                0x0e61c752414ca5df,
+            ]),
+            c1: Fq::from_raw([
+                0x15164c00ab66bdde,
+                0x442beaff9da195ff,
+                0x33f75a05a0a2ce5c,
+                0x69e7e783043620db,
+                0x150fc498bbeea789,
+                0x0fe63f185f56dd29,
+            ]),
+        },
+        c2: Fq2 {
+            c0: Fq::from_raw([
+                0x691c566a8c474978,
+                0xd4801372db478987,
+                0xb5fc24f0000c5874,
+                0x717b7ee43900eee9,
+                0x7af211636f7cfdec,
+                0x10900338a92ed0b4,
+            ]),
+            c1: Fq::from_raw([
+                0x60a301af7776be3d,
+                0xc1ec8b888e59611f,
+                0x901dbd4d2095dd86,
+                0xce2007201536818c,
+                0x602247671bc408bb,
+                0x1454814f3085f0e6,
+            ]),
+        },
+    },
+});
+
+impl std::fmt::Display for Gt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl ConstantTimeEq for Gt {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConditionallySelectable for Gt {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Gt(Fq12::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl Eq for Gt {}
+impl PartialEq for Gt {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl Gt {
+    /// Returns the group identity, which is $1$.
+    pub fn identity() -> Gt {
+        Gt(Fq12::ONE)
+    }
+
+    /// Doubles this group element.
+    pub fn double(&self) -> Gt {
+        Gt(self.0.square())
+    }
+
+    /// Raises this element to `scalar` in variable time, using a width-5 NAF
+    /// of the scalar. Inversion in $\mathbb{G}_T$ is a conjugation, so
+    /// negative digits cost the same as positive ones.
+    ///
+    /// This is not constant time and must only be used with public scalars.
+    pub fn mul_vartime(&self, scalar: &Fr) -> Gt {
+        crate::arithmetic::mul_vartime(self, scalar)
+    }
+}
+
+impl Neg for &Gt {
+    type Output = Gt;
+
+    #[inline]
+    fn neg(self) -> Gt {
+        // The element is unitary, so we just conjugate.
+        let mut u = self.0;
+        u.conjugate();
+        Gt(u)
+    }
+}
+
+impl Neg for Gt {
+    type Output = Gt;
+
+    #[inline]
+    fn neg(self) -> Gt {
+        -&self
+    }
+}
+
+impl<'b> Add<&'b Gt> for &Gt {
+    type Output = Gt;
+
+    #[inline]
+    fn add(self, rhs: &'b Gt) -> Gt {
+        Gt(self.0 * rhs.0)
+    }
+}
+
+impl<'b> Sub<&'b Gt> for &Gt {
+    type Output = Gt;
+
+    #[inline]
+    fn sub(self, rhs: &'b Gt) -> Gt {
+        self + (-rhs)
+    }
+}
+
+impl<'b> Mul<&'b Fr> for &Gt {
+    type Output = Gt;
+
+    fn mul(self, other: &'b Fr) -> Self::Output {
+        let mut acc = Gt::identity();
+
+        for bit in other
+            .to_repr()
+            .as_ref()
+            .iter()
+            .rev()
+            .flat_map(|byte| (0..8).rev().map(move |i| Choice::from((byte >> i) & 1u8)))
+            .skip(1)
+        {
+            acc = acc.double();
+            acc = Gt::conditional_select(&acc, &(acc + self), bit);
+        }
+
+        acc
+    }
+}
+
+use crate::{
+    impl_add_binop_specify_output, impl_binops_additive, impl_binops_additive_specify_output,
+    impl_binops_multiplicative, impl_binops_multiplicative_mixed, impl_sub_binop_specify_output,
+};
+impl_binops_additive!(Gt, Gt);
+impl_binops_multiplicative!(Gt, Fr);
+
+impl<T> Sum<T> for Gt
+where
+    T: Borrow<Gt>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Self::identity(), |acc, item| acc + item.borrow())
+    }
+}
+
+impl Group for Gt {
+    type Scalar = Fr;
+
+    fn random(rng: impl RngCore) -> Self {
+        GT_GENERATOR * Fr::random(rng)
+    }
+
+    fn identity() -> Self {
+        Self::identity()
+    }
+
+    fn generator() -> Self {
+        GT_GENERATOR
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.ct_eq(&Self::identity())
+    }
+
+    fn double(&self) -> Self {
+        self.double()
+    }
+}
+
+/// Size of the compressed encoding of a `Gt` element, an `Fq6` element in
+/// the T2 torus representation.
+const GT_COMPRESSED_SIZE: usize = 288;
+
+/// Compressed encoding of a `Gt` element.
+#[derive(Copy, Clone)]
+pub struct GtCompressed([u8; GT_COMPRESSED_SIZE]);
+
+impl std::fmt::Debug for GtCompressed {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0[..].fmt(f)
+    }
+}
+
+impl Default for GtCompressed {
+    fn default() -> Self {
+        GtCompressed([0; GT_COMPRESSED_SIZE])
+    }
+}
+
+impl AsRef<[u8]> for GtCompressed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for GtCompressed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl Gt {
+    /// Returns whether this element lies in the subgroup of order r of
+    /// $\mathbb{F}_{q^{12}}^*$, which is $\mathbb{G}_T$.
+    pub fn is_torsion_free(&self) -> Choice {
+        // g^r = 1 if and only if g^(r - 1) = g^-1.
+        let inv = self.0.invert();
+        let g_r_minus_1 = self * -Fr::ONE;
+        inv.is_some() & g_r_minus_1.0.ct_eq(&inv.unwrap_or(Fq12::ONE))
+    }
+
+    fn coeffs(&self) -> [Fq2; 6] {
+        let Fq12 { c0, c1 } = self.0;
+        [c0.c0, c0.c1, c0.c2, c1.c0, c1.c1, c1.c2]
+    }
+
+    fn from_coeffs(coeffs: [Fq2; 6]) -> Gt {
+        let [c00, c01, c02, c10, c11, c12] = coeffs;
+        Gt(Fq12 {
+            c0: Fq6 {
+                c0: c00,
+                c1: c01,
+                c2: c02,
+            },
+            c1: Fq6 {
+                c0: c10,
+                c1: c11,
+                c2: c12,
+            },
+        })
+    }
+}
+
+/// `v` as an element of `Fq6`, with `w^2 = v` in `Fq12`.
+const FQ6_V: Fq6 = Fq6 {
+    c0: Fq2::zero(),
+    c1: Fq2::one(),
+    c2: Fq2::zero(),
+};
+
+// The cyclotomic elements c0 + c1 * w other than -1 are written (m + w) / (m - w)
+// with m = (1 + c0) / c1 in Fq6, halving their size. The identity is encoded as
+// m = 0, which would otherwise stand for -1, an element outside of Gt.
+impl GroupEncoding for Gt {
+    type Repr = GtCompressed;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_bytes_unchecked(bytes).and_then(|g| CtOption::new(g, g.is_torsion_free()))
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        let mut m = [Fq2::zero(); 3];
+        let mut is_canonical = Choice::from(1u8);
+        for (i, c) in m.iter_mut().enumerate() {
+            let mut buf = [0u8; 96];
+            buf.copy_from_slice(&bytes.0[i * 96..(i + 1) * 96]);
+            let decoded = Fq2::from_bytes(&buf);
+            is_canonical &= decoded.is_some();
+            *c = decoded.unwrap_or(Fq2::zero());
+        }
+        let m = Fq6 {
+            c0: m[0],
+            c1: m[1],
+            c2: m[2],
+        };
+
+        // (m + w) / (m - w) = (m^2 + v + 2m * w) / (m^2 - v), where m^2 - v is
+        // never zero as v is not a square in Fq6.
+        let m2 = m.square();
+        let den = (m2 - FQ6_V).invert().unwrap();
+        let g = Gt(Fq12 {
+            c0: (m2 + FQ6_V) * den,
+            c1: m.double() * den,
+        });
+        CtOption::new(
+            Gt::conditional_select(&g, &Gt::identity(), m.is_zero()),
+            is_canonical,
+        )
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        // c1 is only zero for the identity, which gives m = 0.
+        let m = (self.0.c0 + Fq6::one()) * self.0.c1.invert().unwrap_or(Fq6::zero());
+        let mut bytes = [0u8; GT_COMPRESSED_SIZE];
+        for (i, c) in [m.c0, m.c1, m.c2].iter().enumerate() {
+            bytes[i * 96..(i + 1) * 96].copy_from_slice(&c.to_bytes());
+        }
+        GtCompressed(bytes)
+    }
+}
+
+impl SerdeObject for Gt {
+    fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
+        debug_assert_eq!(bytes.len(), 576);
+        let mut coeffs = [Fq2::zero(); 6];
+        for (i, c) in coeffs.iter_mut().enumerate() {
+            *c = Fq2::from_raw_bytes_unchecked(&bytes[i * 96..(i + 1) * 96]);
+        }
+        Self::from_coeffs(coeffs)
+    }
+    fn from_raw_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 576 {
+            return None;
+        }
+        let mut coeffs = [Fq2::zero(); 6];
+        for (i, c) in coeffs.iter_mut().enumerate() {
+            *c = Fq2::from_raw_bytes(&bytes[i * 96..(i + 1) * 96])?;
+        }
+        let g = Self::from_coeffs(coeffs);
+        bool::from(g.is_torsion_free()).then_some(g)
+    }
+    fn to_raw_bytes(&self) -> Vec<u8> {
+        let mut res = Vec::with_capacity(576);
+        for c in self.coeffs().iter() {
+            res.extend_from_slice(&c.to_raw_bytes());
+        }
+        res
+    }
+    fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
+        Self::from_coeffs([(); 6].map(|_| Fq2::read_raw_unchecked(reader)))
+    }
+    fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut coeffs = [Fq2::zero(); 6];
+        for c in coeffs.iter_mut() {
+            *c = Fq2::read_raw(reader)?;
+        }
+        let g = Self::from_coeffs(coeffs);
+        bool::from(g.is_torsion_free()).then_some(g).ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "element is not in the subgroup of order r",
+            )
+        })
+    }
+    fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        for c in self.coeffs().iter() {
+            c.write_raw(writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+pub struct G2Prepared {
+    pub(crate) coeffs: Vec<(Fq2, Fq2, Fq2)>,
+    pub(crate) infinity: bool,
+}
+
+impl G2Prepared {
+    pub fn is_zero(&self) -> bool {
+        self.infinity
+    }
+
+    pub fn from_affine(q: G2Affine) -> Self {
+        if bool::from(q.is_identity()) {
+            return G2Prepared {
+                coeffs: vec![],
+                infinity: true,
+            };
+        }
+
+        fn doubling_step(r: &mut G2) -> (Fq2, Fq2, Fq2) {
+            // Adaptation of Algorithm 26, https://eprint.iacr.org/2010/354.pdf
+            let mut tmp0 = r.x;
+            tmp0.square_assign();
+
+            let mut tmp1 = r.y;
+            tmp1.square_assign();
+
+            let mut tmp2 = tmp1;
+            tmp2.square_assign();
+
+            let mut tmp3 = tmp1;
+            tmp3 += &r.x;
+            tmp3.square_assign();
+            tmp3 -= &tmp0;
+            tmp3 -= &tmp2;
+            tmp3.double_assign();
+
+            let mut tmp4 = tmp0;
+            tmp4.double_assign();
+            tmp4 += &tmp0;
+
+            let mut tmp6 = r.x;
+            tmp6 += &tmp4;
+
+            let mut tmp5 = tmp4;
+            tmp5.square_assign();
+
+            let mut zsquared = r.z;
+            zsquared.square_assign();
+
+            r.x = tmp5;
+            r.x -= &tmp3;
+            r.x -= &tmp3;
+
+            r.z += &r.y;
+            r.z.square_assign();
+            r.z -= &tmp1;
+            r.z -= &zsquared;
+
+            r.y = tmp3;
+            r.y -= &r.x;
+            r.y.mul_assign(&tmp4);
+
+            tmp2.double_assign();
+            tmp2.double_assign();
+            tmp2.double_assign();
+
+            r.y -= &tmp2;
+
+            // up to here everything was by algorith, line 11
+            // use R instead of new T
+
+            // tmp3 is the first part of line 12
+            tmp3 = tmp4;
+            tmp3.mul_assign(&zsquared);
+            tmp3.double_assign();
+            tmp3 = tmp3.neg();
+
+            // tmp6 is from line 14
+            tmp6.square_assign();
+            tmp6 -= &tmp0;
+            tmp6 -= &tmp5;
+
+            tmp1.double_assign();
+            tmp1.double_assign();
+
+            tmp6 -= &tmp1;
+
+            // tmp0 is the first part of line 16
+            tmp0 = r.z;
+            tmp0.mul_assign(&zsquared);
+            tmp0.double_assign();
+
+            (tmp0, tmp3, tmp6)
+        }
+
+        fn addition_step(r: &mut G2, q: &G2Affine) -> (Fq2, Fq2, Fq2) {
+            // Adaptation of Algorithm 27, https://eprint.iacr.org/2010/354.pdf
+            let mut zsquared = r.z;
+            zsquared.square_assign();
+
+            let mut ysquared = q.y;
+            ysquared.square_assign();
+
+            // t0 corresponds to line 1
+            let mut t0 = zsquared;
+            t0.mul_assign(&q.x);
+
+            // t1 corresponds to lines 2 and 3
+            let mut t1 = q.y;
+            t1 += &r.z;
+            t1.square_assign();
+            t1 -= &ysquared;
+            t1 -= &zsquared;
+            t1.mul_assign(&zsquared);
+
+            // t2 corresponds to line 4
+            let mut t2 = t0;
+            t2 -= &r.x;
+
+            // t3 corresponds to line 5
+            let mut t3 = t2;
+            t3.square_assign();
+
+            // t4 corresponds to line 6
+            let mut t4 = t3;
+            t4.double_assign();
+            t4.double_assign();
+
+            // t5 corresponds to line 7
+            let mut t5 = t4;
+            t5.mul_assign(&t2);
+
+            // t6 corresponds to line 8
+            let mut t6 = t1;
+            t6 -= &r.y;
+            t6 -= &r.y;
+
+            // t9 corresponds to line 9
+            let mut t9 = t6;
+            t9.mul_assign(&q.x);
+
+            // corresponds to line 10
+            let mut t7 = t4;
+            t7.mul_assign(&r.x);
+
+            // corresponds to line 11, but assigns to r.x instead of T.x
+            r.x = t6;
+            r.x.square_assign();
+            r.x -= &t5;
+            r.x -= &t7;
+            r.x -= &t7;
+
+            // corresponds to line 12, but assigns to r.z instead of T.z
+            r.z += &t2;
+            r.z.square_assign();
+            r.z -= &zsquared;
+            r.z -= &t3;
+
+            // corresponds to line 13
+            let mut t10 = q.y;
+            t10 += &r.z;
+
+            // corresponds to line 14
+            let mut t8 = t7;
+            t8 -= &r.x;
+            t8.mul_assign(&t6);
+
+            // corresponds to line 15
+            t0 = r.y;
+            t0.mul_assign(&t5);
+            t0.double_assign();
+
+            // corresponds to line 12, but assigns to r.y instead of T.y
+            r.y = t8;
+            r.y -= &t0;
+
+            // corresponds to line 17
+            t10.square_assign();
+            t10 -= &ysquared;
+
+            let mut ztsquared = r.z;
+            ztsquared.square_assign();
+
+            t10 -= &ztsquared;
+
+            // corresponds to line 18
+            t9.double_assign();
+            t9 -= &t10;
+
+            // t10 = 2*Zt from Algo 27, line 19
+            t10 = r.z;
+            t10.double_assign();
+
+            // t1 = first multiplicator of line 21
+            t6 = t6.neg();
+
+            t1 = t6;
+            t1.double_assign();
+
+            // t9 corresponds to t9 from Algo 27
+            (t10, t1, t9)
+        }
+
+        let mut coeffs = vec![];
+        let mut r: G2 = q.into();
+
+        // The loop runs over |x|, the sign of x being accounted for at the end
+        // of the Miller loop.
+        for i in (0..63 - BLS_X.leading_zeros()).rev() {
+            coeffs.push(doubling_step(&mut r));
+            if (BLS_X >> i) & 1 == 1 {
+                coeffs.push(addition_step(&mut r, &q));
+            }
+        }
+
+        G2Prepared {
+            coeffs,
+            infinity: false,
+        }
+    }
+}
+
+/// Number of line coefficients of a prepared point other than the identity: a
+/// doubling step per bit of |x| but the top one and an addition step per set
+/// bit but the top one.
+const G2_PREPARED_NUM_COEFFS: usize =
+    (63 - BLS_X.leading_zeros() + BLS_X.count_ones() - 1) as usize;
+
+/// Size of the raw encoding of a line coefficient.
+const G2_PREPARED_COEFF_SIZE: usize = 3 * 96;
+
+// The raw encoding is a flag byte, 1 for the identity and 0 otherwise,
+// followed by the raw line coefficients, of which the identity has none.
+impl SerdeObject for G2Prepared {
+    fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
+        let coeffs = bytes[1..]
+            .chunks(G2_PREPARED_COEFF_SIZE)
+            .map(|c| {
+                let [c0, c1, c2] =
+                    [0, 96, 192].map(|i| Fq2::from_raw_bytes_unchecked(&c[i..i + 96]));
+                (c0, c1, c2)
+            })
+            .collect();
+        G2Prepared {
+            coeffs,
+            infinity: bytes[0] == 1,
+        }
+    }
+    fn from_raw_bytes(bytes: &[u8]) -> Option<Self> {
+        let infinity = match bytes.first() {
+            Some(0) => false,
+            Some(1) => true,
+            _ => return None,
+        };
+        let num_coeffs = if infinity { 0 } else { G2_PREPARED_NUM_COEFFS };
+        if bytes.len() != 1 + num_coeffs * G2_PREPARED_COEFF_SIZE {
+            return None;
+        }
+
+        let mut coeffs = Vec::with_capacity(num_coeffs);
+        for c in bytes[1..].chunks(G2_PREPARED_COEFF_SIZE) {
+            let c0 = Fq2::from_raw_bytes(&c[..96])?;
+            let c1 = Fq2::from_raw_bytes(&c[96..192])?;
+            let c2 = Fq2::from_raw_bytes(&c[192..])?;
+            coeffs.push((c0, c1, c2));
+        }
+        Some(G2Prepared { coeffs, infinity })
+    }
+    fn to_raw_bytes(&self) -> Vec<u8> {
+        let mut res = Vec::with_capacity(1 + self.coeffs.len() * G2_PREPARED_COEFF_SIZE);
+        res.push(self.infinity as u8);
+        for (c0, c1, c2) in self.coeffs.iter() {
+            res.extend_from_slice(&c0.to_raw_bytes());
+            res.extend_from_slice(&c1.to_raw_bytes());
+            res.extend_from_slice(&c2.to_raw_bytes());
+        }
+        res
+    }
+    fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
+        let mut flag = [0u8];
+        reader.read_exact(&mut flag).unwrap();
+        let infinity = flag[0] == 1;
+        let num_coeffs = if infinity { 0 } else { G2_PREPARED_NUM_COEFFS };
+        let coeffs = (0..num_coeffs)
+            .map(|_| {
+                let [c0, c1, c2] = [(); 3].map(|_| Fq2::read_raw_unchecked(reader));
+                (c0, c1, c2)
+            })
+            .collect();
+        G2Prepared { coeffs, infinity }
+    }
+    fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut flag = [0u8];
+        reader.read_exact(&mut flag)?;
+        let infinity = match flag[0] {
+            0 => false,
+            1 => true,
+            _ => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "invalid infinity flag",
+                ))
+            }
+        };
+        let num_coeffs = if infinity { 0 } else { G2_PREPARED_NUM_COEFFS };
+
+        let mut coeffs = Vec::with_capacity(num_coeffs);
+        for _ in 0..num_coeffs {
+            let c0 = Fq2::read_raw(reader)?;
+            let c1 = Fq2::read_raw(reader)?;
+            let c2 = Fq2::read_raw(reader)?;
+            coeffs.push((c0, c1, c2));
+        }
+        Ok(G2Prepared { coeffs, infinity })
+    }
+    fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&[self.infinity as u8])?;
+        for (c0, c1, c2) in self.coeffs.iter() {
+            c0.write_raw(writer)?;
+            c1.write_raw(writer)?;
+            c2.write_raw(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<G2Affine> for G2Prepared {
+    fn from(q: G2Affine) -> G2Prepared {
+        G2Prepared::from_affine(q)
+    }
+}
+
+impl MillerLoopResult for Gt {
+    type Gt = Self;
+
+    fn final_exponentiation(&self) -> Gt {
+        // The hard part raises to exactly (p^4 - p^2 + 1) / r, which is
+        // (x - 1)^2 / 3 (x + p) (x^2 + p^2 - 1) + 1 with (x - 1)^2 / 3 an
+        // integer as x = 1 mod 3. The chain of Hayashida, Hayasaka and Teruya
+        // used by zkcrypto and blst saves the division by 3 but yields the cube
+        // of this, which is not the standard pairing.
+        //
+        // x is sparse enough for Karabina's compressed squarings to pay off.
+        fn exp_by_neg_x(f: &Fq12) -> Fq12 {
+            let mut f = f.cyclotomic_exp_compressed(BLS_X);
+            // x is negative
+            f.conjugate();
+            f
+        }
+
+        let mut f1 = self.0;
+        f1.conjugate();
+
+        Gt(self
+            .0
+            .invert()
+            .map(|f2| {
+                // Easy part: f^((p^6 - 1) (p^2 + 1))
+                let mut r = f1 * f2;
+                let t = r;
+                r.frobenius_map(2);
+                r.mul_assign(&t);
+
+                // t0 = r^((x - 1)^2 / 3) = r^((1 - x) (1 - x) / 3)
+                let t0 = r.cyclotomic_exp((BLS_X + 1) / 3);
+                let mut t1 = t0;
+                t1.conjugate();
+                let t0 = t0 * exp_by_neg_x(&t1);
+
+                // t0^(x + p)
+                let mut t1 = t0;
+                t1.frobenius_map(1);
+                let t0 = exp_by_neg_x(&t0) * t1;
+
+                // t0^(x^2 + p^2 - 1)
+                let mut t1 = t0;
+                t1.frobenius_map(2);
+                let mut t2 = t0;
+                t2.conjugate();
+                let t0 = exp_by_neg_x(&exp_by_neg_x(&t0)) * t1 * t2;
+
+                t0 * r
+            })
+            .unwrap())
+    }
+}
+
+pub fn multi_miller_loop(terms: &[(&G1Affine, &G2Prepared)]) -> Gt {
+    let mut pairs = vec![];
+    for &(p, q) in terms {
+        if !bool::from(p.is_identity()) && !q.is_zero() {
+            pairs.push((p, q.coeffs.iter()));
+        }
+    }
+
+    // Final steps of the line function on prepared coefficients
+    fn ell(f: &mut Fq12, coeffs: &(Fq2, Fq2, Fq2), p: &G1Affine) {
+        let mut c0 = coeffs.0;
+        let mut c1 = coeffs.1;
+
+        c0.c0.mul_assign(&p.y);
+        c0.c1.mul_assign(&p.y);
+
+        c1.c0.mul_assign(&p.x);
+        c1.c1.mul_assign(&p.x);
+
+        // Sparse multiplication in Fq12, the twist being an M-type one
+        f.mul_by_014(&coeffs.2, &c1, &c0);
+    }
+
+    let mut f = Fq12::ONE;
+
+    let top = 63 - BLS_X.leading_zeros();
+    for i in (0..top).rev() {
+        if i != top - 1 {
+            f.square_assign();
+        }
+        for &mut (p, ref mut coeffs) in &mut pairs {
+            ell(&mut f, coeffs.next().unwrap(), p);
+        }
+        if (BLS_X >> i) & 1 == 1 {
+            for &mut (p, ref mut coeffs) in &mut pairs {
+                ell(&mut f, coeffs.next().unwrap(), p);
+            }
+        }
+    }
+
+    for &mut (_p, ref mut coeffs) in &mut pairs {
+        assert_eq!(coeffs.next(), None);
+    }
+
+    // x is negative
+    f.conjugate();
+
+    Gt(f)
+}
+
+/// Computes the same result as `multi_miller_loop`, running the Miller loop of
+/// each share of the terms on its own thread and multiplying the partial
+/// results, which is exact as every step of the loop is multiplicative.
+pub fn multi_miller_loop_parallel(terms: &[(&G1Affine, &G2Prepared)]) -> Gt {
+    #[cfg(feature = "multicore")]
+    {
+        use rayon::prelude::*;
+
+        let chunk_size = terms.len() / rayon::current_num_threads() + 1;
+        terms
+            .par_chunks(chunk_size)
+            .map(multi_miller_loop)
+            .reduce(Gt::identity, |a, b| Gt(a.0 * b.0))
+    }
+    #[cfg(not(feature = "multicore"))]
+    multi_miller_loop(terms)
+}
+
+pub fn pairing(g1: &G1Affine, g2: &G2Affine) -> Gt {
+    let g2 = G2Prepared::from_affine(*g2);
+    let terms: &[(&G1Affine, &G2Prepared)] = &[(g1, &g2)];
+    let u = multi_miller_loop(terms);
+    u.final_exponentiation()
+}
+
+#[derive(Clone, Debug)]
+pub struct Bls12381;
+
+impl Engine for Bls12381 {
+    type Scalar = Fr;
+    type G1 = G1;
+    type G1Affine = G1Affine;
+    type G2 = G2;
+    type G2Affine = G2Affine;
+    type Gt = Gt;
+
+    fn pairing(p: &Self::G1Affine, q: &Self::G2Affine) -> Self::Gt {
+        pairing(p, q)
+    }
+}
+
+impl MultiMillerLoop for Bls12381 {
+    type G2Prepared = G2Prepared;
+    type Result = Gt;
+
+    fn multi_miller_loop(terms: &[(&Self::G1Affine, &Self::G2Prepared)]) -> Self::Result {
+        multi_miller_loop(terms)
+    }
+
+    fn multi_miller_loop_parallel(terms: &[(&Self::G1Affine, &Self::G2Prepared)]) -> Self::Result {
+        multi_miller_loop_parallel(terms)
+    }
+
+    fn pairing_check(terms: &[(&Self::G1Affine, &Self::G2Prepared)]) -> bool {
+        // Pairs with an identity input contribute a factor of one.
+        if terms
+            .iter()
+            .all(|(p, q)| bool::from(p.is_identity()) || q.is_zero())
+        {
+            return true;
+        }
+        Self::pairing_check_ct(terms).into()
+    }
+
+    fn pairing_check_ct(terms: &[(&Self::G1Affine, &Self::G2Prepared)]) -> Choice {
+        // The final exponentiation lands in the subgroup of order r of the
+        // cyclotomic subgroup, where c0 + c1 * w with c1 = 0 forces c0^2 = 1 and
+        // hence c0 = 1. Checking c1 is therefore enough.
+        multi_miller_loop(terms)
+            .final_exponentiation()
+            .0
+            .c1
+            .is_zero()
+    }
+}
+
+#[cfg(test)]
+use rand::SeedableRng;
+#[cfg(test)]
+use rand_xorshift::XorShiftRng;
+
+#[test]
+fn test_final_exponentiation_power() {
+    // (p^4 - p^2 + 1) / r
+    const HARD_EXP: [u64; 20] = [
+        0xe516c3f438e3ba79,
+        0xfa9912aae208ccf1,
+        0x905ce937335d5b68,
+        0xc71a2629b0dea236,
+        0x83774940996754c8,
+        0x21d160aeb6a1e799,
+        0x2ed0b283ed237db4,
+        0x915c97f36c6f1821,
+        0x67f17fcbde783765,
+        0x2378b9039096d1b7,
+        0x7988f8761bdc51dc,
+        0x2076995003fc77a1,
+        0x827eca0ba621315b,
+        0xe5a72bce8d63cb9f,
+        0xf68f7764c28b6f8a,
+        0x2f230063cf081517,
+        0x94506632528d6a9a,
+        0xd3cde88eeb996ca3,
+        0xc0bd38c3195c899e,
+        0x000f686b3d807d01,
+    ];
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+    for _ in 0..10 {
+        let a = G1Affine::from(G1::random(&mut rng));
+        let b = G2Prepared::from(G2Affine::from(G2::random(&mut rng)));
+        let f = multi_miller_loop(&[(&a, &b)]);
+
+        // f^((p^6 - 1) (p^2 + 1))
+        let mut easy = f.0;
+        easy.conjugate();
+        easy.mul_assign(&f.0.invert().unwrap());
+        let t = easy;
+        easy.frobenius_map(2);
+        easy.mul_assign(&t);
+
+        assert_eq!(f.final_exponentiation().0, easy.pow_vartime(HARD_EXP));
+    }
+}
+
+#[test]
+fn test_pairing_zkcrypto() {
+    // `Gt::generator()` of zkcrypto's `bls12_381` crate, whose final
+    // exponentiation raises to 3 (p^4 - p^2 + 1) / r.
+    let zkcrypto = Gt(Fq12 {
+        c0: Fq6 {
+            c0: Fq2 {
+                c0: Fq::from_raw([
+                    0xa84305aaca1789b6,
+                    0xb6d194f60839c508,
+                    0x3dd8e90ce98db3e7,
+                    0x272d441befa15c50,
+                    0xa7b2d83168d0d727,
+                    0x1250ebd871fc0a92,
+                ]),
+                c1: Fq::from_raw([
+                    0x59882a98eaa0170f,
+                    0xf1a8943e50439f1d,
+                    0xaf5af689452eafab,
+                    0x68a84045483c92b7,
+                    0x86750ec6a5323488,
+                    0x089a1c5b46e5110b,
+                ]),
+            },
+            c1: Fq2 {
+                c0: Fq::from_raw([
+                    0x881c4c849ec23e87,
+                    0xddff57309396b38c,
+                    0x16da0e22a5031b54,
+                    0x0378a68e72a6b3b2,
+                    0x9703f239689ce34c,
+                    0x1368bb445c7c2d20,
+                ]),
+                c1: Fq::from_raw([
+                    0x315021ec3c19934f,
+                    0xffe51d7a579973b1,
+                    0x7c90d8bd66065b1f,
+                    0x37e0794e1e65a761,
+                    0xc273fa075a505129,
+                    0x193502b86edb8857,
+                ]),
+            },
+            c2: Fq2 {
+                c0: Fq::from_raw([
+                    0x1dad1c1fb597aaa5,
+                    0x19c34dffbbaad843,
+                    0x185203fcca589ac7,
+                    0xfbf2f8da752f7c74,
+                    0x91125ba84dc4007c,
+                    0x01b2f522473d1713,
+                ]),
+                c1: Fq::from_raw([
+                    0x8beae9624045b4b6,
+                    0x23f7dacaa35c8ca7,
+                    0x8061e55cceba478b,
+                    0x46da634b8f6be14a,
+                    0xbd3c79937a45b845,
+                    0x018107154f25a764,
+                ]),
+            },
+        },
+        c1: Fq6 {
+            c0: Fq2 {
+                c0: Fq::from_raw([
+                    0x0f948226e47ee89d,
+                    0xbb12d58386a8703e,
+                    0xdea54d43b2b73f2c,
+                    0xc88784fbb3d0b2db,
+                    0x9cd6bd15c3d5a04d,
+                    0x19f26337d205fb46,
+                ]),
+                c1: Fq::from_raw([
+                    0x102ae1c2d5d5ab1a,
+                    0x1bfd1b68ff02f0b8,
+                    0xa7d2809d61bfe02e,
+                    0xd5857baaf222eb95,
+                    0x9f80940ca771b6ff,
+                    0x06fba23eb7c5af0d,
+                ]),
+            },
+            c1: Fq2 {
+                c0: Fq::from_raw([
+                    0x1b93b47333e2ba57,
+                    0x78ef48881e32fac9,
+                    0x7d0d15ff7b984e89,
+                    0xc81a93b330ee1a67,
+                    0xfcef68083b0b0ec5,
+                    0x11b8b424cd48bf38,
+                ]),
+                c1: Fq::from_raw([
+                    0xbe2291a0c25a99a2,
+                    0x7ba810c5a09ffdd9,
+                    0x20c806ad36082910,
+                    0xc6a0e9786ab59733,
+                    0xc31b4fcb6ce5771c,
+                    0x03350f55a7aefcd3,
+                ]),
+            },
+            c2: Fq2 {
+                c0: Fq::from_raw([
+                    0x9108f0242d0fe3ef,
+                    0xa4fafc05066245cb,
+                    0x1c7cdba7b3872629,
+                    0xa189e87935a95405,
+                    0x02249b64728ffd21,
+                    0x04c581234d086a99,
+                ]),
+                c1: Fq::from_raw([
+                    0xfde449383b676631,
+                    0xd48eaa24afe47e1e,
+                    0xdeff686bfd6df543,
+                    0x3baca4d72ca93544,
+                    0x068672cbd01a7ec7,
+                    0x0f41e58663bf08cf,
+                ]),
+            },
+        },
+    });
+
+    let g = pairing(&G1Affine::generator(), &G2Affine::generator());
+    assert_eq!(g * Fr::from(3), zkcrypto);
+    assert_eq!(g, GT_GENERATOR);
+}
+
+#[test]
+fn test_gt() {
+    crate::tests::group::group_tests::<Gt>();
+
+    assert_eq!(
+        GT_GENERATOR,
+        pairing(&G1Affine::generator(), &G2Affine::generator())
+    );
+    assert_eq!(Gt::generator() * -Fr::ONE, -Gt::generator());
+}
+
+#[test]
+fn test_pairing() {
+    let g1 = G1::generator();
+    let mut g2 = G2::generator();
+    g2 = g2.double();
+    let pair12 = Bls12381::pairing(&G1Affine::from(g1), &G2Affine::from(g2));
+
+    let mut g1 = G1::generator();
+    let g2 = G2::generator();
+    g1 = g1.double();
+    let pair21 = Bls12381::pairing(&G1Affine::from(g1), &G2Affine::from(g2));
+
+    assert_eq!(pair12, pair21);
+
+    let g1 = G1::generator();
+    let mut g2 = G2::generator();
+    g2 = g2.double().double();
+    let pair12 = Bls12381::pairing(&G1Affine::from(g1), &G2Affine::from(g2));
+
+    let mut g1 = G1::generator();
+    let mut g2 = G2::generator();
+    g1 = g1.double();
+    g2 = g2.double();
+    let pair21 = Bls12381::pairing(&G1Affine::from(g1), &G2Affine::from(g2));
+
+    assert_eq!(pair12, pair21);
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+    for _ in 0..100 {
+        let a = Fr::random(&mut rng);
+        let b = Fr::random(&mut rng);
+
+        let mut g1 = G1::generator();
+        g1.mul_assign(a);
+
+        let mut g2 = G2::generator();
+        g2.mul_assign(b);
+
+        let pair_ab = Bls12381::pairing(&G1Affine::from(g1), &G2Affine::from(g2));
+
+        g1 = G1::generator();
+        g1.mul_assign(b);
+
+        g2 = G2::generator();
+        g2.mul_assign(a);
+
+        let pair_ba = Bls12381::pairing(&G1Affine::from(g1), &G2Affine::from(g2));
+
+        assert_eq!(pair_ab, pair_ba);
+    }
+}
+
+#[test]
+fn random_bilinearity_tests() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    for _ in 0..100 {
+        let mut a = G1::generator();
+        let ka = Fr::random(&mut rng);
+        a.mul_assign(ka);
+
+        let mut b = G2::generator();
+        let kb = Fr::random(&mut rng);
+        b.mul_assign(kb);
+
+        let c = Fr::random(&mut rng);
+        let d = Fr::random(&mut rng);
+
+        let mut ac = a;
+        ac.mul_assign(c);
+
+        let mut ad = a;
+        ad.mul_assign(d);
+
+        let mut bc = b;
+        bc.mul_assign(c);
+
+        let mut bd = b;
+        bd.mul_assign(d);
+
+        let acbd = Bls12381::pairing(&G1Affine::from(ac), &G2Affine::from(bd));
+        let adbc = Bls12381::pairing(&G1Affine::from(ad), &G2Affine::from(bc));
+
+        let mut cd = c;
+        cd.mul_assign(&d);
+
+        cd *= Fr([1, 0, 0, 0]);
+
+        let abcd = Gt(Bls12381::pairing(&G1Affine::from(a), &G2Affine::from(b))
+            .0
+            .pow_vartime(cd.0));
+
+        assert_eq!(acbd, adbc);
+        assert_eq!(acbd, abcd);
+    }
+}
+
+#[test]
+pub fn engine_tests() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    for _ in 0..10 {
+        let a = G1Affine::from(G1::random(&mut rng));
+        let b = G2Affine::from(G2::random(&mut rng));
+
+        assert!(a.pairing_with(&b) == b.pairing_with(&a));
+        assert!(a.pairing_with(&b) == pairing(&a, &b));
+    }
+
+    for _ in 0..100 {
+        let z1 = G1Affine::identity();
+        let z2 = G2Prepared::from(G2Affine::identity());
+
+        let a = G1Affine::from(G1::random(&mut rng));
+        let b = G2Prepared::from(G2Affine::from(G2::random(&mut rng)));
+        let c = G1Affine::from(G1::random(&mut rng));
+        let d = G2Prepared::from(G2Affine::from(G2::random(&mut rng)));
+
+        assert_eq!(
+            Fq12::ONE,
+            multi_miller_loop(&[(&z1, &b)]).final_exponentiation().0,
+        );
+
+        assert_eq!(
+            Fq12::ONE,
+            multi_miller_loop(&[(&a, &z2)]).final_exponentiation().0,
+        );
+
+        assert_eq!(
+            multi_miller_loop(&[(&z1, &b), (&c, &d)]).final_exponentiation(),
+            multi_miller_loop(&[(&a, &z2), (&c, &d)]).final_exponentiation(),
+        );
+
+        assert_eq!(
+            multi_miller_loop(&[(&a, &b), (&z1, &d)]).final_exponentiation(),
+            multi_miller_loop(&[(&a, &b), (&c, &z2)]).final_exponentiation(),
+        );
+    }
+}
+
+#[test]
+fn random_miller_loop_tests() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    // Exercise a double miller loop
+    for _ in 0..100 {
+        let a = G1Affine::from(G1::random(&mut rng));
+        let b = G2Affine::from(G2::random(&mut rng));
+        let c = G1Affine::from(G1::random(&mut rng));
+        let d = G2Affine::from(G2::random(&mut rng));
+
+        let ab = pairing(&a, &b);
+        let cd = pairing(&c, &d);
+
+        let mut abcd = ab;
+        abcd = Gt(abcd.0 * cd.0);
+
+        let b = G2Prepared::from(b);
+        let d = G2Prepared::from(d);
+
+        let abcd_with_double_loop = multi_miller_loop(&[(&a, &b), (&c, &d)]).final_exponentiation();
+
+        assert_eq!(abcd, abcd_with_double_loop);
+    }
+}
+
+#[test]
+fn test_pairing_check() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    assert!(Bls12381::pairing_check(&[]));
+    assert!(bool::from(Bls12381::pairing_check_ct(&[])));
+
+    for _ in 0..10 {
+        let s = Fr::random(&mut rng);
+        let a = G1Affine::from(G1::random(&mut rng));
+        let b = G2Affine::from(G2::random(&mut rng));
+
+        let sa = G1Affine::from(a * s);
+        let sb = G2Prepared::from(G2Affine::from(b * s));
+        let b = G2Prepared::from(b);
+        let identity = G2Prepared::from(G2Affine::identity());
+
+        // e(s * a, b) * e(-a, s * b) = 1
+        let neg_a = -a;
+        assert!(Bls12381::pairing_check(&[(&sa, &b), (&neg_a, &sb)]));
+        assert!(bool::from(Bls12381::pairing_check_ct(&[
+            (&sa, &b),
+            (&neg_a, &sb)
+        ])));
+        assert!(Bls12381::pairing_eq(&sa, &b, &a, &sb));
+
+        assert!(!Bls12381::pairing_check(&[(&sa, &b), (&a, &sb)]));
+        assert!(!bool::from(Bls12381::pairing_check_ct(&[
+            (&sa, &b),
+            (&a, &sb)
+        ])));
+        assert!(!Bls12381::pairing_eq(&sa, &b, &a, &b));
+
+        assert!(Bls12381::pairing_check(&[
+            (&G1Affine::identity(), &b),
+            (&a, &identity)
+        ]));
+        assert!(!Bls12381::pairing_check(&[
+            (&G1Affine::identity(), &b),
+            (&a, &sb)
+        ]));
+    }
+}
+
+#[test]
+fn test_gt_serialization() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let mut elements = vec![Gt::identity(), Gt::generator(), -Gt::generator()];
+    elements.extend((0..20).map(|_| Gt::random(&mut rng)));
+    for g in elements {
+        assert!(bool::from(g.is_torsion_free()));
+
+        let bytes = g.to_bytes();
+        assert_eq!(Gt::from_bytes(&bytes).unwrap(), g);
+        assert_eq!(Gt::from_bytes_unchecked(&bytes).unwrap(), g);
+
+        let raw = g.to_raw_bytes();
+        assert_eq!(raw.len(), 576);
+        assert_eq!(Gt::from_raw_bytes(&raw), Some(g));
+        assert_eq!(Gt::from_raw_bytes_unchecked(&raw), g);
+        let mut buf = Vec::new();
+        g.write_raw(&mut buf).unwrap();
+        assert_eq!(Gt::read_raw(&mut &buf[..]).unwrap(), g);
+        assert_eq!(Gt::read_raw_unchecked(&mut &buf[..]), g);
+    }
+
+    // Random torus elements have norm one but are almost never of order r.
+    for _ in 0..10 {
+        let mut bytes = GtCompressed::default();
+        for (i, c) in [
+            Fq2::random(&mut rng),
+            Fq2::random(&mut rng),
+            Fq2::random(&mut rng),
+        ]
+        .iter()
+        .enumerate()
+        {
+            bytes.0[i * 96..(i + 1) * 96].copy_from_slice(&c.to_bytes());
+        }
+        let g = Gt::from_bytes_unchecked(&bytes).unwrap();
+        assert!(!bool::from(g.is_torsion_free()));
+        assert!(bool::from(Gt::from_bytes(&bytes).is_none()));
+        assert_eq!(Gt::from_raw_bytes(&g.to_raw_bytes()), None);
+    }
+
+    // Non canonical field elements are rejected.
+    let mut bytes = Gt::generator().to_bytes();
+    bytes.0[..48].copy_from_slice(&[0xff; 48]);
+    assert!(bool::from(Gt::from_bytes_unchecked(&bytes).is_none()));
+
+    // Random Fq12 elements are not in Gt.
+    let g = Gt(Fq12::random(&mut rng));
+    assert!(!bool::from(g.is_torsion_free()));
+    assert_eq!(Gt::from_raw_bytes(&g.to_raw_bytes()), None);
+}
+
+#[test]
+fn test_g2_prepared_serialization() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let mut points = vec![G2Affine::identity(), G2Affine::generator()];
+    points.extend((0..5).map(|_| G2Affine::from(G2::random(&mut rng))));
+    for q in points {
+        let prepared = G2Prepared::from_affine(q);
+        assert_eq!(
+            prepared.coeffs.len(),
+            if prepared.is_zero() {
+                0
+            } else {
+                G2_PREPARED_NUM_COEFFS
+            }
+        );
+
+        let bytes = prepared.to_raw_bytes();
+        assert_eq!(G2Prepared::from_raw_bytes(&bytes), Some(prepared.clone()));
+        assert_eq!(G2Prepared::from_raw_bytes_unchecked(&bytes), prepared);
+
+        let mut buf = Vec::new();
+        prepared.write_raw(&mut buf).unwrap();
+        assert_eq!(buf, bytes);
+        assert_eq!(G2Prepared::read_raw(&mut &buf[..]).unwrap(), prepared);
+        assert_eq!(G2Prepared::read_raw_unchecked(&mut &buf[..]), prepared);
+
+        #[cfg(feature = "derive_serde")]
+        {
+            let bytes = bincode::serialize(&prepared).unwrap();
+            let prepared_rec: G2Prepared = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(prepared, prepared_rec);
+        }
+
+        // A deserialized point still pairs correctly.
+        let p = G1Affine::from(G1::random(&mut rng));
+        let prepared_rec = G2Prepared::from_raw_bytes(&bytes).unwrap();
+        assert_eq!(
+            multi_miller_loop(&[(&p, &prepared_rec)]).final_exponentiation(),
+            pairing(&p, &q)
+        );
+    }
+
+    let bytes = G2Prepared::from_affine(G2Affine::generator()).to_raw_bytes();
+
+    // Invalid flag.
+    let mut invalid = bytes.clone();
+    invalid[0] = 2;
+    assert_eq!(G2Prepared::from_raw_bytes(&invalid), None);
+    assert!(G2Prepared::read_raw(&mut &invalid[..]).is_err());
+
+    // Wrong number of coefficients.
+    assert_eq!(G2Prepared::from_raw_bytes(&bytes[..bytes.len() - 1]), None);
+    assert_eq!(G2Prepared::from_raw_bytes(&bytes[..1]), None);
+    assert_eq!(G2Prepared::from_raw_bytes(&[1, 0]), None);
+    assert!(G2Prepared::read_raw(&mut &bytes[..bytes.len() - 1]).is_err());
+
+    // Non canonical field element.
+    let mut invalid = bytes;
+    invalid[1..49].copy_from_slice(&[0xff; 48]);
+    assert_eq!(G2Prepared::from_raw_bytes(&invalid), None);
+    assert!(G2Prepared::read_raw(&mut &invalid[..]).is_err());
+}
+
+#[test]
+fn test_multi_miller_loop_parallel() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let g1: Vec<G1Affine> = (0..40)
+        .map(|_| G1Affine::from(G1::random(&mut rng)))
+        .collect();
+    let g2: Vec<G2Prepared> = (0..40)
+        .map(|_| G2Prepared::from(G2Affine::from(G2::random(&mut rng))))
+        .collect();
+    let terms: Vec<(&G1Affine, &G2Prepared)> = g1.iter().zip(g2.iter()).collect();
+
+    for n in [0, 1, 2, 7, 40] {
+        assert_eq!(
+            Bls12381::multi_miller_loop_parallel(&terms[..n]),
+            Bls12381::multi_miller_loop(&terms[..n])
+        );
+    }
+}
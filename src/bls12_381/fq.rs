@@ -0,0 +1,375 @@
+use super::LegendreSymbol;
+use crate::arithmetic::{adc, mac, sbb};
+use crate::ff::{FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
+use crate::hash_to_curve::{hash_to_field, HashToField};
+use core::convert::TryInto;
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
+use rand::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+#[cfg(feature = "derive_serde")]
+use serde::{Deserialize, Serialize};
+
+/// This represents an element of $\mathbb{F}_q$ where
+///
+/// `p = 0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab`
+///
+/// is the base field of the BLS12-381 curve.
+// The internal representation of this type is six 64-bit unsigned
+// integers in little-endian order. `Fq` values are always in
+// Montgomery form; i.e., Fq(a) = aR mod q, with R = 2^384.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+pub struct Fq(pub(crate) [u64; 6]);
+
+/// Constant representing the modulus
+/// q = 0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab
+const MODULUS: Fq = Fq([
+    0xb9feffffffffaaab,
+    0x1eabfffeb153ffff,
+    0x6730d2a0f6b0f624,
+    0x64774b84f38512bf,
+    0x4b1ba7b6434bacd7,
+    0x1a0111ea397fe69a,
+]);
+
+/// INV = -(q^{-1} mod 2^64) mod 2^64
+const INV: u64 = 0x89f3fffcfffcfffd;
+
+/// R = 2^384 mod q
+const R: Fq = Fq([
+    0x760900000002fffd,
+    0xebf4000bc40c0002,
+    0x5f48985753c758ba,
+    0x77ce585370525745,
+    0x5c071a97a256ec6d,
+    0x15f65ec3fa80e493,
+]);
+
+/// R^2 = 2^768 mod q
+const R2: Fq = Fq([
+    0xf4df1f341c341746,
+    0x0a76e6a609d104f1,
+    0x8de5476c4c95b6d5,
+    0x67eb88a9939d83c0,
+    0x9a793e85b519952d,
+    0x11988fe592cae3aa,
+]);
+
+/// R^3 = 2^1152 mod q
+const R3: Fq = Fq([
+    0xed48ac6bd94ca1e0,
+    0x315f831e03a7adf8,
+    0x9a53352a615e29dd,
+    0x34c04e5e921e1761,
+    0x2512d43565724728,
+    0x0aa6346091755d4d,
+]);
+
+pub const NEGATIVE_ONE: Fq = Fq([
+    0x43f5fffffffcaaae,
+    0x32b7fff2ed47fffd,
+    0x07e83a49a2e99d69,
+    0xeca8f3318332bb7a,
+    0xef148d1ea0f4c069,
+    0x040ab3263eff0206,
+]);
+
+const MODULUS_STR: &str = "0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab";
+
+/// `GENERATOR = 2 mod q` is a quadratic non-residue, hence a generator of
+/// the 2-Sylow subgroup of the multiplicative group.
+const MULTIPLICATIVE_GENERATOR: Fq = Fq::from_raw([0x02, 0x0, 0x0, 0x0, 0x0, 0x0]);
+
+const TWO_INV: Fq = Fq::from_raw([
+    0xdcff7fffffffd556,
+    0x0f55ffff58a9ffff,
+    0xb39869507b587b12,
+    0xb23ba5c279c2895f,
+    0x258dd3db21a5d66b,
+    0x0d0088f51cbff34d,
+]);
+
+/// q = 3 mod 4, so the 2-adicity is `S = 1` and the 2^S root of unity is -1.
+const ROOT_OF_UNITY: Fq = NEGATIVE_ONE;
+
+/// 1 / ROOT_OF_UNITY mod q
+const ROOT_OF_UNITY_INV: Fq = NEGATIVE_ONE;
+
+/// GENERATOR^{2^s} where t * 2^s + 1 = q with t odd.
+const DELTA: Fq = Fq::from_raw([0x04, 0x0, 0x0, 0x0, 0x0, 0x0]);
+
+/// `ZETA^3 = 1 mod q` where `ZETA^2 != 1 mod q`
+const ZETA: Fq = Fq::from_raw([
+    0x8bfd00000000aaac,
+    0x409427eb4f49fffd,
+    0x897d29650fb85f9b,
+    0xaa0d857d89759ad4,
+    0xec02408663d4de85,
+    0x1a0111ea397fe699,
+]);
+
+use crate::{
+    field_arithmetic, field_common, field_specific, impl_add_binop_specify_output,
+    impl_binops_additive, impl_binops_additive_specify_output, impl_binops_multiplicative,
+    impl_binops_multiplicative_mixed, impl_sub_binop_specify_output, impl_sum_prod,
+};
+impl_binops_additive!(Fq, Fq);
+impl_binops_multiplicative!(Fq, Fq);
+field_common!(
+    Fq,
+    MODULUS,
+    INV,
+    MODULUS_STR,
+    TWO_INV,
+    ROOT_OF_UNITY_INV,
+    DELTA,
+    ZETA,
+    R,
+    R2,
+    R3
+);
+impl_sum_prod!(Fq);
+field_arithmetic!(Fq, MODULUS, INV, sparse);
+
+impl Fq {
+    pub const fn size() -> usize {
+        Self::NUM_LIMBS * 8
+    }
+
+    /// Returns the canonical representative of this element, taking it out of
+    /// Montgomery form by computing (a.R) / R = a.
+    fn to_canonical(self) -> Fq {
+        let mut wide = [0; 2 * Self::NUM_LIMBS];
+        wide[..Self::NUM_LIMBS].copy_from_slice(&self.0);
+        Self::montgomery_reduce(&wide)
+    }
+
+    /// Returns the Legendre symbol of this element, computed as the Jacobi
+    /// symbol of its canonical representative. This is variable time.
+    pub fn legendre(&self) -> LegendreSymbol {
+        match crate::safegcd::jacobi(&self.to_canonical().0, &MODULUS.0) {
+            0 => LegendreSymbol::Zero,
+            1 => LegendreSymbol::QuadraticResidue,
+            _ => LegendreSymbol::QuadraticNonResidue,
+        }
+    }
+}
+
+impl ff::Field for Fq {
+    const ZERO: Self = Self::zero();
+    const ONE: Self = Self::one();
+
+    fn random(mut rng: impl RngCore) -> Self {
+        let mut random_bytes = [0; Self::NUM_LIMBS * 16];
+        rng.fill_bytes(&mut random_bytes[..]);
+
+        Self::from_uniform_bytes(&random_bytes)
+    }
+
+    fn double(&self) -> Self {
+        self.double()
+    }
+
+    #[inline(always)]
+    fn square(&self) -> Self {
+        self.square()
+    }
+
+    /// Computes the square root of this element, if it exists.
+    fn sqrt(&self) -> CtOption<Self> {
+        // q = 3 mod 4, so the candidate root is self^((q + 1) / 4).
+        let tmp = self.pow([
+            0xee7fbfffffffeaab,
+            0x07aaffffac54ffff,
+            0xd9cc34a83dac3d89,
+            0xd91dd2e13ce144af,
+            0x92c6e9ed90d2eb35,
+            0x0680447a8e5ff9a6,
+        ]);
+
+        CtOption::new(tmp, tmp.square().ct_eq(self))
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        ff::helpers::sqrt_ratio_generic(num, div)
+    }
+
+    /// Computes the multiplicative inverse of this element,
+    /// failing if the element is zero.
+    fn invert(&self) -> CtOption<Self> {
+        self.invert_safegcd()
+    }
+}
+
+impl ff::PrimeField for Fq {
+    type Repr = FqBytes;
+
+    const NUM_BITS: u32 = 381;
+    const CAPACITY: u32 = 380;
+    const MODULUS: &'static str = MODULUS_STR;
+    const MULTIPLICATIVE_GENERATOR: Self = MULTIPLICATIVE_GENERATOR;
+    const ROOT_OF_UNITY: Self = ROOT_OF_UNITY;
+    const ROOT_OF_UNITY_INV: Self = ROOT_OF_UNITY_INV;
+    const TWO_INV: Self = TWO_INV;
+    const DELTA: Self = DELTA;
+    const S: u32 = 1;
+
+    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+        let mut tmp = Fq::zero();
+        for (limb, bytes) in tmp.0.iter_mut().zip(repr.0.chunks_exact(8)) {
+            *limb = u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+
+        // Try to subtract the modulus
+        let borrow = tmp
+            .0
+            .iter()
+            .zip(MODULUS.0.iter())
+            .fold(0, |borrow, (t, m)| sbb(*t, *m, borrow).1);
+
+        // If the element is smaller than MODULUS then the
+        // subtraction will underflow, producing a borrow value
+        // of 0xffff...ffff. Otherwise, it'll be zero.
+        let is_some = (borrow as u8) & 1;
+
+        // Convert to Montgomery form by computing
+        // (a.R^0 * R^2) / R = a.R
+        tmp *= &R2;
+
+        CtOption::new(tmp, Choice::from(is_some))
+    }
+
+    fn to_repr(&self) -> Self::Repr {
+        let mut res = FqBytes::default();
+        for (bytes, limb) in res.0.chunks_exact_mut(8).zip(self.to_canonical().0.iter()) {
+            bytes.copy_from_slice(&limb.to_le_bytes());
+        }
+        res
+    }
+
+    fn is_odd(&self) -> Choice {
+        Choice::from(self.to_repr().0[0] & 1)
+    }
+}
+
+impl FromUniformBytes<{ Fq::NUM_LIMBS * 16 }> for Fq {
+    /// Converts a little endian integer of twice the width of the modulus
+    /// into an `Fq` by reducing by the modulus.
+    fn from_uniform_bytes(bytes: &[u8; Fq::NUM_LIMBS * 16]) -> Self {
+        let mut limbs = [0; 2 * Fq::NUM_LIMBS];
+        for (limb, bytes) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+            *limb = u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        Self::from_wide(&limbs)
+    }
+}
+
+impl HashToField for Fq {
+    // ceil((381 + 128) / 8)
+    const HASH_LEN: usize = 64;
+
+    fn hash_to_field(suite: &str, domain_prefix: &str, message: &[u8], buf: &mut [Self]) {
+        hash_to_field(suite, domain_prefix, message, buf)
+    }
+
+    fn sgn0(&self) -> Choice {
+        self.is_odd()
+    }
+}
+
+impl WithSmallOrderMulGroup<3> for Fq {
+    const ZETA: Self = ZETA;
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FqBytes([u8; Fq::NUM_LIMBS * 8]);
+
+impl Default for FqBytes {
+    fn default() -> Self {
+        Self([0u8; Fq::NUM_LIMBS * 8])
+    }
+}
+
+impl AsMut<[u8]> for FqBytes {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl AsRef<[u8]> for FqBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ff::Field;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_sqrt_fq() {
+        let v = (Fq::TWO_INV).square().sqrt().unwrap();
+        assert!(v == Fq::TWO_INV || (-v) == Fq::TWO_INV);
+
+        for _ in 0..10000 {
+            let a = Fq::random(OsRng);
+            let mut b = a;
+            b = b.square();
+            assert_eq!(b.legendre(), LegendreSymbol::QuadraticResidue);
+
+            let b = b.sqrt().unwrap();
+            let mut negb = b;
+            negb = negb.neg();
+
+            assert!(a == b || a == negb);
+        }
+
+        // GENERATOR is a quadratic non-residue.
+        assert_eq!(
+            MULTIPLICATIVE_GENERATOR.legendre(),
+            LegendreSymbol::QuadraticNonResidue
+        );
+        assert!(bool::from(MULTIPLICATIVE_GENERATOR.sqrt().is_none()));
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(NEGATIVE_ONE, -Fq::one());
+        assert_eq!(Fq::ROOT_OF_UNITY.square(), Fq::one());
+        assert_eq!(Fq::ROOT_OF_UNITY * Fq::ROOT_OF_UNITY_INV, Fq::one());
+        assert_eq!(Fq::TWO_INV.double(), Fq::one());
+        assert_eq!(Fq::DELTA, MULTIPLICATIVE_GENERATOR.pow([1u64 << Fq::S]));
+        assert_eq!(Fq::ZETA.square() + Fq::ZETA + Fq::one(), Fq::zero());
+    }
+
+    #[test]
+    fn test_from_wide() {
+        assert_eq!(
+            Fq::from_raw([
+                0x8be9bf7812cd4867,
+                0x668899c396fcadf6,
+                0x2ba9207dda9ecbef,
+                0x66c4c99d5e4008c0,
+                0xd55a0c408f2a47bb,
+                0x14661091ca5c8f4f,
+            ]),
+            Fq::from_wide(&[0xaaaaaaaaaaaaaaaa; 12])
+        );
+    }
+
+    #[test]
+    fn test_field() {
+        crate::tests::field::random_field_tests::<Fq>("bls12-381 base".to_string());
+    }
+
+    #[test]
+    fn test_serialization() {
+        crate::tests::field::random_serialization_test::<Fq>("fq".to_string());
+        #[cfg(feature = "derive_serde")]
+        crate::tests::field::random_serde_test::<Fq>("fq".to_string());
+    }
+}
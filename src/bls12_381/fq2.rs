@@ -0,0 +1,791 @@
+use super::fq::{Fq, NEGATIVE_ONE};
+use super::LegendreSymbol;
+use crate::ff::{Field, FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
+use crate::hash_to_curve::HashToField;
+use core::convert::TryInto;
+use core::ops::{Add, Mul, Neg, Sub};
+use rand::RngCore;
+use std::cmp::Ordering;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+#[cfg(feature = "derive_serde")]
+use serde::{Deserialize, Serialize};
+
+/// An element of Fq2, represented by c0 + c1 * u.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+pub struct Fq2 {
+    pub c0: Fq,
+    pub c1: Fq,
+}
+
+/// `Fq2` elements are ordered lexicographically.
+impl Ord for Fq2 {
+    #[inline(always)]
+    fn cmp(&self, other: &Fq2) -> Ordering {
+        match self.c1.cmp(&other.c1) {
+            Ordering::Greater => Ordering::Greater,
+            Ordering::Less => Ordering::Less,
+            Ordering::Equal => self.c0.cmp(&other.c0),
+        }
+    }
+}
+
+impl PartialOrd for Fq2 {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Fq2) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl ConditionallySelectable for Fq2 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Fq2 {
+            c0: Fq::conditional_select(&a.c0, &b.c0, choice),
+            c1: Fq::conditional_select(&a.c1, &b.c1, choice),
+        }
+    }
+}
+
+impl ConstantTimeEq for Fq2 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1)
+    }
+}
+
+impl Default for Fq2 {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<Fq2> for [u8; 96] {
+    fn from(value: Fq2) -> [u8; 96] {
+        value.to_bytes()
+    }
+}
+
+impl<'a> From<&'a Fq2> for [u8; 96] {
+    fn from(value: &'a Fq2) -> [u8; 96] {
+        value.to_bytes()
+    }
+}
+
+impl Neg for Fq2 {
+    type Output = Fq2;
+
+    #[inline]
+    fn neg(self) -> Fq2 {
+        -&self
+    }
+}
+
+impl Neg for &Fq2 {
+    type Output = Fq2;
+
+    #[inline]
+    fn neg(self) -> Fq2 {
+        self.neg()
+    }
+}
+
+impl<'b> Sub<&'b Fq2> for &Fq2 {
+    type Output = Fq2;
+
+    #[inline]
+    fn sub(self, rhs: &'b Fq2) -> Fq2 {
+        self.sub(rhs)
+    }
+}
+
+impl<'b> Add<&'b Fq2> for &Fq2 {
+    type Output = Fq2;
+
+    #[inline]
+    fn add(self, rhs: &'b Fq2) -> Fq2 {
+        self.add(rhs)
+    }
+}
+
+impl<'b> Mul<&'b Fq2> for &Fq2 {
+    type Output = Fq2;
+
+    #[inline]
+    fn mul(self, rhs: &'b Fq2) -> Fq2 {
+        self.mul(rhs)
+    }
+}
+
+use crate::{
+    impl_add_binop_specify_output, impl_binops_additive, impl_binops_additive_specify_output,
+    impl_binops_multiplicative, impl_binops_multiplicative_mixed, impl_sub_binop_specify_output,
+    impl_sum_prod,
+};
+impl_binops_additive!(Fq2, Fq2);
+impl_binops_multiplicative!(Fq2, Fq2);
+impl_sum_prod!(Fq2);
+
+impl Fq2 {
+    #[inline]
+    pub const fn zero() -> Fq2 {
+        Fq2 {
+            c0: Fq::zero(),
+            c1: Fq::zero(),
+        }
+    }
+
+    #[inline]
+    pub const fn one() -> Fq2 {
+        Fq2 {
+            c0: Fq::one(),
+            c1: Fq::zero(),
+        }
+    }
+
+    pub const fn new(c0: Fq, c1: Fq) -> Self {
+        Fq2 { c0, c1 }
+    }
+
+    pub const fn size() -> usize {
+        96
+    }
+    /// Attempts to convert a little-endian byte representation of
+    /// a scalar into a `Fq`, failing if the input is not canonical.
+    pub fn from_bytes(bytes: &[u8; 96]) -> CtOption<Fq2> {
+        let c0 = Fq::from_bytes(bytes[0..48].try_into().unwrap());
+        let c1 = Fq::from_bytes(bytes[48..96].try_into().unwrap());
+        CtOption::new(
+            Fq2 {
+                c0: c0.unwrap_or(Fq::zero()),
+                c1: c1.unwrap_or(Fq::zero()),
+            },
+            c0.is_some() & c1.is_some(),
+        )
+    }
+
+    /// Converts an element of `Fq` into a byte representation in
+    /// little-endian byte order.
+    pub fn to_bytes(&self) -> [u8; 96] {
+        let mut res = [0u8; 96];
+        let c0_bytes = self.c0.to_bytes();
+        let c1_bytes = self.c1.to_bytes();
+        res[0..48].copy_from_slice(&c0_bytes[..]);
+        res[48..96].copy_from_slice(&c1_bytes[..]);
+        res
+    }
+
+    pub fn legendre(&self) -> LegendreSymbol {
+        self.norm().legendre()
+    }
+
+    pub fn mul_assign(&mut self, other: &Self) {
+        let mut t1 = self.c0 * other.c0;
+        let mut t0 = self.c0 + self.c1;
+        let t2 = self.c1 * other.c1;
+        self.c1 = other.c0 + other.c1;
+        self.c0 = t1 - t2;
+        t1 += t2;
+        t0 *= self.c1;
+        self.c1 = t0 - t1;
+    }
+
+    pub fn square_assign(&mut self) {
+        let ab = self.c0 * self.c1;
+        let c0c1 = self.c0 + self.c1;
+        let mut c0 = -self.c1;
+        c0 += self.c0;
+        c0 *= c0c1;
+        c0 -= ab;
+        self.c1 = ab.double();
+        self.c0 = c0 + ab;
+    }
+
+    pub fn double(&self) -> Self {
+        Self {
+            c0: self.c0.double(),
+            c1: self.c1.double(),
+        }
+    }
+
+    pub fn double_assign(&mut self) {
+        self.c0 = self.c0.double();
+        self.c1 = self.c1.double();
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        Self {
+            c0: self.c0.add(&other.c0),
+            c1: self.c1.add(&other.c1),
+        }
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        Self {
+            c0: self.c0.sub(&other.c0),
+            c1: self.c1.sub(&other.c1),
+        }
+    }
+
+    pub fn mul(&self, other: &Self) -> Self {
+        let mut t = *other;
+        t.mul_assign(self);
+        t
+    }
+
+    pub fn square(&self) -> Self {
+        let mut t = *self;
+        t.square_assign();
+        t
+    }
+
+    pub fn neg(&self) -> Self {
+        Self {
+            c0: self.c0.neg(),
+            c1: self.c1.neg(),
+        }
+    }
+
+    // conjucate by negating c1
+    pub fn conjugate(&mut self) {
+        self.c1 = -self.c1;
+    }
+
+    pub fn frobenius_map(&mut self, power: usize) {
+        self.c1 *= &FROBENIUS_COEFF_FQ2_C1[power % 2];
+    }
+
+    /// Multiply this element by quadratic nonresidue 1 + u.
+    pub fn mul_by_nonresidue(&mut self) {
+        // (xu+y)(u+1) = (x+y)u+(y-x)
+        let t0 = self.c0;
+        self.c0 -= &self.c1;
+        self.c1 += &t0;
+    }
+
+    /// Norm of Fq2 as extension field in i over Fq
+    pub fn norm(&self) -> Fq {
+        let mut t0 = self.c0;
+        let mut t1 = self.c1;
+        t0 = t0.square();
+        t1 = t1.square();
+        t1 + t0
+    }
+
+    pub fn invert(&self) -> CtOption<Self> {
+        let mut t1 = self.c1;
+        t1 = t1.square();
+        let mut t0 = self.c0;
+        t0 = t0.square();
+        t0 += &t1;
+        t0.invert().map(|t| {
+            let mut tmp = Fq2 {
+                c0: self.c0,
+                c1: self.c1,
+            };
+            tmp.c0 *= &t;
+            tmp.c1 *= &t;
+            tmp.c1 = -tmp.c1;
+
+            tmp
+        })
+    }
+}
+
+impl Field for Fq2 {
+    const ZERO: Self = Self::zero();
+    const ONE: Self = Self::one();
+
+    fn random(mut rng: impl RngCore) -> Self {
+        Fq2 {
+            c0: Fq::random(&mut rng),
+            c1: Fq::random(&mut rng),
+        }
+    }
+
+    fn is_zero(&self) -> Choice {
+        self.c0.is_zero() & self.c1.is_zero()
+    }
+
+    fn square(&self) -> Self {
+        self.square()
+    }
+
+    fn double(&self) -> Self {
+        self.double()
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        // Algorithm 9, https://eprint.iacr.org/2012/685.pdf
+
+        if self.is_zero().into() {
+            CtOption::new(Self::ZERO, Choice::from(1))
+        } else {
+            // a1 = self^((q - 3) / 4)
+            let u: [u64; 6] = [
+                0xee7fbfffffffeaaa,
+                0x07aaffffac54ffff,
+                0xd9cc34a83dac3d89,
+                0xd91dd2e13ce144af,
+                0x92c6e9ed90d2eb35,
+                0x0680447a8e5ff9a6,
+            ];
+            let mut a1 = self.pow(u);
+            let mut alpha = a1;
+
+            alpha.square_assign();
+            alpha.mul_assign(self);
+            let mut a0 = alpha;
+            a0.frobenius_map(1);
+            a0.mul_assign(&alpha);
+
+            let neg1 = Fq2 {
+                c0: NEGATIVE_ONE,
+                c1: Fq::zero(),
+            };
+
+            if a0 == neg1 {
+                CtOption::new(a0, Choice::from(0))
+            } else {
+                a1.mul_assign(self);
+
+                if alpha == neg1 {
+                    a1.mul_assign(&Fq2 {
+                        c0: Fq::zero(),
+                        c1: Fq::one(),
+                    });
+                } else {
+                    alpha += &Fq2::ONE;
+                    // alpha = alpha^((q - 1) / 2)
+                    let u: [u64; 6] = [
+                        0xdcff7fffffffd555,
+                        0x0f55ffff58a9ffff,
+                        0xb39869507b587b12,
+                        0xb23ba5c279c2895f,
+                        0x258dd3db21a5d66b,
+                        0x0d0088f51cbff34d,
+                    ];
+                    alpha = alpha.pow(u);
+                    a1.mul_assign(&alpha);
+                }
+                CtOption::new(a1, Choice::from(1))
+            }
+        }
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        ff::helpers::sqrt_ratio_generic(num, div)
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        self.invert()
+    }
+}
+
+impl From<bool> for Fq2 {
+    fn from(bit: bool) -> Fq2 {
+        if bit {
+            Fq2::ONE
+        } else {
+            Fq2::ZERO
+        }
+    }
+}
+
+impl From<u64> for Fq2 {
+    fn from(val: u64) -> Self {
+        Fq2 {
+            c0: Fq::from(val),
+            c1: Fq::zero(),
+        }
+    }
+}
+
+impl PrimeField for Fq2 {
+    type Repr = Fq2Bytes;
+
+    const MODULUS: &'static str =
+        "0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab";
+    const MULTIPLICATIVE_GENERATOR: Self = Fq2 {
+        c0: Fq::from_raw([0x02, 0x0, 0x0, 0x0, 0x0, 0x0]),
+        c1: Fq::ZERO,
+    };
+    const NUM_BITS: u32 = 381;
+    const CAPACITY: u32 = 380;
+    const S: u32 = 0;
+    // TODO: Check that we can just 0 this and forget.
+    const ROOT_OF_UNITY: Self = Fq2::zero();
+    const ROOT_OF_UNITY_INV: Self = Fq2 {
+        c0: Fq::zero(),
+        c1: Fq::zero(),
+    };
+    const DELTA: Self = Fq2 {
+        c0: Fq::zero(),
+        c1: Fq::zero(),
+    };
+    const TWO_INV: Self = Fq2 {
+        c0: Fq::from_raw([
+            0xdcff7fffffffd556,
+            0x0f55ffff58a9ffff,
+            0xb39869507b587b12,
+            0xb23ba5c279c2895f,
+            0x258dd3db21a5d66b,
+            0x0d0088f51cbff34d,
+        ]),
+        c1: Fq([0, 0, 0, 0, 0, 0]),
+    };
+
+    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+        Fq2::from_bytes(&repr.0)
+    }
+
+    fn to_repr(&self) -> Self::Repr {
+        Fq2Bytes(self.to_bytes())
+    }
+
+    fn is_odd(&self) -> Choice {
+        Choice::from(self.to_repr().as_ref()[0] & 1)
+    }
+}
+
+impl FromUniformBytes<{ Fq::NUM_LIMBS * 16 }> for Fq2 {
+    fn from_uniform_bytes(bytes: &[u8; Fq::NUM_LIMBS * 16]) -> Self {
+        Self::new(Fq::from_uniform_bytes(bytes), Fq::zero())
+    }
+}
+
+impl HashToField for Fq2 {
+    const HASH_LEN: usize = Fq::HASH_LEN;
+
+    fn hash_to_field(suite: &str, domain_prefix: &str, message: &[u8], buf: &mut [Self]) {
+        // Each element of the extension takes two consecutive base field elements.
+        let mut coeffs = vec![Fq::zero(); 2 * buf.len()];
+        Fq::hash_to_field(suite, domain_prefix, message, &mut coeffs);
+        for (e, c) in buf.iter_mut().zip(coeffs.chunks_exact(2)) {
+            *e = Fq2::new(c[0], c[1]);
+        }
+    }
+
+    fn sgn0(&self) -> Choice {
+        let sign_0 = self.c0.is_odd();
+        let zero_0 = self.c0.is_zero();
+        let sign_1 = self.c1.is_odd();
+        sign_0 | (zero_0 & sign_1)
+    }
+}
+#[derive(Clone, Copy, Debug)]
+pub struct Fq2Bytes([u8; 96]);
+
+impl Default for Fq2Bytes {
+    fn default() -> Self {
+        Self([0u8; 96])
+    }
+}
+
+impl AsMut<[u8]> for Fq2Bytes {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl AsRef<[u8]> for Fq2Bytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl crate::serde::SerdeObject for Fq2 {
+    fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
+        debug_assert_eq!(bytes.len(), 96);
+        let [c0, c1] = [0, 48].map(|i| Fq::from_raw_bytes_unchecked(&bytes[i..i + 48]));
+        Self { c0, c1 }
+    }
+    fn from_raw_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 96 {
+            return None;
+        }
+        let [c0, c1] = [0, 48].map(|i| Fq::from_raw_bytes(&bytes[i..i + 48]));
+        c0.zip(c1).map(|(c0, c1)| Self { c0, c1 })
+    }
+    fn to_raw_bytes(&self) -> Vec<u8> {
+        let mut res = Vec::with_capacity(96);
+        for limb in self.c0.0.iter().chain(self.c1.0.iter()) {
+            res.extend_from_slice(&limb.to_le_bytes());
+        }
+        res
+    }
+    fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
+        let [c0, c1] = [(); 2].map(|_| Fq::read_raw_unchecked(reader));
+        Self { c0, c1 }
+    }
+    fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let c0 = Fq::read_raw(reader)?;
+        let c1 = Fq::read_raw(reader)?;
+        Ok(Self { c0, c1 })
+    }
+    fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.c0.write_raw(writer)?;
+        self.c1.write_raw(writer)
+    }
+}
+
+impl WithSmallOrderMulGroup<3> for Fq2 {
+    // Fq::ZETA ^2
+    const ZETA: Self = Fq2 {
+        c0: Fq::from_raw([
+            0x2e01fffffffefffe,
+            0xde17d813620a0002,
+            0xddb3a93be6f89688,
+            0xba69c6076a0f77ea,
+            0x5f19672fdf76ce51,
+            0x0000000000000000,
+        ]),
+        c1: Fq::zero(),
+    };
+}
+
+pub const FROBENIUS_COEFF_FQ2_C1: [Fq; 2] = [
+    // Fq(-1)**(((q^0) - 1) / 2)
+    // it's 1 in Montgommery form
+    Fq([
+        0x760900000002fffd,
+        0xebf4000bc40c0002,
+        0x5f48985753c758ba,
+        0x77ce585370525745,
+        0x5c071a97a256ec6d,
+        0x15f65ec3fa80e493,
+    ]),
+    // Fq(-1)**(((q^1) - 1) / 2)
+    Fq([
+        0x43f5fffffffcaaae,
+        0x32b7fff2ed47fffd,
+        0x07e83a49a2e99d69,
+        0xeca8f3318332bb7a,
+        0xef148d1ea0f4c069,
+        0x040ab3263eff0206,
+    ]),
+];
+
+#[cfg(test)]
+use rand::SeedableRng;
+#[cfg(test)]
+use rand_xorshift::XorShiftRng;
+
+#[test]
+fn test_ser() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let a0 = Fq2::random(&mut rng);
+    let a_bytes = a0.to_bytes();
+    let a1 = Fq2::from_bytes(&a_bytes).unwrap();
+    assert_eq!(a0, a1);
+}
+
+#[test]
+fn test_fq2_ordering() {
+    let mut a = Fq2 {
+        c0: Fq::zero(),
+        c1: Fq::zero(),
+    };
+
+    let mut b = a;
+
+    assert!(a.cmp(&b) == Ordering::Equal);
+    b.c0 += &Fq::one();
+    assert!(a.cmp(&b) == Ordering::Less);
+    a.c0 += &Fq::one();
+    assert!(a.cmp(&b) == Ordering::Equal);
+    b.c1 += &Fq::one();
+    assert!(a.cmp(&b) == Ordering::Less);
+    a.c0 += &Fq::one();
+    assert!(a.cmp(&b) == Ordering::Less);
+    a.c1 += &Fq::one();
+    assert!(a.cmp(&b) == Ordering::Greater);
+    b.c0 += &Fq::one();
+    assert!(a.cmp(&b) == Ordering::Equal);
+}
+
+#[test]
+fn test_fq2_basics() {
+    assert_eq!(
+        Fq2 {
+            c0: Fq::zero(),
+            c1: Fq::zero(),
+        },
+        Fq2::ZERO
+    );
+    assert_eq!(
+        Fq2 {
+            c0: Fq::one(),
+            c1: Fq::zero(),
+        },
+        Fq2::ONE
+    );
+    assert_eq!(Fq2::ZERO.is_zero().unwrap_u8(), 1);
+    assert_eq!(Fq2::ONE.is_zero().unwrap_u8(), 0);
+    assert_eq!(
+        Fq2 {
+            c0: Fq::zero(),
+            c1: Fq::one(),
+        }
+        .is_zero()
+        .unwrap_u8(),
+        0
+    );
+}
+
+#[test]
+fn test_fq2_squaring() {
+    let mut a = Fq2 {
+        c0: Fq::one(),
+        c1: Fq::one(),
+    }; // u + 1
+    a.square_assign();
+    assert_eq!(
+        a,
+        Fq2 {
+            c0: Fq::zero(),
+            c1: Fq::one() + Fq::one(),
+        }
+    ); // 2u
+
+    let mut a = Fq2 {
+        c0: Fq::zero(),
+        c1: Fq::one(),
+    }; // u
+    a.square_assign();
+    assert_eq!(a, {
+        let neg1 = -Fq::one();
+        Fq2 {
+            c0: neg1,
+            c1: Fq::zero(),
+        }
+    }); // -1
+}
+
+#[test]
+fn test_fq2_mul_nonresidue() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+    let nqr = Fq2 {
+        c0: Fq::one(),
+        c1: Fq::one(),
+    };
+
+    for _ in 0..1000 {
+        let mut a = Fq2::random(&mut rng);
+        let mut b = a;
+        a.mul_by_nonresidue();
+        b.mul_assign(&nqr);
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_fq2_legendre() {
+    assert_eq!(LegendreSymbol::Zero, Fq2::ZERO.legendre());
+    // i^2 = -1
+    let mut m1 = Fq2::ONE;
+    m1 = m1.neg();
+    assert_eq!(LegendreSymbol::QuadraticResidue, m1.legendre());
+    m1.mul_by_nonresidue();
+    assert_eq!(LegendreSymbol::QuadraticNonResidue, m1.legendre());
+}
+
+#[test]
+pub fn test_sqrt() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    for _ in 0..10000 {
+        let a = Fq2::random(&mut rng);
+        if a.legendre() == LegendreSymbol::QuadraticNonResidue {
+            assert!(bool::from(a.sqrt().is_none()));
+        }
+    }
+
+    for _ in 0..10000 {
+        let a = Fq2::random(&mut rng);
+        let mut b = a;
+        b.square_assign();
+        assert_eq!(b.legendre(), LegendreSymbol::QuadraticResidue);
+
+        let b = b.sqrt().unwrap();
+        let mut negb = b;
+        negb = negb.neg();
+
+        assert!(a == b || a == negb);
+    }
+
+    let mut c = Fq2::ONE;
+    for _ in 0..10000 {
+        let mut b = c;
+        b.square_assign();
+        assert_eq!(b.legendre(), LegendreSymbol::QuadraticResidue);
+
+        b = b.sqrt().unwrap();
+
+        if b != c {
+            b = b.neg();
+        }
+
+        assert_eq!(b, c);
+
+        c += &Fq2::ONE;
+    }
+}
+
+#[test]
+fn test_frobenius() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    for _ in 0..100 {
+        for i in 0..14 {
+            let mut a = Fq2::random(&mut rng);
+            let mut b = a;
+
+            for _ in 0..i {
+                a = a.pow([
+                    0xb9feffffffffaaab,
+                    0x1eabfffeb153ffff,
+                    0x6730d2a0f6b0f624,
+                    0x64774b84f38512bf,
+                    0x4b1ba7b6434bacd7,
+                    0x1a0111ea397fe69a,
+                ]);
+            }
+            b.frobenius_map(i);
+
+            assert_eq!(a, b);
+        }
+    }
+}
+
+#[test]
+fn test_zeta() {
+    assert_eq!(Fq2::ZETA, Fq2::new(Fq::ZETA.square(), Fq::zero()));
+    assert_eq!(Fq2::ZETA.square() + Fq2::ZETA + Fq2::one(), Fq2::zero());
+}
+
+#[test]
+fn test_field() {
+    crate::tests::field::random_field_tests::<Fq2>("fq2".to_string());
+}
+
+#[test]
+fn test_serialization() {
+    crate::tests::field::random_serialization_test::<Fq2>("fq2".to_string());
+    #[cfg(feature = "derive_serde")]
+    crate::tests::field::random_serde_test::<Fq2>("fq2".to_string());
+}
@@ -0,0 +1,102 @@
+use crate::derive::prime_field::define_prime_field;
+
+define_prime_field!(
+    /// This is the scalar field of the BLS12-381 curve.
+    Fr,
+    "0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+    generator = 7
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ff::{Field, FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
+    use core::ops::Neg;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_sqrt() {
+        let v = (Fr::TWO_INV).square().sqrt().unwrap();
+        assert!(v == Fr::TWO_INV || (-v) == Fr::TWO_INV);
+
+        for _ in 0..10000 {
+            let a = Fr::random(OsRng);
+            let mut b = a;
+            b = b.square();
+
+            let b = b.sqrt().unwrap();
+            let mut negb = b;
+            negb = negb.neg();
+
+            assert!(a == b || a == negb);
+        }
+    }
+
+    #[test]
+    fn test_constants() {
+        // The constants the field was defined with before being derived by
+        // `define_prime_field!`.
+        assert_eq!(Fr::MULTIPLICATIVE_GENERATOR, Fr::from(7));
+        assert_eq!(Fr::S, 32);
+        assert_eq!(
+            Fr::ROOT_OF_UNITY,
+            Fr::from_raw([
+                0x3829971f439f0d2b,
+                0xb63683508c2280b9,
+                0xd09b681922c813b4,
+                0x16a2a19edfe81f20,
+            ])
+        );
+        assert_eq!(
+            Fr::DELTA,
+            Fr::from_raw([
+                0x6c083479590189d7,
+                0xf6502437c6a09c00,
+                0x43cab354fabb0062,
+                0x08634d0aa021aaf8,
+            ])
+        );
+        // The endomorphism of G1 acts as multiplication by this cube root of
+        // unity.
+        assert_eq!(
+            Fr::ZETA,
+            Fr::from_raw([0x00000000ffffffff, 0xac45a4010001a402, 0x00, 0x00])
+        );
+    }
+
+    #[test]
+    fn test_root_of_unity() {
+        assert_eq!(Fr::ROOT_OF_UNITY * Fr::ROOT_OF_UNITY_INV, Fr::one());
+        assert_eq!(Fr::ROOT_OF_UNITY.pow([1u64 << Fr::S, 0, 0, 0]), Fr::one());
+        assert_eq!(
+            Fr::ROOT_OF_UNITY.pow([1u64 << (Fr::S - 1), 0, 0, 0]),
+            -Fr::one()
+        );
+    }
+
+    #[test]
+    fn test_delta() {
+        assert_eq!(
+            Fr::DELTA,
+            Fr::MULTIPLICATIVE_GENERATOR.pow([1u64 << Fr::S, 0, 0, 0])
+        );
+    }
+
+    #[test]
+    fn test_zeta() {
+        assert_eq!(Fr::ZETA.square() + Fr::ZETA + Fr::one(), Fr::zero());
+    }
+
+    #[test]
+    fn test_from_uniform_bytes() {
+        assert_eq!(
+            Fr::from_raw([
+                0xdbbbf10b4d4c6848,
+                0x72489e87afb6e817,
+                0xae8cb8644c38265f,
+                0x04db3be66a3bff60,
+            ]),
+            Fr::from_uniform_bytes(&[0xaa; 64])
+        );
+    }
+}
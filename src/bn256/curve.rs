@@ -1,10 +1,14 @@
+use crate::arithmetic::EndoParameters;
 use crate::bn256::Fq;
 use crate::bn256::Fq2;
 use crate::bn256::Fr;
+use crate::bn256::{BN_X, FROBENIUS_COEFF_FQ6_C1, XI_TO_Q_MINUS_1_OVER_2};
 use crate::ff::WithSmallOrderMulGroup;
 use crate::ff::{Field, PrimeField};
+use crate::fixed_base::FixedBaseTable;
 use crate::group::Curve;
 use crate::group::{cofactor::CofactorGroup, prime::PrimeCurveAffine, Group, GroupEncoding};
+use crate::hash_to_curve::svdw_hash_to_curve;
 use crate::{Coordinates, CurveAffine, CurveAffineExt, CurveExt};
 use core::cmp;
 use core::fmt::Debug;
@@ -17,7 +21,7 @@ use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    batch_add, impl_add_binop_specify_output, impl_binops_additive,
+    batch_add, endo, impl_add_binop_specify_output, impl_binops_additive,
     impl_binops_additive_specify_output, impl_binops_multiplicative,
     impl_binops_multiplicative_mixed, impl_sub_binop_specify_output, new_curve_impl,
 };
@@ -32,6 +36,8 @@ new_curve_impl!(
     (G1_GENERATOR_X,G1_GENERATOR_Y),
     G1_B,
     "bn256_g1",
+    glv,
+    |domain_prefix| svdw_hash_to_curve::<G1>(G1_SVDW_SUITE, domain_prefix, G1_SVDW_Z),
 );
 
 new_curve_impl!(
@@ -44,8 +50,12 @@ new_curve_impl!(
     (G2_GENERATOR_X, G2_GENERATOR_Y),
     G2_B,
     "bn256_g2",
+    fixed_window,
+    |domain_prefix| svdw_hash_to_curve::<G2>(G2_SVDW_SUITE, domain_prefix, G2_SVDW_Z),
 );
 
+endo!(G1, Fr, ENDO_PARAMS_BN);
+
 impl CurveAffineExt for G1Affine {
     batch_add!();
 
@@ -62,10 +72,66 @@ impl CurveAffineExt for G2Affine {
     }
 }
 
+lazy_static::lazy_static! {
+    /// Precomputed multiples of the `G1` generator, built on first use.
+    pub static ref G1_GENERATOR_TABLE: FixedBaseTable<G1Affine> =
+        FixedBaseTable::new(G1Affine::generator());
+
+    /// Precomputed multiples of the `G2` generator, built on first use.
+    pub static ref G2_GENERATOR_TABLE: FixedBaseTable<G2Affine> =
+        FixedBaseTable::new(G2Affine::generator());
+}
+
 const G1_GENERATOR_X: Fq = Fq::one();
 const G1_GENERATOR_Y: Fq = Fq::from_raw([2, 0, 0, 0]);
 const G1_B: Fq = Fq::from_raw([3, 0, 0, 0]);
 
+/// GLV decomposition parameters for scalars of `G1`, where the endomorphism
+/// acts as multiplication by `Fr::ZETA`.
+const ENDO_PARAMS_BN: EndoParameters = EndoParameters {
+    gamma1: [
+        0xd91d232ec7e0b3d7,
+        0x0000000000000002,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    gamma2: [
+        0x7a7bd9d4391eb18e,
+        0x4ccef014a773d2cf,
+        0x0000000000000002,
+        0x0000000000000000,
+    ],
+    a1: [
+        0x89d3256894d213e3,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    b1: [
+        0xc1d039a872b0eed9,
+        0xb8e665ff8b011694,
+        0xb85045b68181585c,
+        0x30644e72e131a029,
+    ],
+    a2: [
+        0x0be4e1541221250b,
+        0x6f4d8248eeb859fd,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    b2: [
+        0x89d3256894d213e3,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+};
+
+/// Suite identifier of the RFC 9380 hash to `G1` suite.
+const G1_SVDW_SUITE: &str = "BN254G1_XMD:SHA-256_SVDW_RO_";
+/// `Z` parameter of the Shallue-van de Woestijne map to `G1`.
+const G1_SVDW_Z: Fq = Fq::one();
+
 const G2_B: Fq2 = Fq2 {
     c0: Fq::from_raw([
         0x3267e6dc24a138e5,
@@ -81,6 +147,14 @@ const G2_B: Fq2 = Fq2 {
     ]),
 };
 
+/// Suite identifier of the RFC 9380 hash to `G2` suite.
+const G2_SVDW_SUITE: &str = "BN254G2_XMD:SHA-256_SVDW_RO_";
+/// `Z` parameter of the Shallue-van de Woestijne map to `G2`, which is `u`.
+const G2_SVDW_Z: Fq2 = Fq2 {
+    c0: Fq::zero(),
+    c1: Fq::one(),
+};
+
 const G2_GENERATOR_X: Fq2 = Fq2 {
     c0: Fq::from_raw([
         0x46debd5cd992f6ed,
@@ -128,62 +202,75 @@ impl group::cofactor::CofactorGroup for G1 {
     }
 }
 
-impl CofactorGroup for G2 {
-    type Subgroup = G2;
-
-    fn clear_cofactor(&self) -> Self {
-        // "0x30644e72e131a029b85045b68181585e06ceecda572a2489345f2299c0f9fa8d"
-        let e: [u8; 32] = [
-            0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81,
-            0x58, 0x5e, 0x06, 0xce, 0xec, 0xda, 0x57, 0x2a, 0x24, 0x89, 0x34, 0x5f, 0x22, 0x99,
-            0xc0, 0xf9, 0xfa, 0x8d,
-        ];
+impl G2 {
+    /// Applies the untwist-Frobenius-twist endomorphism
+    /// ψ(x, y) = (conj(x) * ξ^((p - 1) / 3), conj(y) * ξ^((p - 1) / 2)).
+    fn psi(&self) -> Self {
+        let mut x = self.x;
+        let mut y = self.y;
+        let mut z = self.z;
+        x.conjugate();
+        y.conjugate();
+        z.conjugate();
+        G2 {
+            x: x * FROBENIUS_COEFF_FQ6_C1[1],
+            y: y * XI_TO_Q_MINUS_1_OVER_2,
+            z,
+        }
+    }
 
-        // self * COFACTOR_G2
+    /// Multiplies by the BN parameter `x`, which is public.
+    fn mul_by_x(&self) -> Self {
         let mut acc = G2::identity();
-        for bit in e
-            .iter()
-            .flat_map(|byte| (0..8).rev().map(move |i| Choice::from((byte >> i) & 1u8)))
-            .skip(1)
-        {
+        for i in (0..64 - BN_X.leading_zeros()).rev() {
             acc = acc.double();
-            acc = G2::conditional_select(&acc, &(acc + self), bit);
+            if (BN_X >> i) & 1 == 1 {
+                acc += self;
+            }
         }
         acc
     }
+}
+
+impl CofactorGroup for G2 {
+    type Subgroup = G2;
+
+    fn clear_cofactor(&self) -> Self {
+        // Fuentes-Castañeda, Knapp and Rodríguez-Henríquez, "Faster hashing to G2",
+        // section 6.1: [x]P + ψ([3x]P) + ψ²([x]P) + ψ³(P).
+        let x_p = self.mul_by_x();
+        let psi_3x_p = (x_p.double() + x_p).psi();
+        let psi2_x_p = x_p.psi().psi();
+        let psi3_p = self.psi().psi().psi();
+
+        x_p + psi_3x_p + psi2_x_p + psi3_p
+    }
 
     fn into_subgroup(self) -> CtOption<Self::Subgroup> {
-        unimplemented!();
+        CtOption::new(self, self.is_torsion_free())
     }
 
     fn is_torsion_free(&self) -> Choice {
-        // "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001"
-        let e: [u8; 32] = [
-            0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81,
-            0x58, 0x5d, 0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93,
-            0xf0, 0x00, 0x00, 0x01,
-        ];
-
-        // self * GROUP_ORDER;
-
-        let mut acc = G2::identity();
-        for bit in e
-            .iter()
-            .flat_map(|byte| (0..8).rev().map(move |i| Choice::from((byte >> i) & 1u8)))
-            .skip(1)
-        {
-            acc = acc.double();
-            acc = G2::conditional_select(&acc, &(acc + self), bit);
-        }
-        acc.is_identity()
+        // El Housni, Guillevic and Piellard, "Co-factor clearing and subgroup
+        // membership testing on pairing-friendly curves": P is in G2 iff
+        // ψ(P) = [6x²]P.
+        let x2_p = self.mul_by_x().mul_by_x();
+        let six_x2_p = (x2_p.double() + x2_p).double();
+        self.psi().ct_eq(&six_x2_p)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::bn256::{Fr, G1, G2};
-    use crate::CurveExt;
-    use ff::WithSmallOrderMulGroup;
+    use super::{G1_SVDW_SUITE, G2_SVDW_SUITE, G2_SVDW_Z};
+    use crate::bn256::{Fq, Fq2, Fr, G1Affine, G2Affine, G1, G2};
+    use crate::hash_to_curve::{hash_to_field, svdw_map_to_curve, HashToField, SvdwConstants};
+    use crate::{CurveAffine, CurveExt};
+    use ff::{Field, PrimeField};
+    use group::cofactor::CofactorGroup;
+    use group::{prime::PrimeCurveAffine, Curve, Group, GroupEncoding, UncompressedEncoding};
+    use rand_core::SeedableRng;
+    use rand_xorshift::XorShiftRng;
 
     #[test]
     fn test_curve() {
@@ -193,11 +280,12 @@ mod tests {
 
     #[test]
     fn test_endo_consistency() {
-        let g = G1::generator();
-        assert_eq!(g * Fr::ZETA, g.endo());
+        crate::tests::curve::endo_consistency_test::<G1>();
+    }
 
-        let g = G2::generator();
-        assert_eq!(g * Fr::ZETA, g.endo());
+    #[test]
+    fn test_endo_decomposition() {
+        crate::tests::curve::endo_decomposition_test::<G1>();
     }
 
     #[test]
@@ -210,4 +298,242 @@ mod tests {
             crate::tests::curve::random_serde_test::<G2>();
         }
     }
+
+    fn fq_from_hex(hex: &str) -> Fq {
+        let mut repr = <Fq as PrimeField>::Repr::default();
+        for (i, b) in repr.as_mut().iter_mut().rev().enumerate() {
+            *b = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        Fq::from_repr(repr).unwrap()
+    }
+
+    #[test]
+    fn test_hash_to_curve() {
+        crate::tests::curve::hash_to_curve_test::<G1>();
+
+        // Vectors for the suite BN254G1_XMD:SHA-256_SVDW_RO_, with tags built
+        // like those of the RFC 9380 examples. RFC 9380 defines no BN254 suite,
+        // so these are self-generated, with a Python script following its
+        // sections 5.3 and 6.6.1, and only pin the current output.
+        const DOMAIN_PREFIX: &str = "QUUX-V01-CS02-with";
+        let vectors: [(&[u8], [&str; 4]); 5] = [
+            (
+                b"",
+                [
+                    "2f87b81d9d6ef05ad4d249737498cc27e1bd485dca804487844feb3c67c1a9b5",
+                    "06de2d0d7c0d9c7a5a6c0b74675e7543f5b98186b5dbf831067449000b2b1f8e",
+                    "0a976ab906170db1f9638d376514dbf8c42aef256a54bbd48521f20749e59e86",
+                    "02925ead66b9e68bfc309b014398640ab55f6619ab59bc1fab2210ad4c4d53d5",
+                ],
+            ),
+            (
+                b"abc",
+                [
+                    "11945105b5e3d3b9392b5a2318409cbc28b7246aa47fa30da5739907737799a9",
+                    "1255fc9ad5a6e0fb440916f091229bda611c41be2f2283c3d8f98c596be4c8c9",
+                    "23f717bee89b1003957139f193e6be7da1df5f1374b26a4643b0378b5baf53d1",
+                    "04142f826b71ee574452dbc47e05bc3e1a647478403a7ba38b7b93948f4e151d",
+                ],
+            ),
+            (
+                b"abcdef0123456789",
+                [
+                    "2f7993a6b43a8dbb37060e790011a888157f456b895b925c3568690685f4983d",
+                    "2677d0532b47a4cead2488845e7df7ebc16c0b8a2cd8a6b7f4ce99f51659794e",
+                    "187dbf1c3c89aceceef254d6548d7163fdfa43084145f92c4c91c85c21442d4a",
+                    "0abd99d5b0000910b56058f9cc3b0ab0a22d47cf27615f588924fac1e5c63b4d",
+                ],
+            ),
+            (
+                &[b"q128_".as_slice(), &[b'q'; 128]].concat(),
+                [
+                    "2a50be15282ee276b76db1dab761f75401cdc8bd9fff81fcf4d428db16092a7b",
+                    "23b41953676183c30aca54b5c8bd3ffe3535a6238c39f6b15487a5467d5d20eb",
+                    "00fe2b0743575324fc452d590d217390ad48e5a16cf051bee5c40a2eba233f5c",
+                    "0794211e0cc72d3cbbdf8e4e5cd6e7d7e78d101ff94862caae8acbe63e9fdc78",
+                ],
+            ),
+            (
+                &[b"a512_".as_slice(), &[b'a'; 512]].concat(),
+                [
+                    "048527470f534978bae262c0f3ba8380d7f560916af58af9ad7dcb6a4238e633",
+                    "19a6d8be25702820b9b11eada2d42f425343889637a01ecd7672fbcf590d9ffe",
+                    "01b05dc540bd79fd0fea4fbb07de08e94fc2e7bd171fe025c479dc212a2173ce",
+                    "1bf028afc00c0f843d113758968f580640541728cfc6d32ced9779aa613cd9b0",
+                ],
+            ),
+        ];
+
+        let hasher = G1::hash_to_curve(DOMAIN_PREFIX);
+        for (message, [u0, u1, x, y]) in vectors.iter() {
+            let mut us = [Fq::ZERO; 2];
+            hash_to_field(G1_SVDW_SUITE, DOMAIN_PREFIX, message, &mut us);
+            assert_eq!(us, [fq_from_hex(u0), fq_from_hex(u1)]);
+
+            let expected = G1Affine::from_xy(fq_from_hex(x), fq_from_hex(y)).unwrap();
+            assert_eq!(hasher(message).to_affine(), expected);
+        }
+    }
+
+    fn fq2_from_hex(c0: &str, c1: &str) -> Fq2 {
+        Fq2::new(fq_from_hex(c0), fq_from_hex(c1))
+    }
+
+    #[test]
+    fn test_hash_to_curve_g2() {
+        crate::tests::curve::hash_to_curve_test::<G2>();
+
+        // Vectors for the suite BN254G2_XMD:SHA-256_SVDW_RO_, self-generated
+        // like the G1 ones above, with the cofactor cleared by psi as in
+        // `G2::clear_cofactor`. They only pin the current output.
+        const DOMAIN_PREFIX: &str = "QUUX-V01-CS02-with";
+        let vectors: [(&[u8], [&str; 8]); 5] = [
+            (
+                b"",
+                [
+                    "2c85988ecf26034a6d6c495c467150aeaead51fceb623aa99b0433275c8952c7",
+                    "182126b31e6df7cf33844bf16a92f42072ee47f80539dace68dbfc3380d1fcbd",
+                    "1c3035901eab4768d522b3d0eb7e58b05c130603c8f43587345dc51745fa3533",
+                    "23597b1c4f238038ba6579d203e7fcb7d427c63d4e0d037185453168718203bb",
+                    "0ed0d40000f45c0ccb22f6d4b5556a622aeae7171bc7786384388c72c2d1c457",
+                    "16cdd0997f60c1db037b92d2a1547807cdd197452dcf022939d1319ee0b54734",
+                    "22cbeff2598127f6bd6d978719090096fcb12ecd4963ff6cc319e5cdc81292a9",
+                    "08a263f06c6eef04278720d7b1d775b3d089b11cd333d85b4e7b8b5d8aee0407",
+                ],
+            ),
+            (
+                b"abc",
+                [
+                    "234b244ed36d5acbb96a4f5fb67094945a0bb4ecf33d55bcc218ce834dc82c63",
+                    "04ca11f51d0cf7e7393a0e6d7be3d0e6b07652d5ba308554a72dafe502dd59cc",
+                    "1c31ec87881353ec57fc87c27e31099a0705390c52dbfc8c047d14260658df71",
+                    "2daa8e05eb3367285b5de508d248b3153207498f3e9e51cbe6183ff7dae286a6",
+                    "1a157379821af76197c91be2edaa32896367ec7928c067e6915a03009987517e",
+                    "17c113afe7b749ff849e6920b0f72ce986eaaca1e5b7c467be6e88c7aab5c962",
+                    "116a0553a3b974ae27aff261e6503477ee021839b0139f58655fad1460b738b7",
+                    "0e481714afa543e91943b8ae6894bd64d43d1481c8081a43ee7f857397ffe8d3",
+                ],
+            ),
+            (
+                b"abcdef0123456789",
+                [
+                    "29c7f821157ab18e589d1e7d7bd393d20aff69af2ac4deadc7950998d594d201",
+                    "0860010a5c2ae9289f0d4f7099ff0d5904ded06f99d5960f734de36b82ff983c",
+                    "1f3c50c3ccfbaad8e81f8a765c5465a034b55fb873be48fd60dc21fb2cca98b8",
+                    "02fa095cba1059ef5e2d5ea1c976a87f4530225aa7759b5b9510bb76d7b1d4f3",
+                    "0f07cab202d08b8d3cde56c6c7f5a39a2d35258cf6f4d6664edf6d8912fa56dd",
+                    "2932a6df266b0fd00201295dce06788e882bb863bc8d21cb6f1651ffc23ebefa",
+                    "03346da40e86b4e4a93f4f7997587c2318f8e16df00f36ce41e09a1d84b73092",
+                    "102e1b8b212283cf2c68bd650441d351ed3e8055c2ae4aec221a4bde438dfd7d",
+                ],
+            ),
+            (
+                &[b"q128_".as_slice(), &[b'q'; 128]].concat(),
+                [
+                    "0859e4f9b60f7ce13f81da9da46435c8827ed53f553b4e1804a395af1354b2c7",
+                    "0368bfd8f29d990293171aee9be3bc4ad623c54d0db776d0fe87cfd579059a86",
+                    "103aa84a49f14d0ca1dfda47fa93a43cece0c267ae8799123d63ccd027772f71",
+                    "09ebcb7d529f69c5e7ab096ff1a727ec8bc6c5214ed1784cd7f9e325e121640c",
+                    "2fcb6bd31a83cecad0ca27a1c2412e85924db60d1b171e47239fb93e315d6f37",
+                    "032e3578a4d297dd830c6d0acf9565df67c8d5d208b9a4246d60c623d212cf2e",
+                    "0e5dfbcb4a8de23fe749a9a2ce7824a315f2f5af392f5bb748faf4f7df796e52",
+                    "1612016e04afecdf0f3f62920791d72d4ced3885b06b2b51ea89c6beb09ab5ae",
+                ],
+            ),
+            (
+                &[b"a512_".as_slice(), &[b'a'; 512]].concat(),
+                [
+                    "0f0a229a329e3df7fe4feea02aac7dad3a01d345f65efe512544699439aacd83",
+                    "15b85241a3f8790e550026f37fd861babd3dba9e2bce0deced2df56f7440bbb4",
+                    "0fa59525a85744763ea88a78ca612cb8db4d6e08f3d192568749b90ef16c36b6",
+                    "1c32e85696693c537a91a4283353fba8c24f4107278b82990cc0c595a4d4f6cc",
+                    "2dec143936c0cc57fdad2091b0a6565b704876c9656de3beecc262c3df4994ce",
+                    "09b5b8c980ff9a142f412329e6799e101e6330c459b49faa1617c88d245e60e8",
+                    "290eee412ea2c138bcc0b20fcaef8f38ea86e6f6c3af74accdccc1ca8c0a5524",
+                    "1c2de882b68eb5580f15fd96b9d7f79ce0bbb09ad8a906237d9ac9f82e9c64ec",
+                ],
+            ),
+        ];
+
+        let hasher = G2::hash_to_curve(DOMAIN_PREFIX);
+        for (message, [u00, u01, u10, u11, x0, x1, y0, y1]) in vectors.iter() {
+            let mut us = [Fq2::ZERO; 2];
+            Fq2::hash_to_field(G2_SVDW_SUITE, DOMAIN_PREFIX, message, &mut us);
+            assert_eq!(us, [fq2_from_hex(u00, u01), fq2_from_hex(u10, u11)]);
+
+            let expected = G2Affine::from_xy(fq2_from_hex(x0, x1), fq2_from_hex(y0, y1)).unwrap();
+            assert_eq!(hasher(message).to_affine(), expected);
+        }
+    }
+
+    #[test]
+    fn test_clear_cofactor_g2() {
+        let constants = SvdwConstants::new(G2_SVDW_Z, G2::a(), G2::b());
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+        for _ in 0..100 {
+            // Points on the twist, which are almost never in the prime order subgroup.
+            let p = svdw_map_to_curve::<G2>(Fq2::random(&mut rng), &constants);
+            assert!(!bool::from(p.is_torsion_free()));
+
+            let p = p.clear_cofactor();
+            assert!(bool::from(p.is_on_curve()));
+            assert!(bool::from(p.is_torsion_free()));
+        }
+    }
+
+    #[test]
+    fn test_is_torsion_free_g2() {
+        let constants = SvdwConstants::new(G2_SVDW_Z, G2::a(), G2::b());
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        assert!(bool::from(G2::identity().is_torsion_free()));
+        assert!(bool::from(G2::generator().is_torsion_free()));
+
+        for _ in 0..100 {
+            let p = G2::random(&mut rng);
+            assert!(bool::from(p.is_torsion_free()));
+            assert!(bool::from(p.into_subgroup().is_some()));
+
+            let p = svdw_map_to_curve::<G2>(Fq2::random(&mut rng), &constants);
+            // [r]P == O
+            let in_subgroup = p * -Fr::ONE + p == G2::identity();
+            assert_eq!(bool::from(p.is_torsion_free()), in_subgroup);
+            assert_eq!(bool::from(p.into_subgroup().is_some()), in_subgroup);
+        }
+    }
+
+    #[test]
+    fn test_decoding_rejects_torsion_g2() {
+        let constants = SvdwConstants::new(G2_SVDW_Z, G2::a(), G2::b());
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..10 {
+            let p = svdw_map_to_curve::<G2>(Fq2::random(&mut rng), &constants).to_affine();
+            assert!(!bool::from(p.to_curve().is_torsion_free()));
+
+            let compressed = p.to_bytes();
+            assert!(bool::from(G2Affine::from_bytes(&compressed).is_none()));
+            assert!(bool::from(G2::from_bytes(&compressed).is_none()));
+            assert_eq!(G2Affine::from_bytes_unchecked(&compressed).unwrap(), p);
+            assert_eq!(G2::from_bytes_unchecked(&compressed).unwrap(), p.to_curve());
+
+            let uncompressed = p.to_uncompressed();
+            assert!(bool::from(
+                G2Affine::from_uncompressed(&uncompressed).is_none()
+            ));
+            assert_eq!(
+                G2Affine::from_uncompressed_unchecked(&uncompressed).unwrap(),
+                p
+            );
+        }
+    }
 }
@@ -3,17 +3,21 @@ use crate::bn256::curve::*;
 use crate::bn256::fq::*;
 use crate::bn256::fq12::*;
 use crate::bn256::fq2::*;
-use crate::bn256::fq6::FROBENIUS_COEFF_FQ6_C1;
+use crate::bn256::fq6::{Fq6, FROBENIUS_COEFF_FQ6_C1};
 use crate::bn256::fr::*;
 use crate::ff::{Field, PrimeField};
 use crate::group::cofactor::CofactorCurveAffine;
-use crate::group::Group;
+use crate::group::{Group, GroupEncoding};
 use crate::pairing::{Engine, MillerLoopResult, MultiMillerLoop, PairingCurveAffine};
+use crate::serde::SerdeObject;
 use core::borrow::Borrow;
 use core::iter::Sum;
 use core::ops::{Add, Mul, MulAssign, Neg, Sub};
 use rand_core::RngCore;
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+#[cfg(feature = "derive_serde")]
+use serde::{Deserialize, Serialize};
 
 pub const BN_X: u64 = 4965661367192848881;
 
@@ -60,6 +64,99 @@ impl PairingCurveAffine for G2Affine {
 #[derive(Copy, Clone, Debug, Default)]
 pub struct Gt(pub(crate) Fq12);
 
+/// Canonical generator of $\mathbb{G}_T$, the pairing of the `G1` and `G2`
+/// generators.
+pub const GT_GENERATOR: Gt = Gt(Fq12 {
+    c0: Fq6 {
+        c0: Fq2 {
+            c0: Fq::from_raw([
+                0x8fca81000db9a1f5,
+                0x1bf7f61d72631e26,
+                0x510cd1707e8856f7,
+                0x12c70e90e12b7874,
+            ]),
+            c1: Fq::from_raw([
+                0xb0552990967d4704,
+                0xdeaf3f12aa31f28c,
+                0x6bc2f2ea2b897394,
+                0x084f330485b09e86,
+            ]),
+        },
+        c1: Fq2 {
+            c0: Fq::from_raw([
+                0x96b819a358d34bde,
+                0xbc27fdd375e3605f,
+                0xac9326b9558380e0,
+                0x0e841c2ac18a4003,
+            ]),
+            c1: Fq::from_raw([
+                0xf6b5104c47c8c5d8,
+                0x60224ee5ae15e66a,
+                0xeffa1938c754fe3c,
+                0x2067586885c3318e,
+            ]),
+        },
+        c2: Fq2 {
+            c0: Fq::from_raw([
+                0xbdd02236e14b3636,
+                0x86302996919d4bf4,
+                0x409c4a394bc54268,
+                0x01676555de427abc,
+            ]),
+            c1: Fq::from_raw([
+                0x95e3594468f222c4,
+                0xffc8747ea13e72da,
+                0x72d86df88674c270,
+                0x2b03614464f04dd7,
+            ]),
+        },
+    },
+    c1: Fq6 {
+        c0: Fq2 {
+            c0: Fq::from_raw([
+                0x30c866276c565909,
+                0xaf25d7859cfbc12c,
+                0x38fb30ddc8ac3bf0,
+                0x2c53748bcd21a7c0,
+            ]),
+            c1: Fq::from_raw([
+                0xb2ab862411536104,
+                0x669c026360e37cc5,
+                0x6e710bbfbd2fd922,
+                0x27ed208e7a0b55ae,
+            ]),
+        },
+        c1: Fq2 {
+            c0: Fq::from_raw([
+                0x9d762b47edb3b54a,
+                0x17411fa48dba8d49,
+                0xac462173d31d3d61,
+                0x1ad9db1937fd72f4,
+            ]),
+            c1: Fq::from_raw([
+                0x64889c79fc038ee3,
+                0x2b6efae421583875,
+                0x2532c7c493d8e072,
+                0x279db296f9d47929,
+            ]),
+        },
+        c2: Fq2 {
+            c0: Fq::from_raw([
+                0x17f0f6d08745a069,
+                0x0ba4c70c94b29b5f,
+                0x029bd441d77c221f,
+                0x0dc26f240656bbe2,
+            ]),
+            c1: Fq::from_raw([
+                0x63729f5257628417,
+                0xd6cc3bda6c4d5376,
+                0x44d0f110405d3856,
+                0x108c19d15f9446f7,
+            ]),
+        },
+    },
+});
+
 impl std::fmt::Display for Gt {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:?}", self)
@@ -96,6 +193,15 @@ impl Gt {
     pub fn double(&self) -> Gt {
         Gt(self.0.square())
     }
+
+    /// Raises this element to `scalar` in variable time, using a width-5 NAF
+    /// of the scalar. Inversion in $\mathbb{G}_T$ is a conjugation, so
+    /// negative digits cost the same as positive ones.
+    ///
+    /// This is not constant time and must only be used with public scalars.
+    pub fn mul_vartime(&self, scalar: &Fr) -> Gt {
+        crate::arithmetic::mul_vartime(self, scalar)
+    }
 }
 
 impl<'a> Neg for &'a Gt {
@@ -180,8 +286,8 @@ where
 impl Group for Gt {
     type Scalar = Fr;
 
-    fn random(_: impl RngCore) -> Self {
-        unimplemented!();
+    fn random(rng: impl RngCore) -> Self {
+        GT_GENERATOR * Fr::random(rng)
     }
 
     fn identity() -> Self {
@@ -189,7 +295,7 @@ impl Group for Gt {
     }
 
     fn generator() -> Self {
-        unimplemented!();
+        GT_GENERATOR
     }
 
     fn is_identity(&self) -> Choice {
@@ -202,7 +308,181 @@ impl Group for Gt {
     }
 }
 
-#[derive(Clone, Debug)]
+/// Size of the compressed encoding of a `Gt` element, an `Fq6` element in
+/// the T2 torus representation.
+const GT_COMPRESSED_SIZE: usize = 192;
+
+/// Compressed encoding of a `Gt` element.
+#[derive(Copy, Clone)]
+pub struct GtCompressed([u8; GT_COMPRESSED_SIZE]);
+
+impl std::fmt::Debug for GtCompressed {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0[..].fmt(f)
+    }
+}
+
+impl Default for GtCompressed {
+    fn default() -> Self {
+        GtCompressed([0; GT_COMPRESSED_SIZE])
+    }
+}
+
+impl AsRef<[u8]> for GtCompressed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for GtCompressed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl Gt {
+    /// Returns whether this element lies in the subgroup of order r of
+    /// $\mathbb{F}_{q^{12}}^*$, which is $\mathbb{G}_T$.
+    pub fn is_torsion_free(&self) -> Choice {
+        // g^r = 1 if and only if g^(r - 1) = g^-1.
+        let inv = self.0.invert();
+        let g_r_minus_1 = self * -Fr::ONE;
+        inv.is_some() & g_r_minus_1.0.ct_eq(&inv.unwrap_or(Fq12::ONE))
+    }
+
+    fn coeffs(&self) -> [Fq2; 6] {
+        let Fq12 { c0, c1 } = self.0;
+        [c0.c0, c0.c1, c0.c2, c1.c0, c1.c1, c1.c2]
+    }
+
+    fn from_coeffs(coeffs: [Fq2; 6]) -> Gt {
+        let [c00, c01, c02, c10, c11, c12] = coeffs;
+        Gt(Fq12 {
+            c0: Fq6 {
+                c0: c00,
+                c1: c01,
+                c2: c02,
+            },
+            c1: Fq6 {
+                c0: c10,
+                c1: c11,
+                c2: c12,
+            },
+        })
+    }
+}
+
+/// `v` as an element of `Fq6`, with `w^2 = v` in `Fq12`.
+const FQ6_V: Fq6 = Fq6 {
+    c0: Fq2::zero(),
+    c1: Fq2::one(),
+    c2: Fq2::zero(),
+};
+
+// The cyclotomic elements c0 + c1 * w other than -1 are written (m + w) / (m - w)
+// with m = (1 + c0) / c1 in Fq6, halving their size. The identity is encoded as
+// m = 0, which would otherwise stand for -1, an element outside of Gt.
+impl GroupEncoding for Gt {
+    type Repr = GtCompressed;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_bytes_unchecked(bytes).and_then(|g| CtOption::new(g, g.is_torsion_free()))
+    }
+
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        let mut m = [Fq2::zero(); 3];
+        let mut is_canonical = Choice::from(1u8);
+        for (i, c) in m.iter_mut().enumerate() {
+            let mut buf = [0u8; 64];
+            buf.copy_from_slice(&bytes.0[i * 64..(i + 1) * 64]);
+            let decoded = Fq2::from_bytes(&buf);
+            is_canonical &= decoded.is_some();
+            *c = decoded.unwrap_or(Fq2::zero());
+        }
+        let m = Fq6 {
+            c0: m[0],
+            c1: m[1],
+            c2: m[2],
+        };
+
+        // (m + w) / (m - w) = (m^2 + v + 2m * w) / (m^2 - v), where m^2 - v is
+        // never zero as v is not a square in Fq6.
+        let m2 = m.square();
+        let den = (m2 - FQ6_V).invert().unwrap();
+        let g = Gt(Fq12 {
+            c0: (m2 + FQ6_V) * den,
+            c1: m.double() * den,
+        });
+        CtOption::new(
+            Gt::conditional_select(&g, &Gt::identity(), m.is_zero()),
+            is_canonical,
+        )
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        // c1 is only zero for the identity, which gives m = 0.
+        let m = (self.0.c0 + Fq6::one()) * self.0.c1.invert().unwrap_or(Fq6::zero());
+        let mut bytes = [0u8; GT_COMPRESSED_SIZE];
+        for (i, c) in [m.c0, m.c1, m.c2].iter().enumerate() {
+            bytes[i * 64..(i + 1) * 64].copy_from_slice(&c.to_bytes());
+        }
+        GtCompressed(bytes)
+    }
+}
+
+impl SerdeObject for Gt {
+    fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
+        debug_assert_eq!(bytes.len(), 384);
+        let mut coeffs = [Fq2::zero(); 6];
+        for (i, c) in coeffs.iter_mut().enumerate() {
+            *c = Fq2::from_raw_bytes_unchecked(&bytes[i * 64..(i + 1) * 64]);
+        }
+        Self::from_coeffs(coeffs)
+    }
+    fn from_raw_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 384 {
+            return None;
+        }
+        let mut coeffs = [Fq2::zero(); 6];
+        for (i, c) in coeffs.iter_mut().enumerate() {
+            *c = Fq2::from_raw_bytes(&bytes[i * 64..(i + 1) * 64])?;
+        }
+        let g = Self::from_coeffs(coeffs);
+        bool::from(g.is_torsion_free()).then_some(g)
+    }
+    fn to_raw_bytes(&self) -> Vec<u8> {
+        let mut res = Vec::with_capacity(384);
+        for c in self.coeffs().iter() {
+            res.extend_from_slice(&c.to_raw_bytes());
+        }
+        res
+    }
+    fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
+        Self::from_coeffs([(); 6].map(|_| Fq2::read_raw_unchecked(reader)))
+    }
+    fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut coeffs = [Fq2::zero(); 6];
+        for c in coeffs.iter_mut() {
+            *c = Fq2::read_raw(reader)?;
+        }
+        let g = Self::from_coeffs(coeffs);
+        bool::from(g.is_torsion_free()).then_some(g).ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "element is not in the subgroup of order r",
+            )
+        })
+    }
+    fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        for c in self.coeffs().iter() {
+            c.write_raw(writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
 pub struct G2Prepared {
     pub(crate) coeffs: Vec<(Fq2, Fq2, Fq2)>,
     pub(crate) infinity: bool,
@@ -448,6 +728,119 @@ impl G2Prepared {
     }
 }
 
+/// Number of line coefficients of a prepared point other than the identity: a
+/// doubling step per digit of 6u + 2 but the last, an addition step per
+/// nonzero digit but the last, and two final addition steps.
+const G2_PREPARED_NUM_COEFFS: usize = {
+    let mut n = SIX_U_PLUS_2_NAF.len() + 1;
+    let mut i = 0;
+    while i < SIX_U_PLUS_2_NAF.len() - 1 {
+        if SIX_U_PLUS_2_NAF[i] != 0 {
+            n += 1;
+        }
+        i += 1;
+    }
+    n
+};
+
+/// Size of the raw encoding of a line coefficient.
+const G2_PREPARED_COEFF_SIZE: usize = 3 * 64;
+
+// The raw encoding is a flag byte, 1 for the identity and 0 otherwise,
+// followed by the raw line coefficients, of which the identity has none.
+impl SerdeObject for G2Prepared {
+    fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
+        let coeffs = bytes[1..]
+            .chunks(G2_PREPARED_COEFF_SIZE)
+            .map(|c| {
+                let [c0, c1, c2] =
+                    [0, 64, 128].map(|i| Fq2::from_raw_bytes_unchecked(&c[i..i + 64]));
+                (c0, c1, c2)
+            })
+            .collect();
+        G2Prepared {
+            coeffs,
+            infinity: bytes[0] == 1,
+        }
+    }
+    fn from_raw_bytes(bytes: &[u8]) -> Option<Self> {
+        let infinity = match bytes.first() {
+            Some(0) => false,
+            Some(1) => true,
+            _ => return None,
+        };
+        let num_coeffs = if infinity { 0 } else { G2_PREPARED_NUM_COEFFS };
+        if bytes.len() != 1 + num_coeffs * G2_PREPARED_COEFF_SIZE {
+            return None;
+        }
+
+        let mut coeffs = Vec::with_capacity(num_coeffs);
+        for c in bytes[1..].chunks(G2_PREPARED_COEFF_SIZE) {
+            let c0 = Fq2::from_raw_bytes(&c[..64])?;
+            let c1 = Fq2::from_raw_bytes(&c[64..128])?;
+            let c2 = Fq2::from_raw_bytes(&c[128..])?;
+            coeffs.push((c0, c1, c2));
+        }
+        Some(G2Prepared { coeffs, infinity })
+    }
+    fn to_raw_bytes(&self) -> Vec<u8> {
+        let mut res = Vec::with_capacity(1 + self.coeffs.len() * G2_PREPARED_COEFF_SIZE);
+        res.push(self.infinity as u8);
+        for (c0, c1, c2) in self.coeffs.iter() {
+            res.extend_from_slice(&c0.to_raw_bytes());
+            res.extend_from_slice(&c1.to_raw_bytes());
+            res.extend_from_slice(&c2.to_raw_bytes());
+        }
+        res
+    }
+    fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
+        let mut flag = [0u8];
+        reader.read_exact(&mut flag).unwrap();
+        let infinity = flag[0] == 1;
+        let num_coeffs = if infinity { 0 } else { G2_PREPARED_NUM_COEFFS };
+        let coeffs = (0..num_coeffs)
+            .map(|_| {
+                let [c0, c1, c2] = [(); 3].map(|_| Fq2::read_raw_unchecked(reader));
+                (c0, c1, c2)
+            })
+            .collect();
+        G2Prepared { coeffs, infinity }
+    }
+    fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut flag = [0u8];
+        reader.read_exact(&mut flag)?;
+        let infinity = match flag[0] {
+            0 => false,
+            1 => true,
+            _ => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "invalid infinity flag",
+                ))
+            }
+        };
+        let num_coeffs = if infinity { 0 } else { G2_PREPARED_NUM_COEFFS };
+
+        let mut coeffs = Vec::with_capacity(num_coeffs);
+        for _ in 0..num_coeffs {
+            let c0 = Fq2::read_raw(reader)?;
+            let c1 = Fq2::read_raw(reader)?;
+            let c2 = Fq2::read_raw(reader)?;
+            coeffs.push((c0, c1, c2));
+        }
+        Ok(G2Prepared { coeffs, infinity })
+    }
+    fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&[self.infinity as u8])?;
+        for (c0, c1, c2) in self.coeffs.iter() {
+            c0.write_raw(writer)?;
+            c1.write_raw(writer)?;
+            c2.write_raw(writer)?;
+        }
+        Ok(())
+    }
+}
+
 impl From<G2Affine> for G2Prepared {
     fn from(q: G2Affine) -> G2Prepared {
         G2Prepared::from_affine(q)
@@ -458,16 +851,14 @@ impl MillerLoopResult for Gt {
     type Gt = Self;
     // pub fn final_exponentiation(r: &Fq12) -> CtOption<Fq12> {
     fn final_exponentiation(&self) -> Gt {
+        // The hard part raises to exactly (p^4 - p^2 + 1) / r. The chain of
+        // Fuentes-Castañeda et al. needs as many exponentiations by x but
+        // yields a power of this by 2x(6x^2 + 3x + 1), which would change every
+        // value of Gt, so the cost is instead cut in the exponentiations.
+        // Karabina's compressed squarings lose to the NAF of x here, as x has
+        // too many set bits to amortize the decompressions.
         fn exp_by_x(f: &mut Fq12) {
-            let x = BN_X;
-            let mut res = Fq12::ONE;
-            for i in (0..64).rev() {
-                res.cyclotomic_square();
-                if ((x >> i) & 1) == 1 {
-                    res.mul_assign(f);
-                }
-            }
-            *f = res;
+            *f = f.cyclotomic_exp(BN_X);
         }
 
         let r = self.0;
@@ -622,6 +1013,24 @@ pub fn multi_miller_loop(terms: &[(&G1Affine, &G2Prepared)]) -> Gt {
     Gt(f)
 }
 
+/// Computes the same result as `multi_miller_loop`, running the Miller loop of
+/// each share of the terms on its own thread and multiplying the partial
+/// results, which is exact as every step of the loop is multiplicative.
+pub fn multi_miller_loop_parallel(terms: &[(&G1Affine, &G2Prepared)]) -> Gt {
+    #[cfg(feature = "multicore")]
+    {
+        use rayon::prelude::*;
+
+        let chunk_size = terms.len() / rayon::current_num_threads() + 1;
+        terms
+            .par_chunks(chunk_size)
+            .map(multi_miller_loop)
+            .reduce(Gt::identity, |a, b| Gt(a.0 * b.0))
+    }
+    #[cfg(not(feature = "multicore"))]
+    multi_miller_loop(terms)
+}
+
 pub fn pairing(g1: &G1Affine, g2: &G2Affine) -> Gt {
     let g2 = G2Prepared::from_affine(*g2);
     let terms: &[(&G1Affine, &G2Prepared)] = &[(g1, &g2)];
@@ -652,6 +1061,32 @@ impl MultiMillerLoop for Bn256 {
     fn multi_miller_loop(terms: &[(&Self::G1Affine, &Self::G2Prepared)]) -> Self::Result {
         multi_miller_loop(terms)
     }
+
+    fn multi_miller_loop_parallel(terms: &[(&Self::G1Affine, &Self::G2Prepared)]) -> Self::Result {
+        multi_miller_loop_parallel(terms)
+    }
+
+    fn pairing_check(terms: &[(&Self::G1Affine, &Self::G2Prepared)]) -> bool {
+        // Pairs with an identity input contribute a factor of one.
+        if terms
+            .iter()
+            .all(|(p, q)| bool::from(p.is_identity()) || q.is_zero())
+        {
+            return true;
+        }
+        Self::pairing_check_ct(terms).into()
+    }
+
+    fn pairing_check_ct(terms: &[(&Self::G1Affine, &Self::G2Prepared)]) -> Choice {
+        // The final exponentiation lands in the subgroup of order r of the
+        // cyclotomic subgroup, where c0 + c1 * w with c1 = 0 forces c0^2 = 1 and
+        // hence c0 = 1. Checking c1 is therefore enough.
+        multi_miller_loop(terms)
+            .final_exponentiation()
+            .0
+            .c1
+            .is_zero()
+    }
 }
 
 #[cfg(test)]
@@ -659,6 +1094,17 @@ use rand::SeedableRng;
 #[cfg(test)]
 use rand_xorshift::XorShiftRng;
 
+#[test]
+fn test_gt() {
+    crate::tests::group::group_tests::<Gt>();
+
+    assert_eq!(
+        GT_GENERATOR,
+        pairing(&G1Affine::generator(), &G2Affine::generator())
+    );
+    assert_eq!(Gt::generator() * -Fr::ONE, -Gt::generator());
+}
+
 #[test]
 fn test_pairing() {
     let g1 = G1::generator();
@@ -714,6 +1160,45 @@ fn test_pairing() {
     }
 }
 
+#[test]
+fn test_final_exponentiation_power() {
+    // (p^4 - p^2 + 1) / r
+    const HARD_EXP: [u64; 12] = [
+        0xe81bb482ccdf42b1,
+        0x5abf5cc4f49c36d4,
+        0xf1154e7e1da014fd,
+        0xdcc7b44c87cdbacf,
+        0xaaa441e3954bcf8a,
+        0x6b887d56d5095f23,
+        0x79581e16f3fd90c6,
+        0x3b1b1355d189227d,
+        0x4e529a5861876f6b,
+        0x6c0eb522d5b12278,
+        0x331ec15183177faf,
+        0x01baaa710b0759ad,
+    ];
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+    for _ in 0..10 {
+        let a = G1Affine::from(G1::random(&mut rng));
+        let b = G2Prepared::from(G2Affine::from(G2::random(&mut rng)));
+        let f = multi_miller_loop(&[(&a, &b)]);
+
+        // f^((p^6 - 1) (p^2 + 1))
+        let mut easy = f.0;
+        easy.conjugate();
+        easy.mul_assign(&f.0.invert().unwrap());
+        let t = easy;
+        easy.frobenius_map(2);
+        easy.mul_assign(&t);
+
+        assert_eq!(f.final_exponentiation().0, easy.pow_vartime(HARD_EXP));
+    }
+}
+
 #[test]
 fn random_bilinearity_tests() {
     let mut rng = XorShiftRng::from_seed([
@@ -836,3 +1321,196 @@ fn random_miller_loop_tests() {
         assert_eq!(abcd, abcd_with_double_loop);
     }
 }
+
+#[test]
+fn test_pairing_check() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    assert!(Bn256::pairing_check(&[]));
+    assert!(bool::from(Bn256::pairing_check_ct(&[])));
+
+    for _ in 0..10 {
+        let s = Fr::random(&mut rng);
+        let a = G1Affine::from(G1::random(&mut rng));
+        let b = G2Affine::from(G2::random(&mut rng));
+
+        let sa = G1Affine::from(a * s);
+        let sb = G2Prepared::from(G2Affine::from(b * s));
+        let b = G2Prepared::from(b);
+        let identity = G2Prepared::from(G2Affine::identity());
+
+        // e(s * a, b) * e(-a, s * b) = 1
+        let neg_a = -a;
+        assert!(Bn256::pairing_check(&[(&sa, &b), (&neg_a, &sb)]));
+        assert!(bool::from(Bn256::pairing_check_ct(&[
+            (&sa, &b),
+            (&neg_a, &sb)
+        ])));
+        assert!(Bn256::pairing_eq(&sa, &b, &a, &sb));
+
+        assert!(!Bn256::pairing_check(&[(&sa, &b), (&a, &sb)]));
+        assert!(!bool::from(Bn256::pairing_check_ct(&[
+            (&sa, &b),
+            (&a, &sb)
+        ])));
+        assert!(!Bn256::pairing_eq(&sa, &b, &a, &b));
+
+        assert!(Bn256::pairing_check(&[
+            (&G1Affine::identity(), &b),
+            (&a, &identity)
+        ]));
+        assert!(!Bn256::pairing_check(&[
+            (&G1Affine::identity(), &b),
+            (&a, &sb)
+        ]));
+    }
+}
+
+#[test]
+fn test_gt_serialization() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let mut elements = vec![Gt::identity(), Gt::generator(), -Gt::generator()];
+    elements.extend((0..20).map(|_| Gt::random(&mut rng)));
+    for g in elements {
+        assert!(bool::from(g.is_torsion_free()));
+
+        let bytes = g.to_bytes();
+        assert_eq!(Gt::from_bytes(&bytes).unwrap(), g);
+        assert_eq!(Gt::from_bytes_unchecked(&bytes).unwrap(), g);
+
+        let raw = g.to_raw_bytes();
+        assert_eq!(raw.len(), 384);
+        assert_eq!(Gt::from_raw_bytes(&raw), Some(g));
+        assert_eq!(Gt::from_raw_bytes_unchecked(&raw), g);
+        let mut buf = Vec::new();
+        g.write_raw(&mut buf).unwrap();
+        assert_eq!(Gt::read_raw(&mut &buf[..]).unwrap(), g);
+        assert_eq!(Gt::read_raw_unchecked(&mut &buf[..]), g);
+    }
+
+    // Random torus elements have norm one but are almost never of order r.
+    for _ in 0..10 {
+        let mut bytes = GtCompressed::default();
+        for (i, c) in [
+            Fq2::random(&mut rng),
+            Fq2::random(&mut rng),
+            Fq2::random(&mut rng),
+        ]
+        .iter()
+        .enumerate()
+        {
+            bytes.0[i * 64..(i + 1) * 64].copy_from_slice(&c.to_bytes());
+        }
+        let g = Gt::from_bytes_unchecked(&bytes).unwrap();
+        assert!(!bool::from(g.is_torsion_free()));
+        assert!(bool::from(Gt::from_bytes(&bytes).is_none()));
+        assert_eq!(Gt::from_raw_bytes(&g.to_raw_bytes()), None);
+    }
+
+    // Non canonical field elements are rejected.
+    let mut bytes = Gt::generator().to_bytes();
+    bytes.0[..32].copy_from_slice(&[0xff; 32]);
+    assert!(bool::from(Gt::from_bytes_unchecked(&bytes).is_none()));
+
+    // Random Fq12 elements are not in Gt.
+    let g = Gt(Fq12::random(&mut rng));
+    assert!(!bool::from(g.is_torsion_free()));
+    assert_eq!(Gt::from_raw_bytes(&g.to_raw_bytes()), None);
+}
+
+#[test]
+fn test_g2_prepared_serialization() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let mut points = vec![G2Affine::identity(), G2Affine::generator()];
+    points.extend((0..5).map(|_| G2Affine::from(G2::random(&mut rng))));
+    for q in points {
+        let prepared = G2Prepared::from_affine(q);
+        assert_eq!(
+            prepared.coeffs.len(),
+            if prepared.is_zero() {
+                0
+            } else {
+                G2_PREPARED_NUM_COEFFS
+            }
+        );
+
+        let bytes = prepared.to_raw_bytes();
+        assert_eq!(G2Prepared::from_raw_bytes(&bytes), Some(prepared.clone()));
+        assert_eq!(G2Prepared::from_raw_bytes_unchecked(&bytes), prepared);
+
+        let mut buf = Vec::new();
+        prepared.write_raw(&mut buf).unwrap();
+        assert_eq!(buf, bytes);
+        assert_eq!(G2Prepared::read_raw(&mut &buf[..]).unwrap(), prepared);
+        assert_eq!(G2Prepared::read_raw_unchecked(&mut &buf[..]), prepared);
+
+        #[cfg(feature = "derive_serde")]
+        {
+            let bytes = bincode::serialize(&prepared).unwrap();
+            let prepared_rec: G2Prepared = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(prepared, prepared_rec);
+        }
+
+        // A deserialized point still pairs correctly.
+        let p = G1Affine::from(G1::random(&mut rng));
+        let prepared_rec = G2Prepared::from_raw_bytes(&bytes).unwrap();
+        assert_eq!(
+            multi_miller_loop(&[(&p, &prepared_rec)]).final_exponentiation(),
+            pairing(&p, &q)
+        );
+    }
+
+    let bytes = G2Prepared::from_affine(G2Affine::generator()).to_raw_bytes();
+
+    // Invalid flag.
+    let mut invalid = bytes.clone();
+    invalid[0] = 2;
+    assert_eq!(G2Prepared::from_raw_bytes(&invalid), None);
+    assert!(G2Prepared::read_raw(&mut &invalid[..]).is_err());
+
+    // Wrong number of coefficients.
+    assert_eq!(G2Prepared::from_raw_bytes(&bytes[..bytes.len() - 1]), None);
+    assert_eq!(G2Prepared::from_raw_bytes(&bytes[..1]), None);
+    assert_eq!(G2Prepared::from_raw_bytes(&[1, 0]), None);
+    assert!(G2Prepared::read_raw(&mut &bytes[..bytes.len() - 1]).is_err());
+
+    // Non canonical field element.
+    let mut invalid = bytes;
+    invalid[1..33].copy_from_slice(&[0xff; 32]);
+    assert_eq!(G2Prepared::from_raw_bytes(&invalid), None);
+    assert!(G2Prepared::read_raw(&mut &invalid[..]).is_err());
+}
+
+#[test]
+fn test_multi_miller_loop_parallel() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let g1: Vec<G1Affine> = (0..40)
+        .map(|_| G1Affine::from(G1::random(&mut rng)))
+        .collect();
+    let g2: Vec<G2Prepared> = (0..40)
+        .map(|_| G2Prepared::from(G2Affine::from(G2::random(&mut rng))))
+        .collect();
+    let terms: Vec<(&G1Affine, &G2Prepared)> = g1.iter().zip(g2.iter()).collect();
+
+    for n in [0, 1, 2, 7, 40] {
+        assert_eq!(
+            Bn256::multi_miller_loop_parallel(&terms[..n]),
+            Bn256::multi_miller_loop(&terms[..n])
+        );
+    }
+}
@@ -0,0 +1,292 @@
+//! Encodings and reference implementations of the BN254 precompiles of the
+//! Ethereum virtual machine, `ecAdd` (0x06), `ecMul` (0x07) and `ecPairing`
+//! (0x08), as specified in EIP-196 and EIP-197.
+//!
+//! Field elements are 32-byte big endian integers and `Fq2` elements are
+//! written imaginary part first. The point at infinity is encoded as all
+//! zeros.
+
+use crate::bn256::{Bn256, Fq, Fq2, Fr, G1Affine, G2Affine, G2Prepared, G1};
+use crate::ff::FromUniformBytes;
+use crate::group::cofactor::CofactorGroup;
+use crate::group::prime::PrimeCurveAffine;
+use crate::group::Curve;
+use crate::pairing::MultiMillerLoop;
+use crate::CurveAffine;
+
+/// Size of an encoded `G1` point.
+pub const G1_SIZE: usize = 64;
+
+/// Size of an encoded `G2` point.
+pub const G2_SIZE: usize = 128;
+
+/// Size of an `ecPairing` input pair.
+pub const PAIR_SIZE: usize = G1_SIZE + G2_SIZE;
+
+/// Errors of the precompiles, all of which make the call fail.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A coordinate is not smaller than the field modulus.
+    InvalidFieldElement,
+    /// A point is not on the curve.
+    NotOnCurve,
+    /// A `G2` point is not in the subgroup of order r.
+    NotInSubgroup,
+    /// The `ecPairing` input is not a multiple of 192 bytes.
+    InvalidInputLength,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::InvalidFieldElement => write!(f, "coordinate is not a field element"),
+            Error::NotOnCurve => write!(f, "point is not on the curve"),
+            Error::NotInSubgroup => write!(f, "point is not in the subgroup of order r"),
+            Error::InvalidInputLength => write!(f, "input length is not a multiple of 192"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+fn decode_fq(bytes: &[u8]) -> Result<Fq, Error> {
+    let mut repr = [0u8; 32];
+    repr.copy_from_slice(bytes);
+    repr.reverse();
+    Option::from(Fq::from_bytes(&repr)).ok_or(Error::InvalidFieldElement)
+}
+
+fn encode_fq(x: &Fq, out: &mut [u8]) {
+    let mut repr = x.to_bytes();
+    repr.reverse();
+    out.copy_from_slice(&repr);
+}
+
+fn decode_fq2(bytes: &[u8]) -> Result<Fq2, Error> {
+    Ok(Fq2 {
+        c1: decode_fq(&bytes[..32])?,
+        c0: decode_fq(&bytes[32..])?,
+    })
+}
+
+fn encode_fq2(x: &Fq2, out: &mut [u8]) {
+    encode_fq(&x.c1, &mut out[..32]);
+    encode_fq(&x.c0, &mut out[32..]);
+}
+
+/// Decodes a `G1` point, checking that it is on the curve.
+pub fn decode_g1(bytes: &[u8; G1_SIZE]) -> Result<G1Affine, Error> {
+    let x = decode_fq(&bytes[..32])?;
+    let y = decode_fq(&bytes[32..])?;
+    // (0, 0) is not on the curve and stands for the identity.
+    Option::from(G1Affine::from_xy(x, y)).ok_or(Error::NotOnCurve)
+}
+
+/// Encodes a `G1` point.
+pub fn encode_g1(p: &G1Affine) -> [u8; G1_SIZE] {
+    let mut bytes = [0u8; G1_SIZE];
+    encode_fq(&p.x, &mut bytes[..32]);
+    encode_fq(&p.y, &mut bytes[32..]);
+    bytes
+}
+
+/// Decodes a `G2` point, checking that it is on the curve and in the subgroup
+/// of order r.
+pub fn decode_g2(bytes: &[u8; G2_SIZE]) -> Result<G2Affine, Error> {
+    let x = decode_fq2(&bytes[..64])?;
+    let y = decode_fq2(&bytes[64..])?;
+    let p: G2Affine = Option::from(G2Affine::from_xy(x, y)).ok_or(Error::NotOnCurve)?;
+    if bool::from(p.to_curve().is_torsion_free()) {
+        Ok(p)
+    } else {
+        Err(Error::NotInSubgroup)
+    }
+}
+
+/// Encodes a `G2` point.
+pub fn encode_g2(p: &G2Affine) -> [u8; G2_SIZE] {
+    let mut bytes = [0u8; G2_SIZE];
+    encode_fq2(&p.x, &mut bytes[..64]);
+    encode_fq2(&p.y, &mut bytes[64..]);
+    bytes
+}
+
+/// Returns `input[offset..offset + N]`, padded with zeros past the end of the
+/// input as the precompiles do.
+fn read_padded<const N: usize>(input: &[u8], offset: usize) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    if offset < input.len() {
+        let len = (input.len() - offset).min(N);
+        bytes[..len].copy_from_slice(&input[offset..offset + len]);
+    }
+    bytes
+}
+
+/// `ecAdd`: adds the two `G1` points of the first 128 bytes of `input`.
+pub fn ec_add(input: &[u8]) -> Result<[u8; G1_SIZE], Error> {
+    let a = decode_g1(&read_padded(input, 0))?;
+    let b = decode_g1(&read_padded(input, G1_SIZE))?;
+    Ok(encode_g1(&(G1::from(a) + b).to_affine()))
+}
+
+/// `ecMul`: multiplies the `G1` point of the first 64 bytes of `input` by the
+/// 256-bit big endian scalar of the next 32 bytes.
+pub fn ec_mul(input: &[u8]) -> Result<[u8; G1_SIZE], Error> {
+    let p = decode_g1(&read_padded(input, 0))?;
+
+    // G1 has prime order r, so the scalar can be reduced modulo r.
+    let mut scalar = [0u8; 64];
+    scalar[..32].copy_from_slice(&read_padded::<32>(input, G1_SIZE));
+    scalar[..32].reverse();
+    let scalar = Fr::from_uniform_bytes(&scalar);
+
+    Ok(encode_g1(&(p * scalar).to_affine()))
+}
+
+/// `ecPairing`: checks whether the product of the pairings of the `(G1, G2)`
+/// pairs of `input` is one, returning 1 or 0 as a 32-byte big endian integer.
+pub fn ec_pairing(input: &[u8]) -> Result<[u8; 32], Error> {
+    let pairs = input.chunks_exact(PAIR_SIZE);
+    if !pairs.remainder().is_empty() {
+        return Err(Error::InvalidInputLength);
+    }
+
+    let mut g1 = Vec::with_capacity(pairs.len());
+    let mut g2 = Vec::with_capacity(pairs.len());
+    for pair in pairs {
+        g1.push(decode_g1(&read_padded(pair, 0))?);
+        g2.push(G2Prepared::from(decode_g2(&read_padded(pair, G1_SIZE))?));
+    }
+    let terms: Vec<_> = g1.iter().zip(g2.iter()).collect();
+
+    let mut output = [0u8; 32];
+    output[31] = Bn256::pairing_check(&terms) as u8;
+    Ok(output)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bn256::G2;
+    use crate::ff::Field;
+    use crate::group::Group;
+    use rand_core::OsRng;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn test_generator_encodings() {
+        assert_eq!(
+            hex(&encode_g1(&G1Affine::generator())),
+            "0000000000000000000000000000000000000000000000000000000000000001\
+             0000000000000000000000000000000000000000000000000000000000000002"
+        );
+        // The G2 generator of EIP-197.
+        assert_eq!(
+            hex(&encode_g2(&G2Affine::generator())),
+            "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+             1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+             090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+             12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"
+        );
+        assert_eq!(encode_g1(&G1Affine::identity()), [0; G1_SIZE]);
+        assert_eq!(encode_g2(&G2Affine::identity()), [0; G2_SIZE]);
+    }
+
+    #[test]
+    fn test_codecs() {
+        for _ in 0..10 {
+            let p = G1::random(OsRng).to_affine();
+            assert_eq!(decode_g1(&encode_g1(&p)), Ok(p));
+            let q = G2::random(OsRng).to_affine();
+            assert_eq!(decode_g2(&encode_g2(&q)), Ok(q));
+        }
+        assert_eq!(decode_g1(&[0; G1_SIZE]), Ok(G1Affine::identity()));
+        assert_eq!(decode_g2(&[0; G2_SIZE]), Ok(G2Affine::identity()));
+
+        let mut bytes = encode_g1(&G1Affine::generator());
+        bytes[63] = 3;
+        assert_eq!(decode_g1(&bytes), Err(Error::NotOnCurve));
+        bytes[..32].copy_from_slice(&[0xff; 32]);
+        assert_eq!(decode_g1(&bytes), Err(Error::InvalidFieldElement));
+
+        // A point on the twist outside of the subgroup of order r.
+        let mut x = Fq2::ONE;
+        let q = loop {
+            let y2 = x.square() * x + G2Affine::b();
+            if let Some(y) = Option::from(y2.sqrt()) {
+                break G2Affine { x, y };
+            }
+            x += Fq2::ONE;
+        };
+        assert_eq!(decode_g2(&encode_g2(&q)), Err(Error::NotInSubgroup));
+    }
+
+    #[test]
+    fn test_ec_add() {
+        let p = G1::random(OsRng);
+        let q = G1::random(OsRng);
+        let mut input = encode_g1(&p.to_affine()).to_vec();
+        input.extend_from_slice(&encode_g1(&q.to_affine()));
+        assert_eq!(ec_add(&input), Ok(encode_g1(&(p + q).to_affine())));
+
+        // Missing bytes are zeros and extra bytes are ignored.
+        assert_eq!(ec_add(&input[..G1_SIZE]), Ok(encode_g1(&p.to_affine())));
+        assert_eq!(ec_add(&[]), Ok([0; G1_SIZE]));
+        input.extend_from_slice(&[1; 10]);
+        assert_eq!(ec_add(&input), Ok(encode_g1(&(p + q).to_affine())));
+
+        input[0] = 0xff;
+        assert_eq!(ec_add(&input), Err(Error::InvalidFieldElement));
+    }
+
+    #[test]
+    fn test_ec_mul() {
+        let p = G1::random(OsRng);
+        let s = Fr::random(OsRng);
+        let mut input = encode_g1(&p.to_affine()).to_vec();
+        let mut scalar = s.to_bytes();
+        scalar.reverse();
+        input.extend_from_slice(&scalar);
+        assert_eq!(ec_mul(&input), Ok(encode_g1(&(p * s).to_affine())));
+
+        // Scalars are full 256-bit integers: 2^256 - 1 = -1 + 2^256 mod r.
+        input[G1_SIZE..].copy_from_slice(&[0xff; 32]);
+        let mut two_256 = [0u8; 64];
+        two_256[32] = 1;
+        let expected = p * (Fr::from_uniform_bytes(&two_256) - Fr::ONE);
+        assert_eq!(ec_mul(&input), Ok(encode_g1(&expected.to_affine())));
+
+        assert_eq!(ec_mul(&input[..G1_SIZE]), Ok([0; G1_SIZE]));
+    }
+
+    #[test]
+    fn test_ec_pairing() {
+        let one = {
+            let mut one = [0u8; 32];
+            one[31] = 1;
+            one
+        };
+
+        assert_eq!(ec_pairing(&[]), Ok(one));
+        assert_eq!(ec_pairing(&[0; 191]), Err(Error::InvalidInputLength));
+
+        // e(s * P, Q) * e(-P, s * Q) = 1
+        let s = Fr::random(OsRng);
+        let p = G1::random(OsRng);
+        let q = G2::random(OsRng);
+        let mut input = Vec::new();
+        input.extend_from_slice(&encode_g1(&(p * s).to_affine()));
+        input.extend_from_slice(&encode_g2(&q.to_affine()));
+        input.extend_from_slice(&encode_g1(&(-p).to_affine()));
+        input.extend_from_slice(&encode_g2(&(q * s).to_affine()));
+        assert_eq!(ec_pairing(&input), Ok(one));
+
+        assert_eq!(ec_pairing(&input[..PAIR_SIZE]), Ok([0; 32]));
+
+        input[PAIR_SIZE + G1_SIZE] = 0xff;
+        assert_eq!(ec_pairing(&input), Err(Error::InvalidFieldElement));
+    }
+}
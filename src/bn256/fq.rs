@@ -5,7 +5,8 @@ use crate::{field_arithmetic, field_specific};
 
 use super::LegendreSymbol;
 use crate::arithmetic::{adc, mac, sbb};
-use crate::ff::{Field, FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
+use crate::ff::{FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
+use crate::hash_to_curve::{hash_to_field, HashToField};
 use core::convert::TryInto;
 use core::fmt;
 use core::ops::{Add, Mul, Neg, Sub};
@@ -29,7 +30,7 @@ pub struct Fq(pub(crate) [u64; 4]);
 
 /// Constant representing the modulus
 /// q = 0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47
-pub const MODULUS: Fq = Fq([
+const MODULUS: Fq = Fq([
     0x3c208c16d87cfd47,
     0x97816a916871ca8d,
     0xb85045b68181585d,
@@ -83,14 +84,15 @@ const TWO_INV: Fq = Fq::from_raw([
     0x183227397098d014,
 ]);
 
-// TODO: Can we simply put 0 here::
-const ROOT_OF_UNITY: Fq = Fq::zero();
+/// `q - 1 = 2 * t` with `t` odd, so the 2-adic root of unity is
+/// `MULTIPLICATIVE_GENERATOR^t = -1`.
+const ROOT_OF_UNITY: Fq = NEGATIVE_ONE;
 
-// Unused constant for base field
-const ROOT_OF_UNITY_INV: Fq = Fq::zero();
+/// Inverse of [`ROOT_OF_UNITY`].
+const ROOT_OF_UNITY_INV: Fq = NEGATIVE_ONE;
 
-// Unused constant for base field
-const DELTA: Fq = Fq::zero();
+/// Generator of the t-order multiplicative subgroup, `MULTIPLICATIVE_GENERATOR^2`.
+const DELTA: Fq = Fq::from_raw([0x09, 0x0, 0x0, 0x0]);
 
 /// `ZETA^3 = 1 mod r` where `ZETA^2 != 1 mod r`
 const ZETA: Fq = Fq::from_raw([
@@ -131,22 +133,15 @@ impl Fq {
         32
     }
 
+    /// Returns the Legendre symbol of this element, computed as the Jacobi
+    /// symbol of its canonical representative. This is variable time.
     pub fn legendre(&self) -> LegendreSymbol {
-        // s = self^((modulus - 1) // 2)
-        // 0x183227397098d014dc2822db40c0ac2ecbc0b548b438e5469e10460b6c3e7ea3
-        let s = &[
-            0x9e10460b6c3e7ea3u64,
-            0xcbc0b548b438e546u64,
-            0xdc2822db40c0ac2eu64,
-            0x183227397098d014u64,
-        ];
-        let s = self.pow(s);
-        if s == Self::zero() {
-            LegendreSymbol::Zero
-        } else if s == Self::one() {
-            LegendreSymbol::QuadraticResidue
-        } else {
-            LegendreSymbol::QuadraticNonResidue
+        let tmp =
+            Self::montgomery_reduce(&[self.0[0], self.0[1], self.0[2], self.0[3], 0, 0, 0, 0]);
+        match crate::safegcd::jacobi(&tmp.0, &MODULUS.0) {
+            0 => LegendreSymbol::Zero,
+            1 => LegendreSymbol::QuadraticResidue,
+            _ => LegendreSymbol::QuadraticNonResidue,
         }
     }
 }
@@ -190,14 +185,7 @@ impl ff::Field for Fq {
     /// Computes the multiplicative inverse of this element,
     /// failing if the element is zero.
     fn invert(&self) -> CtOption<Self> {
-        let tmp = self.pow(&[
-            0x3c208c16d87cfd45,
-            0x97816a916871ca8d,
-            0xb85045b68181585d,
-            0x30644e72e131a029,
-        ]);
-
-        CtOption::new(tmp, !self.ct_eq(&Self::zero()))
+        self.invert_safegcd()
     }
 }
 
@@ -212,7 +200,7 @@ impl ff::PrimeField for Fq {
     const ROOT_OF_UNITY_INV: Self = ROOT_OF_UNITY_INV;
     const TWO_INV: Self = TWO_INV;
     const DELTA: Self = DELTA;
-    const S: u32 = 0;
+    const S: u32 = 1;
 
     fn from_repr(repr: Self::Repr) -> CtOption<Self> {
         let mut tmp = Fq([0, 0, 0, 0]);
@@ -264,7 +252,7 @@ impl FromUniformBytes<64> for Fq {
     /// Converts a 512-bit little endian integer into
     /// an `Fq` by reducing by the modulus.
     fn from_uniform_bytes(bytes: &[u8; 64]) -> Self {
-        Self::from_u512([
+        Self::from_wide(&[
             u64::from_le_bytes(bytes[0..8].try_into().unwrap()),
             u64::from_le_bytes(bytes[8..16].try_into().unwrap()),
             u64::from_le_bytes(bytes[16..24].try_into().unwrap()),
@@ -277,6 +265,19 @@ impl FromUniformBytes<64> for Fq {
     }
 }
 
+impl HashToField for Fq {
+    // ceil((254 + 128) / 8)
+    const HASH_LEN: usize = 48;
+
+    fn hash_to_field(suite: &str, domain_prefix: &str, message: &[u8], buf: &mut [Self]) {
+        hash_to_field(suite, domain_prefix, message, buf)
+    }
+
+    fn sgn0(&self) -> Choice {
+        self.is_odd()
+    }
+}
+
 impl WithSmallOrderMulGroup<3> for Fq {
     const ZETA: Self = ZETA;
 }
@@ -323,6 +324,29 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_legendre() {
+        assert_eq!(Fq::zero().legendre(), LegendreSymbol::Zero);
+        assert_eq!(Fq::one().legendre(), LegendreSymbol::QuadraticResidue);
+
+        // Euler's criterion: a^((p - 1) / 2)
+        let s = [
+            0x9e10460b6c3e7ea3u64,
+            0xcbc0b548b438e546u64,
+            0xdc2822db40c0ac2eu64,
+            0x183227397098d014u64,
+        ];
+        for _ in 0..1000 {
+            let a = Fq::random(OsRng);
+            let expected = if a.pow(s) == Fq::one() {
+                LegendreSymbol::QuadraticResidue
+            } else {
+                LegendreSymbol::QuadraticNonResidue
+            };
+            assert_eq!(a.legendre(), expected);
+        }
+    }
+
     #[test]
     fn test_from_u512() {
         assert_eq!(
@@ -332,7 +356,7 @@ mod test {
                 0xaaa7987907d73ae2,
                 0x24d349431d468e30,
             ]),
-            Fq::from_u512([
+            Fq::from_wide(&[
                 0xaaaaaaaaaaaaaaaa,
                 0xaaaaaaaaaaaaaaaa,
                 0xaaaaaaaaaaaaaaaa,
@@ -345,6 +369,42 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_delta() {
+        assert_eq!(
+            Fq::DELTA,
+            MULTIPLICATIVE_GENERATOR.pow([1u64 << Fq::S, 0, 0, 0])
+        );
+    }
+
+    #[test]
+    fn test_root_of_unity() {
+        assert_eq!(
+            Fq::ROOT_OF_UNITY.pow_vartime([1 << Fq::S, 0, 0, 0]),
+            Fq::one()
+        );
+        assert_ne!(
+            Fq::ROOT_OF_UNITY.pow_vartime([1 << (Fq::S - 1), 0, 0, 0]),
+            Fq::one()
+        );
+
+        // t = (q - 1) / 2^S
+        assert_eq!(
+            Fq::ROOT_OF_UNITY,
+            MULTIPLICATIVE_GENERATOR.pow_vartime([
+                0x9e10460b6c3e7ea3,
+                0xcbc0b548b438e546,
+                0xdc2822db40c0ac2e,
+                0x183227397098d014,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_inv_root_of_unity() {
+        assert_eq!(Fq::ROOT_OF_UNITY_INV, Fq::ROOT_OF_UNITY.invert().unwrap());
+    }
+
     #[test]
     fn test_field() {
         crate::tests::field::random_field_tests::<Fq>("fq".to_string());
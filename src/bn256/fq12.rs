@@ -291,6 +291,34 @@ impl Fq12 {
         t2.double_assign();
         self.c0.c2 = t2 + t5;
     }
+
+    /// Raises an element of the cyclotomic subgroup to `exp` with cyclotomic
+    /// squarings over the non-adjacent form of `exp`, where inversion is a
+    /// conjugation.
+    ///
+    /// This is not constant time in `exp`.
+    pub fn cyclotomic_exp(&self, exp: u64) -> Fq12 {
+        let mut naf = Vec::with_capacity(65);
+        let mut k = exp as u128;
+        while k != 0 {
+            let digit = if k & 1 == 1 { 2 - (k & 3) as i8 } else { 0 };
+            k = (k as i128 - digit as i128) as u128 >> 1;
+            naf.push(digit);
+        }
+
+        let mut inv = *self;
+        inv.conjugate();
+        let mut res = Fq12::one();
+        for digit in naf.iter().rev() {
+            res.cyclotomic_square();
+            match digit {
+                1 => res *= self,
+                -1 => res *= inv,
+                _ => {}
+            }
+        }
+        res
+    }
 }
 
 impl Field for Fq12 {
@@ -600,3 +628,26 @@ fn test_frobenius() {
 fn test_field() {
     crate::tests::field::random_field_tests::<Fq12>("fq12".to_string());
 }
+
+#[test]
+fn test_cyclotomic_exp() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    for _ in 0..100 {
+        // f^((p^6 - 1)(p^2 + 1)) lies in the cyclotomic subgroup.
+        let f = Fq12::random(&mut rng);
+        let mut g = f;
+        g.conjugate();
+        g *= f.invert().unwrap();
+        let mut t = g;
+        t.frobenius_map(2);
+        g *= t;
+
+        for exp in [0, 1, 2, 3, 0xff, crate::bn256::BN_X, u64::MAX] {
+            assert_eq!(g.cyclotomic_exp(exp), g.pow_vartime([exp]));
+        }
+    }
+}
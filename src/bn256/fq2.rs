@@ -1,6 +1,7 @@
 use super::fq::{Fq, NEGATIVE_ONE};
 use super::LegendreSymbol;
 use crate::ff::{Field, FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
+use crate::hash_to_curve::HashToField;
 use core::convert::TryInto;
 use core::ops::{Add, Mul, Neg, Sub};
 use rand::RngCore;
@@ -156,8 +157,8 @@ impl Fq2 {
         let c1 = Fq::from_bytes(bytes[32..64].try_into().unwrap());
         CtOption::new(
             Fq2 {
-                c0: c0.unwrap(),
-                c1: c1.unwrap(),
+                c0: c0.unwrap_or(Fq::zero()),
+                c1: c1.unwrap_or(Fq::zero()),
             },
             c0.is_some() & c1.is_some(),
         )
@@ -487,6 +488,26 @@ impl FromUniformBytes<64> for Fq2 {
         Self::new(Fq::from_uniform_bytes(bytes), Fq::zero())
     }
 }
+
+impl HashToField for Fq2 {
+    const HASH_LEN: usize = Fq::HASH_LEN;
+
+    fn hash_to_field(suite: &str, domain_prefix: &str, message: &[u8], buf: &mut [Self]) {
+        // Each element of the extension takes two consecutive base field elements.
+        let mut coeffs = vec![Fq::zero(); 2 * buf.len()];
+        Fq::hash_to_field(suite, domain_prefix, message, &mut coeffs);
+        for (e, c) in buf.iter_mut().zip(coeffs.chunks_exact(2)) {
+            *e = Fq2::new(c[0], c[1]);
+        }
+    }
+
+    fn sgn0(&self) -> Choice {
+        let sign_0 = self.c0.is_odd();
+        let zero_0 = self.c0.is_zero();
+        let sign_1 = self.c1.is_odd();
+        sign_0 | (zero_0 & sign_1)
+    }
+}
 #[derive(Clone, Copy, Debug)]
 pub struct Fq2Bytes([u8; 64]);
 
@@ -5,6 +5,7 @@ use crate::{field_arithmetic, field_specific};
 
 use crate::arithmetic::{adc, mac, sbb};
 use crate::ff::{FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
+use crate::hash_to_curve::{hash_to_field, HashToField};
 use core::convert::TryInto;
 use core::fmt;
 use core::ops::{Add, Mul, Neg, Sub};
@@ -28,7 +29,7 @@ pub struct Fr(pub(crate) [u64; 4]);
 
 /// Constant representing the modulus
 /// r = 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
-pub const MODULUS: Fr = Fr([
+const MODULUS: Fr = Fr([
     0x43e1f593f0000001,
     0x2833e84879b97091,
     0xb85045b68181585d,
@@ -143,12 +144,18 @@ field_arithmetic!(Fr, MODULUS, INV, sparse);
 #[cfg(feature = "asm")]
 field_arithmetic_asm!(Fr, MODULUS, INV);
 
+impl Fr {
+    pub const fn size() -> usize {
+        32
+    }
+}
+
 impl ff::Field for Fr {
     const ZERO: Self = Self::zero();
     const ONE: Self = Self::one();
 
     fn random(mut rng: impl RngCore) -> Self {
-        Self::from_u512([
+        Self::from_wide(&[
             rng.next_u64(),
             rng.next_u64(),
             rng.next_u64(),
@@ -172,14 +179,7 @@ impl ff::Field for Fr {
     /// Computes the multiplicative inverse of this element,
     /// failing if the element is zero.
     fn invert(&self) -> CtOption<Self> {
-        let tmp = self.pow(&[
-            0x43e1f593efffffff,
-            0x2833e84879b97091,
-            0xb85045b68181585d,
-            0x30644e72e131a029,
-        ]);
-
-        CtOption::new(tmp, !self.ct_eq(&Self::zero()))
+        self.invert_safegcd()
     }
 
     fn sqrt(&self) -> CtOption<Self> {
@@ -260,7 +260,7 @@ impl FromUniformBytes<64> for Fr {
     /// Converts a 512-bit little endian integer into
     /// an `Fr` by reducing by the modulus.
     fn from_uniform_bytes(bytes: &[u8; 64]) -> Self {
-        Self::from_u512([
+        Self::from_wide(&[
             u64::from_le_bytes(bytes[0..8].try_into().unwrap()),
             u64::from_le_bytes(bytes[8..16].try_into().unwrap()),
             u64::from_le_bytes(bytes[16..24].try_into().unwrap()),
@@ -273,6 +273,19 @@ impl FromUniformBytes<64> for Fr {
     }
 }
 
+impl HashToField for Fr {
+    // ceil((254 + 128) / 8)
+    const HASH_LEN: usize = 48;
+
+    fn hash_to_field(suite: &str, domain_prefix: &str, message: &[u8], buf: &mut [Self]) {
+        hash_to_field(suite, domain_prefix, message, buf)
+    }
+
+    fn sgn0(&self) -> Choice {
+        self.is_odd()
+    }
+}
+
 impl WithSmallOrderMulGroup<3> for Fr {
     const ZETA: Self = ZETA;
 }
@@ -329,7 +342,7 @@ mod test {
                 0xf04bc41062fd7322,
                 0x1185fa9c9fef6326,
             ]),
-            Fr::from_u512([
+            Fr::from_wide(&[
                 0xaaaaaaaaaaaaaaaa,
                 0xaaaaaaaaaaaaaaaa,
                 0xaaaaaaaaaaaaaaaa,
@@ -1,5 +1,6 @@
 mod curve;
 mod engine;
+pub mod evm;
 mod fq;
 mod fq12;
 mod fq2;
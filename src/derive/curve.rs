@@ -26,7 +26,12 @@ macro_rules! batch_add {
             // - x_3 = lambda^2 - (x_2 + x_1)
             // - y_3 = lambda * (x_1 - x_3) - y_1
 
-            // Batch invert accumulator
+            // Batch invert accumulator. This is Montgomery's trick, written out
+            // rather than using `BatchInvertSlice`: the denominators are spread
+            // over `points[i + 1].x` and the prefix products are folded into the
+            // numerators, so the hot loop of the MSM needs neither a slice of
+            // denominators nor a scratch buffer, which a caller of
+            // `CurveAffineExt::batch_add` has no way to provide.
             let mut acc = Self::Base::one();
 
             for i in (0..num_points).step_by(2) {
@@ -140,6 +145,56 @@ macro_rules! batch_add {
     };
 }
 
+#[macro_export]
+macro_rules! endo {
+    ($name:ident, $scalar:ident, $params:expr) => {
+        impl $crate::CurveEndo for $name {
+            fn decompose_scalar(k: &$scalar) -> (u128, Choice, u128, Choice) {
+                use core::convert::TryInto;
+
+                let params: $crate::arithmetic::EndoParameters = $params;
+
+                let repr = k.to_repr();
+                let repr = repr.as_ref();
+                let k_limbs = [
+                    u64::from_le_bytes(repr[0..8].try_into().unwrap()),
+                    u64::from_le_bytes(repr[8..16].try_into().unwrap()),
+                    u64::from_le_bytes(repr[16..24].try_into().unwrap()),
+                    u64::from_le_bytes(repr[24..32].try_into().unwrap()),
+                ];
+
+                // c1 = round(b2 * k / r), c2 = round(-b1 * k / r)
+                let c1 = $scalar::from_raw($crate::arithmetic::mul_shr_256_round(
+                    &k_limbs,
+                    &params.gamma1,
+                ));
+                let c2 = $scalar::from_raw($crate::arithmetic::mul_shr_256_round(
+                    &k_limbs,
+                    &params.gamma2,
+                ));
+
+                // (k1, k2) = (k, 0) - c1 * (a1, b1) - c2 * (a2, b2)
+                let k1 = k - c1 * $scalar::from_raw(params.a1) - c2 * $scalar::from_raw(params.a2);
+                let k2 = -(c1 * $scalar::from_raw(params.b1)) - c2 * $scalar::from_raw(params.b2);
+
+                // Both halves are short, so a value with any of its upper 128 bits
+                // set is the reduction of a negative one.
+                let to_signed = |e: $scalar| -> (u128, Choice) {
+                    let repr = e.to_repr();
+                    let is_neg = !repr.as_ref()[16..32].ct_eq(&[0u8; 16]);
+                    let repr = $scalar::conditional_select(&e, &-e, is_neg).to_repr();
+                    let abs = u128::from_le_bytes(repr.as_ref()[0..16].try_into().unwrap());
+                    (abs, is_neg)
+                };
+
+                let (k1, k1_neg) = to_signed(k1);
+                let (k2, k2_neg) = to_signed(k2);
+                (k1, k1_neg, k2, k2_neg)
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! new_curve_impl {
     (($($privacy:tt)*),
@@ -151,8 +206,12 @@ macro_rules! new_curve_impl {
     $generator:expr,
     $constant_b:expr,
     $curve_id:literal,
+    $mul:ident,
+    $hash_to_curve:expr,
+    $($encoding:ident,)?
     ) => {
 
+        #[allow(unused_macros)]
         macro_rules! impl_compressed {
             () => {
                 paste::paste! {
@@ -192,6 +251,12 @@ macro_rules! new_curve_impl {
 
 
                     fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+                        use $crate::group::cofactor::CofactorGroup;
+                        Self::from_bytes_unchecked(bytes)
+                            .and_then(|p| CtOption::new(p, p.to_curve().is_torsion_free()))
+                    }
+
+                    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
                         let bytes = &bytes.0;
                         let mut tmp = *bytes;
                         let is_inf = Choice::from(tmp[[< $name _COMPRESSED_SIZE >] - 1] >> 7);
@@ -220,10 +285,6 @@ macro_rules! new_curve_impl {
                         })
                     }
 
-                    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
-                        Self::from_bytes(bytes)
-                    }
-
                     fn to_bytes(&self) -> Self::Repr {
                         if bool::from(self.is_identity()) {
                             let mut bytes = [0; [< $name _COMPRESSED_SIZE >]];
@@ -248,7 +309,7 @@ macro_rules! new_curve_impl {
                     }
 
                     fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
-                        $name_affine::from_bytes(bytes).map(Self::from)
+                        $name_affine::from_bytes_unchecked(bytes).map(Self::from)
                     }
 
                     fn to_bytes(&self) -> Self::Repr {
@@ -260,6 +321,7 @@ macro_rules! new_curve_impl {
             };
         }
 
+        #[allow(unused_macros)]
         macro_rules! impl_uncompressed {
             () => {
 
@@ -316,7 +378,10 @@ macro_rules! new_curve_impl {
                         type Uncompressed = [< $name Uncompressed >];
 
                         fn from_uncompressed(bytes: &Self::Uncompressed) -> CtOption<Self> {
-                            Self::from_uncompressed_unchecked(bytes).and_then(|p| CtOption::new(p, p.is_on_curve()))
+                            use $crate::group::cofactor::CofactorGroup;
+                            Self::from_uncompressed_unchecked(bytes).and_then(|p| {
+                                CtOption::new(p, p.is_on_curve() & p.to_curve().is_torsion_free())
+                            })
                         }
 
                         fn from_uncompressed_unchecked(bytes: &Self::Uncompressed) -> CtOption<Self> {
@@ -377,6 +442,290 @@ macro_rules! new_curve_impl {
 
         }
 
+        // Big-endian encodings with the flags in the three most significant
+        // bits of the first byte, as used by Zcash and Ethereum for BLS12-381:
+        // bit 7 marks a compressed encoding, bit 6 the point at infinity and
+        // bit 5, for compressed points only, that `y` is the lexicographically
+        // largest of `y` and `-y`. Extension field elements are written with
+        // the highest coefficient first.
+        #[allow(unused_macros)]
+        macro_rules! impl_zcash_compressed {
+            () => {
+                paste::paste! {
+
+                #[allow(non_upper_case_globals)]
+                const [< $name _COMPRESSED_SIZE >]: usize = $base::size();
+                #[derive(Copy, Clone)]
+                pub struct [<$name Compressed >]([u8; [< $name _COMPRESSED_SIZE >]]);
+
+                impl std::fmt::Debug for [< $name Compressed >] {
+                    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                        self.0[..].fmt(f)
+                    }
+                }
+
+                impl Default for [< $name Compressed >] {
+                    fn default() -> Self {
+                        [< $name Compressed >]([0; [< $name _COMPRESSED_SIZE >]])
+                    }
+                }
+
+                impl AsRef<[u8]> for [< $name Compressed >] {
+                    fn as_ref(&self) -> &[u8] {
+                        &self.0
+                    }
+                }
+
+                impl AsMut<[u8]> for [< $name Compressed >] {
+                    fn as_mut(&mut self) -> &mut [u8] {
+                        &mut self.0
+                    }
+                }
+
+                impl $name_affine {
+                    /// Returns whether `y` is greater than `-y` when both are
+                    /// read as big-endian integers.
+                    fn is_lexicographically_largest(y: &$base) -> Choice {
+                        use subtle::ConstantTimeGreater;
+
+                        let (a, b) = (y.to_bytes(), (-y).to_bytes());
+                        let mut gt = Choice::from(0u8);
+                        let mut eq = Choice::from(1u8);
+                        for (a, b) in a.iter().zip(b.iter()).rev() {
+                            gt |= eq & a.ct_gt(b);
+                            eq &= a.ct_eq(b);
+                        }
+                        gt
+                    }
+                }
+
+                impl group::GroupEncoding for $name_affine {
+                    type Repr = [< $name Compressed >];
+
+                    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+                        use $crate::group::cofactor::CofactorGroup;
+                        Self::from_bytes_unchecked(bytes)
+                            .and_then(|p| CtOption::new(p, p.to_curve().is_torsion_free()))
+                    }
+
+                    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+                        let compression_flag_set = Choice::from(bytes.0[0] >> 7);
+                        let infinity_flag_set = Choice::from((bytes.0[0] >> 6) & 1);
+                        let sort_flag_set = Choice::from((bytes.0[0] >> 5) & 1);
+
+                        let mut xbytes = [0u8; $base::size()];
+                        for (x, b) in xbytes.iter_mut().zip(bytes.0.iter().rev()) {
+                            *x = *b;
+                        }
+                        xbytes[$base::size() - 1] &= 0b0001_1111;
+
+                        $base::from_bytes(&xbytes).and_then(|x| {
+                            // The point at infinity is encoded with a zero x-coordinate and
+                            // without the sort flag.
+                            CtOption::new(
+                                Self::identity(),
+                                infinity_flag_set & compression_flag_set & (!sort_flag_set) & x.is_zero(),
+                            )
+                            .or_else(|| {
+                                let x3 = x.square() * x;
+                                (x3 + $name::curve_constant_b()).sqrt().and_then(|y| {
+                                    let y = $base::conditional_select(
+                                        &y,
+                                        &-y,
+                                        Self::is_lexicographically_largest(&y) ^ sort_flag_set,
+                                    );
+
+                                    CtOption::new(
+                                        $name_affine { x, y },
+                                        (!infinity_flag_set) & compression_flag_set,
+                                    )
+                                })
+                            })
+                        })
+                    }
+
+                    fn to_bytes(&self) -> Self::Repr {
+                        let x = $base::conditional_select(&self.x, &$base::zero(), self.is_identity());
+                        let mut res = [0u8; [< $name _COMPRESSED_SIZE >]];
+                        for (r, b) in res.iter_mut().zip(x.to_bytes().iter().rev()) {
+                            *r = *b;
+                        }
+
+                        // This point is in compressed form, so we set the most significant bit.
+                        res[0] |= 1u8 << 7;
+                        // Is this point at infinity? If so, set the second-most significant bit.
+                        res[0] |= u8::conditional_select(&0u8, &(1u8 << 6), self.is_identity());
+                        // Is the y-coordinate the lexicographically largest of the two associated
+                        // with the x-coordinate? If so, set the third-most significant bit so long
+                        // as this is not the point at infinity.
+                        res[0] |= u8::conditional_select(
+                            &0u8,
+                            &(1u8 << 5),
+                            (!self.is_identity()) & Self::is_lexicographically_largest(&self.y),
+                        );
+
+                        [< $name Compressed >](res)
+                    }
+                }
+
+                impl GroupEncoding for $name {
+                    type Repr = [< $name Compressed >];
+
+                    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+                        $name_affine::from_bytes(bytes).map(Self::from)
+                    }
+
+                    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+                        $name_affine::from_bytes_unchecked(bytes).map(Self::from)
+                    }
+
+                    fn to_bytes(&self) -> Self::Repr {
+                        $name_affine::from(self).to_bytes()
+                    }
+                }
+
+                }
+            };
+        }
+
+        #[allow(unused_macros)]
+        macro_rules! impl_zcash_uncompressed {
+            () => {
+                paste::paste! {
+
+                #[allow(non_upper_case_globals)]
+                const [< $name _UNCOMPRESSED_SIZE >]: usize = 2 * $base::size();
+                #[derive(Copy, Clone)]
+                pub struct [< $name Uncompressed >]([u8; [< $name _UNCOMPRESSED_SIZE >]]);
+
+                impl std::fmt::Debug for [< $name Uncompressed >] {
+                    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                        self.0[..].fmt(f)
+                    }
+                }
+
+                impl Default for [< $name Uncompressed >] {
+                    fn default() -> Self {
+                        [< $name Uncompressed >]([0; [< $name _UNCOMPRESSED_SIZE >]])
+                    }
+                }
+
+                impl AsRef<[u8]> for [< $name Uncompressed >] {
+                    fn as_ref(&self) -> &[u8] {
+                        &self.0
+                    }
+                }
+
+                impl AsMut<[u8]> for [< $name Uncompressed >] {
+                    fn as_mut(&mut self) -> &mut [u8] {
+                        &mut self.0
+                    }
+                }
+
+                impl ConstantTimeEq for [< $name Uncompressed >] {
+                    fn ct_eq(&self, other: &Self) -> Choice {
+                        self.0.ct_eq(&other.0)
+                    }
+                }
+
+                impl Eq for [< $name Uncompressed >] {}
+
+                impl PartialEq for [< $name Uncompressed >] {
+                    #[inline]
+                    fn eq(&self, other: &Self) -> bool {
+                        bool::from(self.ct_eq(other))
+                    }
+                }
+
+                impl group::UncompressedEncoding for $name_affine {
+                    type Uncompressed = [< $name Uncompressed >];
+
+                    fn from_uncompressed(bytes: &Self::Uncompressed) -> CtOption<Self> {
+                        use $crate::group::cofactor::CofactorGroup;
+                        Self::from_uncompressed_unchecked(bytes).and_then(|p| {
+                            CtOption::new(p, p.is_on_curve() & p.to_curve().is_torsion_free())
+                        })
+                    }
+
+                    fn from_uncompressed_unchecked(bytes: &Self::Uncompressed) -> CtOption<Self> {
+                        let compression_flag_set = Choice::from(bytes.0[0] >> 7);
+                        let infinity_flag_set = Choice::from((bytes.0[0] >> 6) & 1);
+                        let sort_flag_set = Choice::from((bytes.0[0] >> 5) & 1);
+
+                        // Attempt to obtain the x-coordinate
+                        let x = {
+                            let mut tmp = [0u8; $base::size()];
+                            for (t, b) in tmp.iter_mut().zip(bytes.0[..$base::size()].iter().rev()) {
+                                *t = *b;
+                            }
+                            tmp[$base::size() - 1] &= 0b0001_1111;
+                            $base::from_bytes(&tmp)
+                        };
+
+                        // Attempt to obtain the y-coordinate
+                        let y = {
+                            let mut tmp = [0u8; $base::size()];
+                            for (t, b) in tmp.iter_mut().zip(bytes.0[$base::size()..].iter().rev()) {
+                                *t = *b;
+                            }
+                            $base::from_bytes(&tmp)
+                        };
+
+                        x.and_then(|x| {
+                            y.and_then(|y| {
+                                // Create a point representing this value
+                                let p = $name_affine::conditional_select(
+                                    &$name_affine { x, y },
+                                    &$name_affine::identity(),
+                                    infinity_flag_set,
+                                );
+
+                                CtOption::new(
+                                    p,
+                                    // If the infinity flag is set, the x and y coordinates should have been zero.
+                                    ((!infinity_flag_set) | (x.is_zero() & y.is_zero()))
+                                        // The compression and sort flags should not have been set.
+                                        & (!compression_flag_set)
+                                        & (!sort_flag_set),
+                                )
+                            })
+                        })
+                    }
+
+                    fn to_uncompressed(&self) -> Self::Uncompressed {
+                        let mut res = [0; [< $name _UNCOMPRESSED_SIZE >]];
+
+                        let x = $base::conditional_select(&self.x, &$base::zero(), self.is_identity());
+                        let y = $base::conditional_select(&self.y, &$base::zero(), self.is_identity());
+                        for (r, b) in res[..$base::size()].iter_mut().zip(x.to_bytes().iter().rev()) {
+                            *r = *b;
+                        }
+                        for (r, b) in res[$base::size()..].iter_mut().zip(y.to_bytes().iter().rev()) {
+                            *r = *b;
+                        }
+
+                        // Is this point at infinity? If so, set the second-most significant bit.
+                        res[0] |= u8::conditional_select(&0u8, &(1u8 << 6), self.is_identity());
+
+                        [< $name Uncompressed >](res)
+                    }
+                }
+
+                }
+            };
+        }
+
+        macro_rules! impl_encodings {
+            () => {
+                impl_compressed!();
+                impl_uncompressed!();
+            };
+            (zcash) => {
+                impl_zcash_compressed!();
+                impl_zcash_uncompressed!();
+            };
+        }
+
         #[derive(Copy, Clone, Debug)]
         #[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
         $($privacy)* struct $name {
@@ -394,8 +743,7 @@ macro_rules! new_curve_impl {
 
 
 
-        impl_compressed!();
-        impl_uncompressed!();
+        impl_encodings!($($encoding)?);
 
 
 
@@ -409,6 +757,15 @@ macro_rules! new_curve_impl {
                 }
             }
 
+            /// Multiplies this point by `scalar` in variable time, using a
+            /// width-5 NAF of the scalar.
+            ///
+            /// This is not constant time and must only be used with public
+            /// scalars, e.g. when verifying.
+            pub fn mul_vartime(&self, scalar: &$scalar) -> Self {
+                $crate::arithmetic::mul_vartime(self, scalar)
+            }
+
             const fn curve_constant_b() -> $base {
                 $name_affine::curve_constant_b()
             }
@@ -438,6 +795,15 @@ macro_rules! new_curve_impl {
                 }
             }
 
+            /// Multiplies this point by `scalar` in variable time, using a
+            /// width-5 NAF of the scalar.
+            ///
+            /// This is not constant time and must only be used with public
+            /// scalars, e.g. when verifying.
+            pub fn mul_vartime(&self, scalar: &$scalar) -> $name {
+                self.to_curve().mul_vartime(scalar)
+            }
+
             const fn curve_constant_b() -> $base {
                 $constant_b
             }
@@ -554,8 +920,8 @@ macro_rules! new_curve_impl {
             }
 
 
-            fn hash_to_curve<'a>(_: &'a str) -> Box<dyn Fn(&[u8]) -> Self + 'a> {
-                unimplemented!();
+            fn hash_to_curve<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> Self + 'a> {
+                $hash_to_curve(domain_prefix)
             }
 
             fn is_on_curve(&self) -> Choice {
@@ -596,34 +962,27 @@ macro_rules! new_curve_impl {
             fn batch_normalize(p: &[Self], q: &mut [Self::AffineRepr]) {
                 assert_eq!(p.len(), q.len());
 
-                let mut acc = $base::one();
-                for (p, q) in p.iter().zip(q.iter_mut()) {
-                    // We use the `x` field of $name_affine to store the product
-                    // of previous z-coordinates seen.
-                    q.x = acc;
-
-                    // We will end up skipping all identities in p
-                    acc = $base::conditional_select(&(acc * p.z), &acc, p.is_identity());
-                }
-
-                // This is the inverse, as all z-coordinates are nonzero and the ones
-                // that are not are skipped.
-                acc = acc.invert().unwrap();
-
-                for (p, q) in p.iter().rev().zip(q.iter_mut().rev()) {
-                    let skip = p.is_identity();
-
-                    // Compute tmp = 1/z
-                    let tmp = q.x * acc;
-
-                    // Cancel out z-coordinate in denominator of `acc`
-                    acc = $base::conditional_select(&(acc * p.z), &acc, skip);
-
-                    q.x = p.x * tmp;
-                    q.y = p.y * tmp;
+                // Every chunk shares a single inversion, and the z-coordinate of
+                // the identity, which is zero, is left unchanged.
+                $crate::arithmetic::parallelize(q, |q, start| {
+                    let p = &p[start..start + q.len()];
+                    // The inverses and the prefix products of the inversion share a
+                    // single allocation.
+                    let mut buf = vec![$base::zero(); 2 * q.len()];
+                    let (zinvs, scratch) = buf.split_at_mut(q.len());
+                    for (zinv, p) in zinvs.iter_mut().zip(p.iter()) {
+                        *zinv = p.z;
+                    }
+                    $crate::BatchInvertSlice::batch_invert_with_scratch(&mut *zinvs, scratch);
 
-                    *q = $name_affine::conditional_select(&q, &$name_affine::identity(), skip);
-                }
+                    for ((p, q), zinv) in p.iter().zip(q.iter_mut()).zip(zinvs.iter()) {
+                        let tmp = $name_affine {
+                            x: p.x * zinv,
+                            y: p.y * zinv,
+                        };
+                        *q = $name_affine::conditional_select(&tmp, &$name_affine::identity(), p.is_identity());
+                    }
+                });
             }
 
             fn to_affine(&self) -> Self::AffineRepr {
@@ -1064,30 +1423,66 @@ macro_rules! new_curve_impl {
 
 
 
-        #[allow(clippy::suspicious_arithmetic_impl)]
-        impl<'a, 'b> Mul<&'b $scalar> for &'a $name {
-            type Output = $name;
+        #[allow(unused_macros)]
+        macro_rules! impl_mul {
+            (glv) => {
+                #[allow(clippy::suspicious_arithmetic_impl)]
+                impl<'a, 'b> Mul<&'b $scalar> for &'a $name {
+                    type Output = $name;
+
+                    // GLV scalar multiplication: the scalar is decomposed as
+                    // k1 + k2 * λ and [k1]P + [k2]ψ(P) is computed with a joint
+                    // signed fixed-window ladder over both 128-bit halves. This
+                    // is only correct on curves of prime order, where ψ acts as
+                    // λ on every point.
+                    fn mul(self, other: &'b $scalar) -> Self::Output {
+                        use $crate::arithmetic::{
+                            lookup_ct, mul_table, recode_signed_ct, MUL_NUM_DIGITS, MUL_WINDOW,
+                        };
 
-            // This is a simple double-and-add implementation of point
-            // multiplication, moving from most significant to least
-            // significant bit of the scalar.
+                        let (k1, k1_neg, k2, k2_neg) =
+                            <$name as $crate::CurveEndo>::decompose_scalar(other);
 
-            fn mul(self, other: &'b $scalar) -> Self::Output {
-                let mut acc = $name::identity();
-                for bit in other
-                    .to_repr()
-                    .iter()
-                    .rev()
-                    .flat_map(|byte| (0..8).rev().map(move |i| Choice::from((byte >> i) & 1u8)))
-                {
-                    acc = acc.double();
-                    acc = $name::conditional_select(&acc, &(acc + self), bit);
+                        // P1 and P2 are P and ψ(P) negated along with k1 and k2.
+                        let p1 = $name::conditional_select(self, &-self, k1_neg);
+                        let table1 = mul_table(&p1);
+                        let mut table2 = table1;
+                        for t in table2.iter_mut() {
+                            let e = t.endo();
+                            *t = $name::conditional_select(&e, &-e, k1_neg ^ k2_neg);
+                        }
+
+                        let mut digits1 = [0i8; MUL_NUM_DIGITS];
+                        let mut digits2 = [0i8; MUL_NUM_DIGITS];
+                        recode_signed_ct(&k1.to_le_bytes(), MUL_WINDOW, &mut digits1);
+                        recode_signed_ct(&k2.to_le_bytes(), MUL_WINDOW, &mut digits2);
+
+                        let mut acc = $name::identity();
+                        for i in (0..MUL_NUM_DIGITS).rev() {
+                            for _ in 0..MUL_WINDOW {
+                                acc = acc.double();
+                            }
+                            acc += lookup_ct(&table1, digits1[i]);
+                            acc += lookup_ct(&table2, digits2[i]);
+                        }
+
+                        acc
+                    }
                 }
+            };
+            (fixed_window) => {
+                impl<'a, 'b> Mul<&'b $scalar> for &'a $name {
+                    type Output = $name;
 
-                acc
-            }
+                    fn mul(self, other: &'b $scalar) -> Self::Output {
+                        $crate::arithmetic::mul_ct(self, other)
+                    }
+                }
+            };
         }
 
+        impl_mul!($mul);
+
         impl<'a> Neg for &'a $name_affine {
             type Output = $name_affine;
 
@@ -1144,23 +1539,7 @@ macro_rules! new_curve_impl {
             type Output = $name;
 
             fn mul(self, other: &'b $scalar) -> Self::Output {
-                let mut acc = $name::identity();
-
-                // This is a simple double-and-add implementation of point
-                // multiplication, moving from most significant to least
-                // significant bit of the scalar.
-
-                for bit in other
-                    .to_repr()
-                    .iter()
-                    .rev()
-                    .flat_map(|byte| (0..8).rev().map(move |i| Choice::from((byte >> i) & 1u8)))
-                {
-                    acc = acc.double();
-                    acc = $name::conditional_select(&acc, &(acc + self), bit);
-                }
-
-                acc
+                self.to_curve() * other
             }
         }
     };
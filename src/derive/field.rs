@@ -14,10 +14,13 @@ macro_rules! field_common {
         $r3:ident
     ) => {
         impl $field {
+            /// Number of 64-bit limbs in the representation.
+            pub(crate) const NUM_LIMBS: usize = $modulus.0.len();
+
             /// Returns zero, the additive identity.
             #[inline]
             pub const fn zero() -> $field {
-                $field([0, 0, 0, 0])
+                $field([0; Self::NUM_LIMBS])
             }
 
             /// Returns one, the multiplicative identity.
@@ -26,92 +29,73 @@ macro_rules! field_common {
                 $r
             }
 
-            fn from_u512(limbs: [u64; 8]) -> $field {
-                // We reduce an arbitrary 512-bit number by decomposing it into two 256-bit digits
-                // with the higher bits multiplied by 2^256. Thus, we perform two reductions
+            /// Reduces an arbitrary little-endian integer modulo the field modulus.
+            fn from_wide(limbs: &[u64]) -> $field {
+                // We reduce the number by decomposing it into digits of `NUM_LIMBS`
+                // limbs, the `i`-th of which has to be multiplied by `2^(64 * NUM_LIMBS * i)`,
+                // that is by `R^i`. Thus, we multiply digit `i` by `R^(i + 2)` in a
+                // Montgomery multiplication to obtain its contribution in Montgomery form
                 //
-                // 1. the lower bits are multiplied by R^2, as normal
-                // 2. the upper bits are multiplied by R^2 * 2^256 = R^3
+                // 1. the lowest digit is multiplied by R^2, as normal
+                // 2. the next one is multiplied by R^2 * R = R^3
                 //
-                // and computing their sum in the field. It remains to see that arbitrary 256-bit
-                // numbers can be placed into Montgomery form safely using the reduction. The
-                // reduction works so long as the product is less than R=2^256 multiplied by
-                // the modulus. This holds because for any `c` smaller than the modulus, we have
-                // that (2^256 - 1)*c is an acceptable product for the reduction. Therefore, the
-                // reduction always works so long as `c` is in the field; in this case it is either the
-                // constant `R2` or `R3`.
-                let d0 = $field([limbs[0], limbs[1], limbs[2], limbs[3]]);
-                let d1 = $field([limbs[4], limbs[5], limbs[6], limbs[7]]);
-                // Convert to Montgomery form
-                d0 * $r2 + d1 * $r3
+                // and so on. The reduction works so long as the product is less than R
+                // multiplied by the modulus, which holds since any digit is below R and
+                // the constants `R^(i + 2)` are kept in the field.
+                let mut res = Self::zero();
+                let mut r = $r2;
+                for digit in limbs.chunks(Self::NUM_LIMBS) {
+                    let mut d = [0; Self::NUM_LIMBS];
+                    d[..digit.len()].copy_from_slice(digit);
+                    res += $field(d) * r;
+                    r *= $r2;
+                }
+                res
             }
 
             /// Converts from an integer represented in little endian
             /// into its (congruent) `$field` representation.
-            pub const fn from_raw(val: [u64; 4]) -> Self {
+            pub const fn from_raw(val: [u64; Self::NUM_LIMBS]) -> Self {
                 #[cfg(feature = "asm")]
                 {
-                    let (r0, carry) = mac(0, val[0], $r2.0[0], 0);
-                    let (r1, carry) = mac(0, val[0], $r2.0[1], carry);
-                    let (r2, carry) = mac(0, val[0], $r2.0[2], carry);
-                    let (r3, r4) = mac(0, val[0], $r2.0[3], carry);
-
-                    let (r1, carry) = mac(r1, val[1], $r2.0[0], 0);
-                    let (r2, carry) = mac(r2, val[1], $r2.0[1], carry);
-                    let (r3, carry) = mac(r3, val[1], $r2.0[2], carry);
-                    let (r4, r5) = mac(r4, val[1], $r2.0[3], carry);
-
-                    let (r2, carry) = mac(r2, val[2], $r2.0[0], 0);
-                    let (r3, carry) = mac(r3, val[2], $r2.0[1], carry);
-                    let (r4, carry) = mac(r4, val[2], $r2.0[2], carry);
-                    let (r5, r6) = mac(r5, val[2], $r2.0[3], carry);
-
-                    let (r3, carry) = mac(r3, val[3], $r2.0[0], 0);
-                    let (r4, carry) = mac(r4, val[3], $r2.0[1], carry);
-                    let (r5, carry) = mac(r5, val[3], $r2.0[2], carry);
-                    let (r6, r7) = mac(r6, val[3], $r2.0[3], carry);
+                    // Schoolbook multiplication by R^2
+                    let mut r = [0; 2 * Self::NUM_LIMBS];
+                    let mut i = 0;
+                    while i < Self::NUM_LIMBS {
+                        let mut carry = 0;
+                        let mut j = 0;
+                        while j < Self::NUM_LIMBS {
+                            (r[i + j], carry) = mac(r[i + j], val[i], $r2.0[j], carry);
+                            j += 1;
+                        }
+                        r[i + Self::NUM_LIMBS] = carry;
+                        i += 1;
+                    }
 
                     // Montgomery reduction (first part)
-                    let k = r0.wrapping_mul($inv);
-                    let (_, carry) = mac(r0, k, $modulus.0[0], 0);
-                    let (r1, carry) = mac(r1, k, $modulus.0[1], carry);
-                    let (r2, carry) = mac(r2, k, $modulus.0[2], carry);
-                    let (r3, carry) = mac(r3, k, $modulus.0[3], carry);
-                    let (r4, carry2) = adc(r4, 0, carry);
-
-                    let k = r1.wrapping_mul($inv);
-                    let (_, carry) = mac(r1, k, $modulus.0[0], 0);
-                    let (r2, carry) = mac(r2, k, $modulus.0[1], carry);
-                    let (r3, carry) = mac(r3, k, $modulus.0[2], carry);
-                    let (r4, carry) = mac(r4, k, $modulus.0[3], carry);
-                    let (r5, carry2) = adc(r5, carry2, carry);
-
-                    let k = r2.wrapping_mul($inv);
-                    let (_, carry) = mac(r2, k, $modulus.0[0], 0);
-                    let (r3, carry) = mac(r3, k, $modulus.0[1], carry);
-                    let (r4, carry) = mac(r4, k, $modulus.0[2], carry);
-                    let (r5, carry) = mac(r5, k, $modulus.0[3], carry);
-                    let (r6, carry2) = adc(r6, carry2, carry);
-
-                    let k = r3.wrapping_mul($inv);
-                    let (_, carry) = mac(r3, k, $modulus.0[0], 0);
-                    let (r4, carry) = mac(r4, k, $modulus.0[1], carry);
-                    let (r5, carry) = mac(r5, k, $modulus.0[2], carry);
-                    let (r6, carry) = mac(r6, k, $modulus.0[3], carry);
-                    let (r7, _) = adc(r7, carry2, carry);
+                    let mut carry2 = 0;
+                    let mut i = 0;
+                    while i < Self::NUM_LIMBS {
+                        let k = r[i].wrapping_mul($inv);
+                        let (_, mut carry) = mac(r[i], k, $modulus.0[0], 0);
+                        let mut j = 1;
+                        while j < Self::NUM_LIMBS {
+                            (r[i + j], carry) = mac(r[i + j], k, $modulus.0[j], carry);
+                            j += 1;
+                        }
+                        (r[i + Self::NUM_LIMBS], carry2) =
+                            adc(r[i + Self::NUM_LIMBS], carry2, carry);
+                        i += 1;
+                    }
 
                     // Montgomery reduction (sub part)
-                    let (d0, borrow) = sbb(r4, $modulus.0[0], 0);
-                    let (d1, borrow) = sbb(r5, $modulus.0[1], borrow);
-                    let (d2, borrow) = sbb(r6, $modulus.0[2], borrow);
-                    let (d3, borrow) = sbb(r7, $modulus.0[3], borrow);
-
-                    let (d0, carry) = adc(d0, $modulus.0[0] & borrow, 0);
-                    let (d1, carry) = adc(d1, $modulus.0[1] & borrow, carry);
-                    let (d2, carry) = adc(d2, $modulus.0[2] & borrow, carry);
-                    let (d3, _) = adc(d3, $modulus.0[3] & borrow, carry);
-
-                    $field([d0, d1, d2, d3])
+                    let mut d = [0; Self::NUM_LIMBS];
+                    let mut i = 0;
+                    while i < Self::NUM_LIMBS {
+                        d[i] = r[i + Self::NUM_LIMBS];
+                        i += 1;
+                    }
+                    Self::subtract_modulus(d, carry2)
                 }
                 #[cfg(not(feature = "asm"))]
                 {
@@ -119,25 +103,66 @@ macro_rules! field_common {
                 }
             }
 
+            /// Subtracts the modulus from the value `carry * 2^(64 * NUM_LIMBS) + d`,
+            /// which must be less than twice the modulus, unless that underflows.
+            #[inline(always)]
+            const fn subtract_modulus(mut d: [u64; $field::NUM_LIMBS], carry: u64) -> Self {
+                let mut borrow = 0;
+                let mut i = 0;
+                while i < $field::NUM_LIMBS {
+                    (d[i], borrow) = sbb(d[i], $modulus.0[i], borrow);
+                    i += 1;
+                }
+                let (_, borrow) = sbb(carry, 0, borrow);
+
+                let mut carry = 0;
+                let mut i = 0;
+                while i < $field::NUM_LIMBS {
+                    (d[i], carry) = adc(d[i], $modulus.0[i] & borrow, carry);
+                    i += 1;
+                }
+
+                $field(d)
+            }
+
             /// Attempts to convert a little-endian byte representation of
             /// a scalar into a `Fr`, failing if the input is not canonical.
-            pub fn from_bytes(bytes: &[u8; 32]) -> CtOption<$field> {
-                <Self as ff::PrimeField>::from_repr(*bytes)
+            pub fn from_bytes(bytes: &[u8; Self::NUM_LIMBS * 8]) -> CtOption<$field> {
+                let mut repr = <Self as ff::PrimeField>::Repr::default();
+                repr.as_mut().copy_from_slice(bytes);
+                <Self as ff::PrimeField>::from_repr(repr)
             }
 
             /// Converts an element of `Fr` into a byte representation in
             /// little-endian byte order.
-            pub fn to_bytes(&self) -> [u8; 32] {
-                <Self as ff::PrimeField>::to_repr(self)
+            pub fn to_bytes(&self) -> [u8; Self::NUM_LIMBS * 8] {
+                let mut res = [0; Self::NUM_LIMBS * 8];
+                res.copy_from_slice(<Self as ff::PrimeField>::to_repr(self).as_ref());
+                res
+            }
+
+            /// Computes the multiplicative inverse of this element in constant
+            /// time with the Bernstein-Yang safegcd algorithm, failing if the
+            /// element is zero.
+            fn invert_safegcd(&self) -> CtOption<Self> {
+                const MODULUS_62: $crate::safegcd::Modulus62<{ $field::NUM_LIMBS + 1 }> =
+                    $crate::safegcd::Modulus62::new(&$modulus.0);
+
+                // The Montgomery form aR inverts to a^-1 R^-1, which a Montgomery
+                // multiplication by R^3 brings back to a^-1 R.
+                let tmp = $field($crate::safegcd::invert(&self.0, &MODULUS_62)) * $r3;
+                CtOption::new(tmp, !self.ct_eq(&Self::zero()))
             }
 
             /// Lexicographic comparison of Montgomery forms.
             #[inline(always)]
-            const fn is_less_than(x: &[u64; 4], y: &[u64; 4]) -> bool {
-                let (_, borrow) = sbb(x[0], y[0], 0);
-                let (_, borrow) = sbb(x[1], y[1], borrow);
-                let (_, borrow) = sbb(x[2], y[2], borrow);
-                let (_, borrow) = sbb(x[3], y[3], borrow);
+            const fn is_less_than(x: &[u64; Self::NUM_LIMBS], y: &[u64; Self::NUM_LIMBS]) -> bool {
+                let mut borrow = 0;
+                let mut i = 0;
+                while i < Self::NUM_LIMBS {
+                    (_, borrow) = sbb(x[i], y[i], borrow);
+                    i += 1;
+                }
                 borrow >> 63 == 1
             }
         }
@@ -146,7 +171,7 @@ macro_rules! field_common {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
                 let tmp = self.to_repr();
                 write!(f, "0x")?;
-                for &b in tmp.iter().rev() {
+                for &b in tmp.as_ref().iter().rev() {
                     write!(f, "{:02x}", b)?;
                 }
                 Ok(())
@@ -172,16 +197,18 @@ macro_rules! field_common {
 
         impl From<u64> for $field {
             fn from(val: u64) -> $field {
-                $field([val, 0, 0, 0]) * $r2
+                let mut limbs = [0; $field::NUM_LIMBS];
+                limbs[0] = val;
+                $field(limbs) * $r2
             }
         }
 
         impl ConstantTimeEq for $field {
             fn ct_eq(&self, other: &Self) -> Choice {
-                self.0[0].ct_eq(&other.0[0])
-                    & self.0[1].ct_eq(&other.0[1])
-                    & self.0[2].ct_eq(&other.0[2])
-                    & self.0[3].ct_eq(&other.0[3])
+                self.0
+                    .iter()
+                    .zip(other.0.iter())
+                    .fold(Choice::from(1), |acc, (a, b)| acc & a.ct_eq(b))
             }
         }
 
@@ -189,8 +216,9 @@ macro_rules! field_common {
             fn cmp(&self, other: &Self) -> core::cmp::Ordering {
                 let left = self.to_repr();
                 let right = other.to_repr();
-                left.iter()
-                    .zip(right.iter())
+                left.as_ref()
+                    .iter()
+                    .zip(right.as_ref().iter())
                     .rev()
                     .find_map(|(left_byte, right_byte)| match left_byte.cmp(right_byte) {
                         core::cmp::Ordering::Equal => None,
@@ -208,12 +236,11 @@ macro_rules! field_common {
 
         impl ConditionallySelectable for $field {
             fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
-                $field([
-                    u64::conditional_select(&a.0[0], &b.0[0], choice),
-                    u64::conditional_select(&a.0[1], &b.0[1], choice),
-                    u64::conditional_select(&a.0[2], &b.0[2], choice),
-                    u64::conditional_select(&a.0[3], &b.0[3], choice),
-                ])
+                let mut limbs = [0; $field::NUM_LIMBS];
+                for (i, limb) in limbs.iter_mut().enumerate() {
+                    *limb = u64::conditional_select(&a.0[i], &b.0[i], choice);
+                }
+                $field(limbs)
             }
         }
 
@@ -262,41 +289,43 @@ macro_rules! field_common {
             }
         }
 
-        impl From<$field> for [u8; 32] {
-            fn from(value: $field) -> [u8; 32] {
-                value.to_repr()
+        impl From<$field> for [u8; $field::NUM_LIMBS * 8] {
+            fn from(value: $field) -> [u8; $field::NUM_LIMBS * 8] {
+                value.to_bytes()
             }
         }
 
-        impl<'a> From<&'a $field> for [u8; 32] {
-            fn from(value: &'a $field) -> [u8; 32] {
-                value.to_repr()
+        impl<'a> From<&'a $field> for [u8; $field::NUM_LIMBS * 8] {
+            fn from(value: &'a $field) -> [u8; $field::NUM_LIMBS * 8] {
+                value.to_bytes()
             }
         }
 
         impl $crate::serde::SerdeObject for $field {
             fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
-                debug_assert_eq!(bytes.len(), 32);
-                let inner =
-                    [0, 8, 16, 24].map(|i| u64::from_le_bytes(bytes[i..i + 8].try_into().unwrap()));
+                debug_assert_eq!(bytes.len(), $field::NUM_LIMBS * 8);
+                let mut inner = [0; $field::NUM_LIMBS];
+                for (limb, chunk) in inner.iter_mut().zip(bytes.chunks_exact(8)) {
+                    *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+                }
                 Self(inner)
             }
             fn from_raw_bytes(bytes: &[u8]) -> Option<Self> {
-                if bytes.len() != 32 {
+                if bytes.len() != $field::NUM_LIMBS * 8 {
                     return None;
                 }
                 let elt = Self::from_raw_bytes_unchecked(bytes);
                 Self::is_less_than(&elt.0, &$modulus.0).then(|| elt)
             }
             fn to_raw_bytes(&self) -> Vec<u8> {
-                let mut res = Vec::with_capacity(32);
+                let mut res = Vec::with_capacity($field::NUM_LIMBS * 8);
                 for limb in self.0.iter() {
                     res.extend_from_slice(&limb.to_le_bytes());
                 }
                 res
             }
             fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
-                let inner = [(); 4].map(|_| {
+                let inner = [(); $field::NUM_LIMBS].map(|_| {
                     let mut buf = [0; 8];
                     reader.read_exact(&mut buf).unwrap();
                     u64::from_le_bytes(buf)
@@ -304,7 +333,7 @@ macro_rules! field_common {
                 Self(inner)
             }
             fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-                let mut inner = [0u64; 4];
+                let mut inner = [0u64; $field::NUM_LIMBS];
                 for limb in inner.iter_mut() {
                     let mut buf = [0; 8];
                     reader.read_exact(&mut buf)?;
@@ -344,33 +373,37 @@ macro_rules! field_arithmetic {
             /// Squares this element.
             #[inline]
             pub const fn square(&self) -> $field {
-                let (r1, carry) = mac(0, self.0[0], self.0[1], 0);
-                let (r2, carry) = mac(0, self.0[0], self.0[2], carry);
-                let (r3, r4) = mac(0, self.0[0], self.0[3], carry);
-
-                let (r3, carry) = mac(r3, self.0[1], self.0[2], 0);
-                let (r4, r5) = mac(r4, self.0[1], self.0[3], carry);
-
-                let (r5, r6) = mac(r5, self.0[2], self.0[3], 0);
+                let mut r = [0; 2 * $field::NUM_LIMBS];
+
+                // Products of distinct limbs, which appear twice in the square
+                let mut i = 0;
+                while i < $field::NUM_LIMBS - 1 {
+                    let mut carry = 0;
+                    let mut j = i + 1;
+                    while j < $field::NUM_LIMBS {
+                        (r[i + j], carry) = mac(r[i + j], self.0[i], self.0[j], carry);
+                        j += 1;
+                    }
+                    r[i + $field::NUM_LIMBS] = carry;
+                    i += 1;
+                }
 
-                let r7 = r6 >> 63;
-                let r6 = (r6 << 1) | (r5 >> 63);
-                let r5 = (r5 << 1) | (r4 >> 63);
-                let r4 = (r4 << 1) | (r3 >> 63);
-                let r3 = (r3 << 1) | (r2 >> 63);
-                let r2 = (r2 << 1) | (r1 >> 63);
-                let r1 = r1 << 1;
+                let mut i = 2 * $field::NUM_LIMBS - 1;
+                while i > 0 {
+                    r[i] = (r[i] << 1) | (r[i - 1] >> 63);
+                    i -= 1;
+                }
 
-                let (r0, carry) = mac(0, self.0[0], self.0[0], 0);
-                let (r1, carry) = adc(0, r1, carry);
-                let (r2, carry) = mac(r2, self.0[1], self.0[1], carry);
-                let (r3, carry) = adc(0, r3, carry);
-                let (r4, carry) = mac(r4, self.0[2], self.0[2], carry);
-                let (r5, carry) = adc(0, r5, carry);
-                let (r6, carry) = mac(r6, self.0[3], self.0[3], carry);
-                let (r7, _) = adc(0, r7, carry);
+                // Squares of the limbs
+                let mut carry = 0;
+                let mut i = 0;
+                while i < $field::NUM_LIMBS {
+                    (r[2 * i], carry) = mac(r[2 * i], self.0[i], self.0[i], carry);
+                    (r[2 * i + 1], carry) = adc(0, r[2 * i + 1], carry);
+                    i += 1;
+                }
 
-                $field::montgomery_reduce(&[r0, r1, r2, r3, r4, r5, r6, r7])
+                $field::montgomery_reduce(&r)
             }
 
             /// Multiplies `rhs` by `self`, returning the result.
@@ -378,45 +411,43 @@ macro_rules! field_arithmetic {
             pub const fn mul(&self, rhs: &Self) -> $field {
                 // Schoolbook multiplication
 
-                let (r0, carry) = mac(0, self.0[0], rhs.0[0], 0);
-                let (r1, carry) = mac(0, self.0[0], rhs.0[1], carry);
-                let (r2, carry) = mac(0, self.0[0], rhs.0[2], carry);
-                let (r3, r4) = mac(0, self.0[0], rhs.0[3], carry);
-
-                let (r1, carry) = mac(r1, self.0[1], rhs.0[0], 0);
-                let (r2, carry) = mac(r2, self.0[1], rhs.0[1], carry);
-                let (r3, carry) = mac(r3, self.0[1], rhs.0[2], carry);
-                let (r4, r5) = mac(r4, self.0[1], rhs.0[3], carry);
-
-                let (r2, carry) = mac(r2, self.0[2], rhs.0[0], 0);
-                let (r3, carry) = mac(r3, self.0[2], rhs.0[1], carry);
-                let (r4, carry) = mac(r4, self.0[2], rhs.0[2], carry);
-                let (r5, r6) = mac(r5, self.0[2], rhs.0[3], carry);
-
-                let (r3, carry) = mac(r3, self.0[3], rhs.0[0], 0);
-                let (r4, carry) = mac(r4, self.0[3], rhs.0[1], carry);
-                let (r5, carry) = mac(r5, self.0[3], rhs.0[2], carry);
-                let (r6, r7) = mac(r6, self.0[3], rhs.0[3], carry);
+                let mut r = [0; 2 * $field::NUM_LIMBS];
+                let mut i = 0;
+                while i < $field::NUM_LIMBS {
+                    let mut carry = 0;
+                    let mut j = 0;
+                    while j < $field::NUM_LIMBS {
+                        (r[i + j], carry) = mac(r[i + j], self.0[i], rhs.0[j], carry);
+                        j += 1;
+                    }
+                    r[i + $field::NUM_LIMBS] = carry;
+                    i += 1;
+                }
 
-                $field::montgomery_reduce(&[r0, r1, r2, r3, r4, r5, r6, r7])
+                $field::montgomery_reduce(&r)
             }
 
             /// Subtracts `rhs` from `self`, returning the result.
             #[inline]
             pub const fn sub(&self, rhs: &Self) -> Self {
-                let (d0, borrow) = sbb(self.0[0], rhs.0[0], 0);
-                let (d1, borrow) = sbb(self.0[1], rhs.0[1], borrow);
-                let (d2, borrow) = sbb(self.0[2], rhs.0[2], borrow);
-                let (d3, borrow) = sbb(self.0[3], rhs.0[3], borrow);
+                let mut d = [0; $field::NUM_LIMBS];
+                let mut borrow = 0;
+                let mut i = 0;
+                while i < $field::NUM_LIMBS {
+                    (d[i], borrow) = sbb(self.0[i], rhs.0[i], borrow);
+                    i += 1;
+                }
 
                 // If underflow occurred on the final limb, borrow = 0xfff...fff, otherwise
                 // borrow = 0x000...000. Thus, we use it as a mask to conditionally add the modulus.
-                let (d0, carry) = adc(d0, $modulus.0[0] & borrow, 0);
-                let (d1, carry) = adc(d1, $modulus.0[1] & borrow, carry);
-                let (d2, carry) = adc(d2, $modulus.0[2] & borrow, carry);
-                let (d3, _) = adc(d3, $modulus.0[3] & borrow, carry);
+                let mut carry = 0;
+                let mut i = 0;
+                while i < $field::NUM_LIMBS {
+                    (d[i], carry) = adc(d[i], $modulus.0[i] & borrow, carry);
+                    i += 1;
+                }
 
-                $field([d0, d1, d2, d3])
+                $field(d)
             }
 
             /// Negates `self`.
@@ -425,17 +456,27 @@ macro_rules! field_arithmetic {
                 // Subtract `self` from `MODULUS` to negate. Ignore the final
                 // borrow because it cannot underflow; self is guaranteed to
                 // be in the field.
-                let (d0, borrow) = sbb($modulus.0[0], self.0[0], 0);
-                let (d1, borrow) = sbb($modulus.0[1], self.0[1], borrow);
-                let (d2, borrow) = sbb($modulus.0[2], self.0[2], borrow);
-                let (d3, _) = sbb($modulus.0[3], self.0[3], borrow);
+                let mut d = [0; $field::NUM_LIMBS];
+                let mut borrow = 0;
+                let mut acc = 0;
+                let mut i = 0;
+                while i < $field::NUM_LIMBS {
+                    (d[i], borrow) = sbb($modulus.0[i], self.0[i], borrow);
+                    acc |= self.0[i];
+                    i += 1;
+                }
 
                 // `tmp` could be `MODULUS` if `self` was zero. Create a mask that is
                 // zero if `self` was zero, and `u64::max_value()` if self was nonzero.
-                let mask =
-                    (((self.0[0] | self.0[1] | self.0[2] | self.0[3]) == 0) as u64).wrapping_sub(1);
+                let mask = ((acc == 0) as u64).wrapping_sub(1);
 
-                $field([d0 & mask, d1 & mask, d2 & mask, d3 & mask])
+                let mut i = 0;
+                while i < $field::NUM_LIMBS {
+                    d[i] &= mask;
+                    i += 1;
+                }
+
+                $field(d)
             }
         }
     };
@@ -448,52 +489,49 @@ macro_rules! field_specific {
             /// Adds `rhs` to `self`, returning the result.
             #[inline]
             pub const fn add(&self, rhs: &Self) -> Self {
-                let (d0, carry) = adc(self.0[0], rhs.0[0], 0);
-                let (d1, carry) = adc(self.0[1], rhs.0[1], carry);
-                let (d2, carry) = adc(self.0[2], rhs.0[2], carry);
-                let (d3, _) = adc(self.0[3], rhs.0[3], carry);
+                let mut d = [0; $field::NUM_LIMBS];
+                let mut carry = 0;
+                let mut i = 0;
+                while i < $field::NUM_LIMBS {
+                    (d[i], carry) = adc(self.0[i], rhs.0[i], carry);
+                    i += 1;
+                }
 
                 // Attempt to subtract the modulus, to ensure the value
                 // is smaller than the modulus.
-                (&$field([d0, d1, d2, d3])).sub(&$modulus)
+                (&$field(d)).sub(&$modulus)
             }
 
             #[inline(always)]
-            pub(crate) const fn montgomery_reduce(r: &[u64; 8]) -> $field {
+            pub(crate) const fn montgomery_reduce(r: &[u64; 2 * $field::NUM_LIMBS]) -> $field {
                 // The Montgomery reduction here is based on Algorithm 14.32 in
                 // Handbook of Applied Cryptography
                 // <http://cacr.uwaterloo.ca/hac/about/chap14.pdf>.
 
-                let k = r[0].wrapping_mul($inv);
-                let (_, carry) = mac(r[0], k, $modulus.0[0], 0);
-                let (r1, carry) = mac(r[1], k, $modulus.0[1], carry);
-                let (r2, carry) = mac(r[2], k, $modulus.0[2], carry);
-                let (r3, carry) = mac(r[3], k, $modulus.0[3], carry);
-                let (r4, carry2) = adc(r[4], 0, carry);
-
-                let k = r1.wrapping_mul($inv);
-                let (_, carry) = mac(r1, k, $modulus.0[0], 0);
-                let (r2, carry) = mac(r2, k, $modulus.0[1], carry);
-                let (r3, carry) = mac(r3, k, $modulus.0[2], carry);
-                let (r4, carry) = mac(r4, k, $modulus.0[3], carry);
-                let (r5, carry2) = adc(r[5], carry2, carry);
-
-                let k = r2.wrapping_mul($inv);
-                let (_, carry) = mac(r2, k, $modulus.0[0], 0);
-                let (r3, carry) = mac(r3, k, $modulus.0[1], carry);
-                let (r4, carry) = mac(r4, k, $modulus.0[2], carry);
-                let (r5, carry) = mac(r5, k, $modulus.0[3], carry);
-                let (r6, carry2) = adc(r[6], carry2, carry);
-
-                let k = r3.wrapping_mul($inv);
-                let (_, carry) = mac(r3, k, $modulus.0[0], 0);
-                let (r4, carry) = mac(r4, k, $modulus.0[1], carry);
-                let (r5, carry) = mac(r5, k, $modulus.0[2], carry);
-                let (r6, carry) = mac(r6, k, $modulus.0[3], carry);
-                let (r7, _) = adc(r[7], carry2, carry);
+                let mut r = *r;
+                let mut carry2 = 0;
+                let mut i = 0;
+                while i < $field::NUM_LIMBS {
+                    let k = r[i].wrapping_mul($inv);
+                    let (_, mut carry) = mac(r[i], k, $modulus.0[0], 0);
+                    let mut j = 1;
+                    while j < $field::NUM_LIMBS {
+                        (r[i + j], carry) = mac(r[i + j], k, $modulus.0[j], carry);
+                        j += 1;
+                    }
+                    (r[i + $field::NUM_LIMBS], carry2) =
+                        adc(r[i + $field::NUM_LIMBS], carry2, carry);
+                    i += 1;
+                }
 
                 // Result may be within MODULUS of the correct value
-                (&$field([r4, r5, r6, r7])).sub(&$modulus)
+                let mut d = [0; $field::NUM_LIMBS];
+                let mut i = 0;
+                while i < $field::NUM_LIMBS {
+                    d[i] = r[i + $field::NUM_LIMBS];
+                    i += 1;
+                }
+                $field::subtract_modulus(d, carry2)
             }
         }
     };
@@ -502,74 +540,49 @@ macro_rules! field_specific {
             /// Adds `rhs` to `self`, returning the result.
             #[inline]
             pub const fn add(&self, rhs: &Self) -> Self {
-                let (d0, carry) = adc(self.0[0], rhs.0[0], 0);
-                let (d1, carry) = adc(self.0[1], rhs.0[1], carry);
-                let (d2, carry) = adc(self.0[2], rhs.0[2], carry);
-                let (d3, carry) = adc(self.0[3], rhs.0[3], carry);
+                let mut d = [0; $field::NUM_LIMBS];
+                let mut carry = 0;
+                let mut i = 0;
+                while i < $field::NUM_LIMBS {
+                    (d[i], carry) = adc(self.0[i], rhs.0[i], carry);
+                    i += 1;
+                }
 
                 // Attempt to subtract the modulus, to ensure the value
                 // is smaller than the modulus.
-                let (d0, borrow) = sbb(d0, $modulus.0[0], 0);
-                let (d1, borrow) = sbb(d1, $modulus.0[1], borrow);
-                let (d2, borrow) = sbb(d2, $modulus.0[2], borrow);
-                let (d3, borrow) = sbb(d3, $modulus.0[3], borrow);
-                let (_, borrow) = sbb(carry, 0, borrow);
-
-                let (d0, carry) = adc(d0, $modulus.0[0] & borrow, 0);
-                let (d1, carry) = adc(d1, $modulus.0[1] & borrow, carry);
-                let (d2, carry) = adc(d2, $modulus.0[2] & borrow, carry);
-                let (d3, _) = adc(d3, $modulus.0[3] & borrow, carry);
-
-                $field([d0, d1, d2, d3])
+                $field::subtract_modulus(d, carry)
             }
 
             #[inline(always)]
-            pub(crate) const fn montgomery_reduce(r: &[u64; 8]) -> Self {
+            pub(crate) const fn montgomery_reduce(r: &[u64; 2 * $field::NUM_LIMBS]) -> Self {
                 // The Montgomery reduction here is based on Algorithm 14.32 in
                 // Handbook of Applied Cryptography
                 // <http://cacr.uwaterloo.ca/hac/about/chap14.pdf>.
 
-                let k = r[0].wrapping_mul($inv);
-                let (_, carry) = mac(r[0], k, $modulus.0[0], 0);
-                let (r1, carry) = mac(r[1], k, $modulus.0[1], carry);
-                let (r2, carry) = mac(r[2], k, $modulus.0[2], carry);
-                let (r3, carry) = mac(r[3], k, $modulus.0[3], carry);
-                let (r4, carry2) = adc(r[4], 0, carry);
-
-                let k = r1.wrapping_mul($inv);
-                let (_, carry) = mac(r1, k, $modulus.0[0], 0);
-                let (r2, carry) = mac(r2, k, $modulus.0[1], carry);
-                let (r3, carry) = mac(r3, k, $modulus.0[2], carry);
-                let (r4, carry) = mac(r4, k, $modulus.0[3], carry);
-                let (r5, carry2) = adc(r[5], carry2, carry);
-
-                let k = r2.wrapping_mul($inv);
-                let (_, carry) = mac(r2, k, $modulus.0[0], 0);
-                let (r3, carry) = mac(r3, k, $modulus.0[1], carry);
-                let (r4, carry) = mac(r4, k, $modulus.0[2], carry);
-                let (r5, carry) = mac(r5, k, $modulus.0[3], carry);
-                let (r6, carry2) = adc(r[6], carry2, carry);
-
-                let k = r3.wrapping_mul($inv);
-                let (_, carry) = mac(r3, k, $modulus.0[0], 0);
-                let (r4, carry) = mac(r4, k, $modulus.0[1], carry);
-                let (r5, carry) = mac(r5, k, $modulus.0[2], carry);
-                let (r6, carry) = mac(r6, k, $modulus.0[3], carry);
-                let (r7, carry2) = adc(r[7], carry2, carry);
+                let mut r = *r;
+                let mut carry2 = 0;
+                let mut i = 0;
+                while i < $field::NUM_LIMBS {
+                    let k = r[i].wrapping_mul($inv);
+                    let (_, mut carry) = mac(r[i], k, $modulus.0[0], 0);
+                    let mut j = 1;
+                    while j < $field::NUM_LIMBS {
+                        (r[i + j], carry) = mac(r[i + j], k, $modulus.0[j], carry);
+                        j += 1;
+                    }
+                    (r[i + $field::NUM_LIMBS], carry2) =
+                        adc(r[i + $field::NUM_LIMBS], carry2, carry);
+                    i += 1;
+                }
 
                 // Result may be within MODULUS of the correct value
-                let (d0, borrow) = sbb(r4, $modulus.0[0], 0);
-                let (d1, borrow) = sbb(r5, $modulus.0[1], borrow);
-                let (d2, borrow) = sbb(r6, $modulus.0[2], borrow);
-                let (d3, borrow) = sbb(r7, $modulus.0[3], borrow);
-                let (_, borrow) = sbb(carry2, 0, borrow);
-
-                let (d0, carry) = adc(d0, $modulus.0[0] & borrow, 0);
-                let (d1, carry) = adc(d1, $modulus.0[1] & borrow, carry);
-                let (d2, carry) = adc(d2, $modulus.0[2] & borrow, carry);
-                let (d3, _) = adc(d3, $modulus.0[3] & borrow, carry);
-
-                $field([d0, d1, d2, d3])
+                let mut d = [0; $field::NUM_LIMBS];
+                let mut i = 0;
+                while i < $field::NUM_LIMBS {
+                    d[i] = r[i + $field::NUM_LIMBS];
+                    i += 1;
+                }
+                $field::subtract_modulus(d, carry2)
             }
         }
     };
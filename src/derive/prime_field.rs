@@ -0,0 +1,684 @@
+//! Compile time arithmetic on little-endian multi-limb integers, used by
+//! `define_prime_field!` to derive the constants of a field from its modulus.
+//!
+//! None of these functions are constant time; they are only meant to be
+//! evaluated on public constants, mostly in const contexts.
+
+/// Returns the value of a hexadecimal digit.
+const fn hex_digit(c: u8) -> u64 {
+    match c {
+        b'0'..=b'9' => (c - b'0') as u64,
+        b'a'..=b'f' => (c - b'a' + 10) as u64,
+        b'A'..=b'F' => (c - b'A' + 10) as u64,
+        _ => panic!("invalid hexadecimal digit in modulus"),
+    }
+}
+
+/// Returns the digits of a hexadecimal string with an optional `0x` prefix,
+/// without its leading zeros, as a range of indices into its bytes.
+const fn hex_digits(hex: &str) -> (usize, usize) {
+    let bytes = hex.as_bytes();
+    let mut start = 0;
+    if bytes.len() >= 2 && bytes[0] == b'0' && (bytes[1] == b'x' || bytes[1] == b'X') {
+        start = 2;
+    }
+    while start < bytes.len() && bytes[start] == b'0' {
+        start += 1;
+    }
+    (start, bytes.len())
+}
+
+/// Returns the number of 64-bit limbs needed to hold a hexadecimal integer.
+pub(crate) const fn num_limbs(hex: &str) -> usize {
+    let (start, end) = hex_digits(hex);
+    let digits = end - start;
+    digits / 16 + (digits % 16 != 0) as usize
+}
+
+/// Parses a hexadecimal integer with an optional `0x` prefix into `N`
+/// little-endian limbs.
+pub(crate) const fn from_hex<const N: usize>(hex: &str) -> [u64; N] {
+    let bytes = hex.as_bytes();
+    let (start, end) = hex_digits(hex);
+    assert!(end - start <= 16 * N, "integer does not fit in the limbs");
+
+    let mut res = [0; N];
+    let mut i = 0;
+    while i < end - start {
+        let digit = hex_digit(bytes[end - 1 - i]);
+        res[i / 16] |= digit << (4 * (i % 16));
+        i += 1;
+    }
+    res
+}
+
+/// Returns the bit length of `a`.
+pub(crate) const fn num_bits<const N: usize>(a: &[u64; N]) -> u32 {
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if a[i] != 0 {
+            return 64 * i as u32 + 64 - a[i].leading_zeros();
+        }
+    }
+    0
+}
+
+/// Returns the number of trailing zero bits of a nonzero `a`.
+pub(crate) const fn trailing_zeros<const N: usize>(a: &[u64; N]) -> u32 {
+    let mut i = 0;
+    while a[i] == 0 {
+        i += 1;
+    }
+    64 * i as u32 + a[i].trailing_zeros()
+}
+
+/// Returns `a - b`, which must not underflow.
+pub(crate) const fn sub_u64<const N: usize>(a: &[u64; N], b: u64) -> [u64; N] {
+    let mut res = *a;
+    let mut borrow = b;
+    let mut i = 0;
+    while i < N {
+        let (d, underflow) = res[i].overflowing_sub(borrow);
+        res[i] = d;
+        borrow = underflow as u64;
+        i += 1;
+    }
+    assert!(borrow == 0, "subtraction underflow");
+    res
+}
+
+/// Returns `a + b`, which must not overflow.
+pub(crate) const fn add_u64<const N: usize>(a: &[u64; N], b: u64) -> [u64; N] {
+    let mut res = *a;
+    let mut carry = b;
+    let mut i = 0;
+    while i < N {
+        let (d, overflow) = res[i].overflowing_add(carry);
+        res[i] = d;
+        carry = overflow as u64;
+        i += 1;
+    }
+    assert!(carry == 0, "addition overflow");
+    res
+}
+
+/// Returns `a >> shift`.
+pub(crate) const fn shr<const N: usize>(a: &[u64; N], shift: u32) -> [u64; N] {
+    let (words, bits) = ((shift / 64) as usize, shift % 64);
+    let mut res = [0; N];
+    let mut i = 0;
+    while i + words < N {
+        res[i] = a[i + words] >> bits;
+        if bits > 0 && i + words + 1 < N {
+            res[i] |= a[i + words + 1] << (64 - bits);
+        }
+        i += 1;
+    }
+    res
+}
+
+/// Returns `2^k - 1`.
+pub(crate) const fn ones<const N: usize>(k: u32) -> [u64; N] {
+    assert!(k as usize <= 64 * N, "integer does not fit in the limbs");
+    let mut res = [0; N];
+    let mut i = 0;
+    while i < N {
+        let low = 64 * i as u32;
+        if k >= low + 64 {
+            res[i] = u64::MAX;
+        } else if k > low {
+            res[i] = (1 << (k - low)) - 1;
+        }
+        i += 1;
+    }
+    res
+}
+
+/// Returns the quotient and remainder of the division of `a` by `d`.
+pub(crate) const fn div_rem_u64<const N: usize>(a: &[u64; N], d: u64) -> ([u64; N], u64) {
+    let mut res = [0; N];
+    let mut rem = 0u128;
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        let cur = (rem << 64) | a[i] as u128;
+        res[i] = (cur / d as u128) as u64;
+        rem = cur % d as u128;
+    }
+    (res, rem as u64)
+}
+
+/// Returns `2^k mod m` for an odd `m > 1`, by repeated doubling.
+pub(crate) const fn pow2_mod<const N: usize>(k: usize, m: &[u64; N]) -> [u64; N] {
+    let mut res = [0u64; N];
+    res[0] = 1;
+    let mut i = 0;
+    while i < k {
+        // Double, and subtract the modulus if the result is not below it.
+        let mut carry = 0;
+        let mut j = 0;
+        while j < N {
+            let limb = res[j];
+            res[j] = (limb << 1) | carry;
+            carry = limb >> 63;
+            j += 1;
+        }
+
+        let mut diff = [0; N];
+        let mut borrow = 0;
+        let mut j = 0;
+        while j < N {
+            let (d, b1) = res[j].overflowing_sub(m[j]);
+            let (d, b2) = d.overflowing_sub(borrow);
+            diff[j] = d;
+            borrow = (b1 | b2) as u64;
+            j += 1;
+        }
+        if carry == 1 || borrow == 0 {
+            res = diff;
+        }
+        i += 1;
+    }
+    res
+}
+
+/// Defines a prime field from its hexadecimal modulus and a small generator
+/// of its multiplicative group, which is in particular a quadratic non-residue:
+///
+/// ```ignore
+/// define_prime_field!(Fp, "0xffffffff00000001", generator = 7);
+/// ```
+///
+/// Attributes, such as doc comments, may precede the name of the field and
+/// are applied to its type.
+///
+/// All the Montgomery and `PrimeField` constants are derived from the modulus
+/// at compile time. The field and its `Repr`, `FpBytes` in the example above,
+/// are defined in a private module and re-exported, so that their constants do
+/// not clash with the ones of other fields in the same module. This module
+/// also contains tests checking the derived constants; of the generator, they
+/// only check that it is a quadratic non-residue.
+macro_rules! define_prime_field {
+    (
+        $(#[$attr:meta])*
+        $field:ident, $modulus:literal, generator = $generator:literal
+    ) => {
+        paste::paste! {
+            pub use self::[<$field:snake _impl>]::{$field, [<$field Bytes>]};
+
+            // `field_common!` defines `to_bytes(&self)` like for every other field.
+            #[allow(clippy::wrong_self_convention)]
+            mod [<$field:snake _impl>] {
+                use $crate::arithmetic::{adc, mac, sbb};
+                use $crate::derive::prime_field as bigint;
+                use $crate::ff::{Field, FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
+                use $crate::hash_to_curve::{hash_to_field, HashToField};
+                // The field macros may already be in textual scope, depending
+                // on where this is invoked.
+                #[allow(unused_imports)]
+                use $crate::{
+                    field_arithmetic, field_common, field_specific,
+                    impl_add_binop_specify_output, impl_binops_additive,
+                    impl_binops_additive_specify_output, impl_binops_multiplicative,
+                    impl_binops_multiplicative_mixed, impl_sub_binop_specify_output,
+                    impl_sum_prod,
+                };
+                use core::convert::TryInto;
+                use core::fmt;
+                use core::ops::{Add, Mul, Neg, Sub};
+                use rand::RngCore;
+                use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+                #[doc = concat!("An element of the prime field of modulus `", $modulus, "`.")]
+                $(#[$attr])*
+                // Values are always in Montgomery form; i.e., a is stored as
+                // aR mod p, with R = 2^(64 * NUM_LIMBS).
+                #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+                #[cfg_attr(
+                    feature = "derive_serde",
+                    derive(::serde::Serialize, ::serde::Deserialize)
+                )]
+                pub struct $field(pub(crate) [u64; bigint::num_limbs($modulus)]);
+
+                const MODULUS: $field = $field(bigint::from_hex($modulus));
+
+                const MODULUS_STR: &str = $modulus;
+
+                /// INV = -(p^{-1} mod 2^64) mod 2^64
+                const INV: u64 = $crate::arithmetic::inv_u64(MODULUS.0[0]).wrapping_neg();
+
+                /// R = 2^(64 * NUM_LIMBS) mod p
+                const R: $field = $field(bigint::pow2_mod(64 * $field::NUM_LIMBS, &MODULUS.0));
+
+                /// R^2 mod p
+                const R2: $field =
+                    $field(bigint::pow2_mod(2 * 64 * $field::NUM_LIMBS, &MODULUS.0));
+
+                /// R^3 mod p
+                const R3: $field =
+                    $field(bigint::pow2_mod(3 * 64 * $field::NUM_LIMBS, &MODULUS.0));
+
+                const GENERATOR: $field = {
+                    let mut limbs = [0; $field::NUM_LIMBS];
+                    limbs[0] = $generator;
+                    $field::from_raw(limbs)
+                };
+
+                /// The 2-adicity `S` of p - 1, where t * 2^S + 1 = p with t odd.
+                const S: u32 = bigint::trailing_zeros(&bigint::sub_u64(&MODULUS.0, 1));
+
+                /// (t - 1) / 2
+                const T_MINUS1_OVER2: [u64; $field::NUM_LIMBS] =
+                    bigint::shr(&bigint::sub_u64(&MODULUS.0, 1), S + 1);
+
+                /// GENERATOR^t, a primitive 2^S root of unity.
+                const ROOT_OF_UNITY: $field =
+                    GENERATOR.pow_const(&bigint::shr(&bigint::sub_u64(&MODULUS.0, 1), S));
+
+                /// ROOT_OF_UNITY^(2^S - 1), its inverse.
+                const ROOT_OF_UNITY_INV: $field =
+                    ROOT_OF_UNITY.pow_const(&bigint::ones::<{ $field::NUM_LIMBS }>(S));
+
+                /// (p + 1) / 2
+                const TWO_INV: $field =
+                    $field::from_raw(bigint::add_u64(&bigint::shr(&MODULUS.0, 1), 1));
+
+                /// GENERATOR^(2^S)
+                const DELTA: $field = {
+                    let mut res = GENERATOR;
+                    let mut i = 0;
+                    while i < S {
+                        res = res.square();
+                        i += 1;
+                    }
+                    res
+                };
+
+                /// GENERATOR^((p - 1) / 3), an element of multiplicative order 3,
+                /// if p = 1 mod 3.
+                const ZETA: Option<$field> = {
+                    let (exp, rem) = bigint::div_rem_u64(&bigint::sub_u64(&MODULUS.0, 1), 3);
+                    if rem == 0 {
+                        Some(GENERATOR.pow_const(&exp))
+                    } else {
+                        None
+                    }
+                };
+
+                impl_binops_additive!($field, $field);
+                impl_binops_multiplicative!($field, $field);
+                field_common!(
+                    $field,
+                    MODULUS,
+                    INV,
+                    MODULUS_STR,
+                    TWO_INV,
+                    ROOT_OF_UNITY_INV,
+                    DELTA,
+                    ZETA,
+                    R,
+                    R2,
+                    R3
+                );
+                impl_sum_prod!($field);
+                field_arithmetic!($field, MODULUS, INV, dense);
+
+                impl $field {
+                    pub const fn size() -> usize {
+                        $field::NUM_LIMBS * 8
+                    }
+
+                    /// Width in bytes of the input of `from_uniform_bytes`, twice
+                    /// that of the modulus but with at least 128 bits to spare.
+                    const UNIFORM_BYTES: usize = if $field::NUM_LIMBS < 2 {
+                        ($field::NUM_LIMBS + 2) * 8
+                    } else {
+                        $field::NUM_LIMBS * 16
+                    };
+
+                    /// Computes `self^exp` for a little-endian exponent. This is
+                    /// variable time, and meant for deriving constants.
+                    const fn pow_const<const M: usize>(&self, exp: &[u64; M]) -> $field {
+                        let mut res = R;
+                        let mut i = 64 * M;
+                        while i > 0 {
+                            i -= 1;
+                            res = res.square();
+                            if (exp[i / 64] >> (i % 64)) & 1 == 1 {
+                                res = (&res).mul(self);
+                            }
+                        }
+                        res
+                    }
+                }
+
+                impl Field for $field {
+                    const ZERO: Self = Self::zero();
+                    const ONE: Self = Self::one();
+
+                    fn random(mut rng: impl RngCore) -> Self {
+                        let mut random_bytes = [0; $field::UNIFORM_BYTES];
+                        rng.fill_bytes(&mut random_bytes[..]);
+
+                        Self::from_uniform_bytes(&random_bytes)
+                    }
+
+                    fn double(&self) -> Self {
+                        self.double()
+                    }
+
+                    #[inline(always)]
+                    fn square(&self) -> Self {
+                        self.square()
+                    }
+
+                    /// Computes the square root of this element, if it exists.
+                    fn sqrt(&self) -> CtOption<Self> {
+                        ff::helpers::sqrt_tonelli_shanks(self, &T_MINUS1_OVER2)
+                    }
+
+                    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+                        ff::helpers::sqrt_ratio_generic(num, div)
+                    }
+
+                    /// Computes the multiplicative inverse of this element,
+                    /// failing if the element is zero.
+                    fn invert(&self) -> CtOption<Self> {
+                        self.invert_safegcd()
+                    }
+                }
+
+                impl PrimeField for $field {
+                    type Repr = [<$field Bytes>];
+
+                    const NUM_BITS: u32 = bigint::num_bits(&MODULUS.0);
+                    const CAPACITY: u32 = Self::NUM_BITS - 1;
+                    const MODULUS: &'static str = MODULUS_STR;
+                    const MULTIPLICATIVE_GENERATOR: Self = GENERATOR;
+                    const ROOT_OF_UNITY: Self = ROOT_OF_UNITY;
+                    const ROOT_OF_UNITY_INV: Self = ROOT_OF_UNITY_INV;
+                    const TWO_INV: Self = TWO_INV;
+                    const DELTA: Self = DELTA;
+                    const S: u32 = S;
+
+                    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+                        let mut tmp = Self::zero();
+                        for (limb, chunk) in tmp.0.iter_mut().zip(repr.0.chunks_exact(8)) {
+                            *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+                        }
+                        let is_some = Choice::from(Self::is_less_than(&tmp.0, &MODULUS.0) as u8);
+
+                        // Convert to Montgomery form by computing
+                        // (a.R^0 * R^2) / R = a.R
+                        tmp *= &R2;
+
+                        CtOption::new(tmp, is_some)
+                    }
+
+                    fn to_repr(&self) -> Self::Repr {
+                        // Turn into canonical form by computing
+                        // (a.R) / R = a
+                        let mut wide = [0; 2 * $field::NUM_LIMBS];
+                        wide[..$field::NUM_LIMBS].copy_from_slice(&self.0);
+                        let tmp = Self::montgomery_reduce(&wide);
+
+                        let mut res = [<$field Bytes>]::default();
+                        for (chunk, limb) in res.0.chunks_exact_mut(8).zip(tmp.0.iter()) {
+                            chunk.copy_from_slice(&limb.to_le_bytes());
+                        }
+                        res
+                    }
+
+                    fn is_odd(&self) -> Choice {
+                        Choice::from(self.to_repr().0[0] & 1)
+                    }
+                }
+
+                impl FromUniformBytes<{ $field::UNIFORM_BYTES }> for $field {
+                    /// Converts a little endian integer of `UNIFORM_BYTES` bytes
+                    /// into a field element by reducing by the modulus.
+                    fn from_uniform_bytes(bytes: &[u8; $field::UNIFORM_BYTES]) -> Self {
+                        let mut limbs = [0; $field::UNIFORM_BYTES / 8];
+                        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+                            *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+                        }
+                        Self::from_wide(&limbs)
+                    }
+                }
+
+                impl HashToField for $field {
+                    // ceil((NUM_BITS + 128) / 8)
+                    const HASH_LEN: usize = (Self::NUM_BITS as usize + 128 - 1) / 8 + 1;
+
+                    fn hash_to_field(
+                        suite: &str,
+                        domain_prefix: &str,
+                        message: &[u8],
+                        buf: &mut [Self],
+                    ) {
+                        hash_to_field(suite, domain_prefix, message, buf)
+                    }
+
+                    fn sgn0(&self) -> Choice {
+                        self.is_odd()
+                    }
+                }
+
+                impl WithSmallOrderMulGroup<3> for $field {
+                    // Only evaluated when used, so that fields with p = 2 mod 3 can
+                    // still be defined.
+                    const ZETA: Self = match ZETA {
+                        Some(zeta) => zeta,
+                        None => panic!("the field has no element of multiplicative order 3"),
+                    };
+                }
+
+                #[derive(Clone, Copy, Debug)]
+                pub struct [<$field Bytes>]([u8; $field::NUM_LIMBS * 8]);
+
+                impl Default for [<$field Bytes>] {
+                    fn default() -> Self {
+                        Self([0u8; $field::NUM_LIMBS * 8])
+                    }
+                }
+
+                impl AsMut<[u8]> for [<$field Bytes>] {
+                    fn as_mut(&mut self) -> &mut [u8] {
+                        &mut self.0
+                    }
+                }
+
+                impl AsRef<[u8]> for [<$field Bytes>] {
+                    fn as_ref(&self) -> &[u8] {
+                        &self.0
+                    }
+                }
+
+                #[cfg(test)]
+                mod test {
+                    use super::*;
+                    use num_bigint::BigUint;
+
+                    fn to_biguint(limbs: &[u64]) -> BigUint {
+                        let bytes: Vec<u8> = limbs.iter().flat_map(|l| l.to_le_bytes()).collect();
+                        BigUint::from_bytes_le(&bytes)
+                    }
+
+                    #[test]
+                    fn test_constants() {
+                        let modulus = to_biguint(&MODULUS.0);
+                        let hex = MODULUS_STR.trim_start_matches("0x");
+                        assert_eq!(BigUint::parse_bytes(hex.as_bytes(), 16).unwrap(), modulus);
+                        assert_eq!($field::NUM_BITS as u64, modulus.bits());
+
+                        // Montgomery constants
+                        assert_eq!(MODULUS.0[0].wrapping_mul(INV), u64::MAX);
+                        let r = BigUint::from(1u8) << (64 * $field::NUM_LIMBS);
+                        assert_eq!(to_biguint(&R.0), &r % &modulus);
+                        assert_eq!(to_biguint(&R2.0), r.pow(2) % &modulus);
+                        assert_eq!(to_biguint(&R3.0), r.pow(3) % &modulus);
+
+                        let p_minus_1 = &modulus - 1u8;
+                        assert_eq!(p_minus_1.trailing_zeros(), Some(S as u64));
+                        let t = &p_minus_1 >> S;
+                        assert_eq!(to_biguint(&T_MINUS1_OVER2), &t >> 1u32);
+
+                        // GENERATOR is a quadratic non-residue.
+                        let half = (&p_minus_1 >> 1u32).to_u64_digits();
+                        assert_eq!(GENERATOR.pow_vartime(&half), -$field::ONE);
+
+                        // ROOT_OF_UNITY is a primitive 2^S root of unity.
+                        assert_eq!(ROOT_OF_UNITY, GENERATOR.pow_vartime(&t.to_u64_digits()));
+                        let two_s_minus_1 = (BigUint::from(1u8) << (S - 1)).to_u64_digits();
+                        assert_eq!(ROOT_OF_UNITY.pow_vartime(&two_s_minus_1), -$field::ONE);
+                        assert_eq!(ROOT_OF_UNITY * ROOT_OF_UNITY_INV, $field::ONE);
+
+                        assert_eq!(TWO_INV.double(), $field::ONE);
+                        let two_s = (BigUint::from(1u8) << S).to_u64_digits();
+                        assert_eq!(DELTA, GENERATOR.pow_vartime(&two_s));
+
+                        if &modulus % 3u8 == BigUint::from(1u8) {
+                            let zeta = ZETA.unwrap();
+                            assert_ne!(zeta, $field::ONE);
+                            assert_eq!(zeta.square() * zeta, $field::ONE);
+                        } else {
+                            assert!(ZETA.is_none());
+                        }
+                    }
+
+                    #[test]
+                    fn test_field() {
+                        $crate::tests::field::random_field_tests::<$field>(
+                            stringify!($field).to_string(),
+                        );
+                    }
+
+                    #[test]
+                    fn test_serialization() {
+                        $crate::tests::field::random_serialization_test::<$field>(
+                            stringify!($field).to_string(),
+                        );
+                        #[cfg(feature = "derive_serde")]
+                        $crate::tests::field::random_serde_test::<$field>(
+                            stringify!($field).to_string(),
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use define_prime_field;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::arithmetic::inv_u64;
+    use crate::ff::{FromUniformBytes, PrimeField};
+    use core::mem::size_of;
+    use rand_core::{OsRng, RngCore};
+
+    mod goldilocks {
+        define_prime_field!(Fp, "0xffffffff00000001", generator = 7);
+    }
+
+    mod bn256 {
+        define_prime_field!(
+            Fr,
+            "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001",
+            generator = 7
+        );
+    }
+
+    mod secp256k1 {
+        define_prime_field!(
+            Fp,
+            "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+            generator = 3
+        );
+    }
+
+    mod bls12_381 {
+        define_prime_field!(
+            Fq,
+            "0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab",
+            generator = 2
+        );
+    }
+
+    /// Checks that two implementations of the same field agree on their
+    /// constants and arithmetic.
+    fn test_same_field<const N: usize, F, G>()
+    where
+        F: FromUniformBytes<N>,
+        G: FromUniformBytes<N>,
+    {
+        fn eq<F: PrimeField, G: PrimeField>(a: F, b: G) -> bool {
+            a.to_repr().as_ref() == b.to_repr().as_ref()
+        }
+
+        assert_eq!(F::MODULUS, G::MODULUS);
+        assert_eq!(F::NUM_BITS, G::NUM_BITS);
+        assert_eq!(F::CAPACITY, G::CAPACITY);
+        assert_eq!(F::S, G::S);
+        assert!(eq(F::MULTIPLICATIVE_GENERATOR, G::MULTIPLICATIVE_GENERATOR));
+        assert!(eq(F::ROOT_OF_UNITY, G::ROOT_OF_UNITY));
+        assert!(eq(F::ROOT_OF_UNITY_INV, G::ROOT_OF_UNITY_INV));
+        assert!(eq(F::TWO_INV, G::TWO_INV));
+        assert!(eq(F::DELTA, G::DELTA));
+
+        for _ in 0..1000 {
+            let mut bytes = [0; N];
+            OsRng.fill_bytes(&mut bytes);
+            let (a, b) = (F::from_uniform_bytes(&bytes), G::from_uniform_bytes(&bytes));
+            OsRng.fill_bytes(&mut bytes);
+            let (c, d) = (F::from_uniform_bytes(&bytes), G::from_uniform_bytes(&bytes));
+
+            assert!(eq(a, b));
+            assert!(eq(a + c, b + d));
+            assert!(eq(a - c, b - d));
+            assert!(eq(a * c, b * d));
+            assert!(eq(a.invert().unwrap(), b.invert().unwrap()));
+            assert_eq!(
+                bool::from(a.sqrt().is_some()),
+                bool::from(b.sqrt().is_some())
+            );
+            assert!(eq(F::from_repr(a.to_repr()).unwrap(), b));
+        }
+    }
+
+    #[test]
+    fn test_defined_fields() {
+        test_same_field::<64, bn256::Fr, crate::bn256::Fr>();
+        test_same_field::<64, secp256k1::Fp, crate::secp256k1::Fp>();
+        test_same_field::<96, bls12_381::Fq, crate::bls12_381::Fq>();
+
+        assert_eq!(goldilocks::Fp::size(), 8);
+        assert_eq!(size_of::<goldilocks::FpBytes>(), 8);
+        assert_eq!(size_of::<bn256::FrBytes>(), 32);
+        assert_eq!(size_of::<secp256k1::FpBytes>(), 32);
+        assert_eq!(size_of::<bls12_381::FqBytes>(), 48);
+    }
+
+    #[test]
+    fn test_bigint_helpers() {
+        const P: [u64; 2] = from_hex("0x00f00000000000000001");
+        assert_eq!(P, [1, 0xf0]);
+        assert_eq!(num_limbs("0x00f00000000000000001"), 2);
+        assert_eq!(num_limbs("0xffffffff00000001"), 1);
+        assert_eq!(num_bits(&P), 72);
+        assert_eq!(trailing_zeros(&sub_u64(&P, 1)), 68);
+        assert_eq!(shr(&P, 4), [0, 0xf]);
+        assert_eq!(add_u64(&[u64::MAX, 0], 1), [0, 1]);
+        assert_eq!(ones::<2>(70), [u64::MAX, 0x3f]);
+        assert_eq!(div_rem_u64(&[0, 1], 3), ([0x5555_5555_5555_5555, 0], 1));
+
+        const GOLDILOCKS: u64 = 0xffff_ffff_0000_0001;
+        assert_eq!(GOLDILOCKS.wrapping_mul(inv_u64(GOLDILOCKS)), 1);
+        assert_eq!(pow2_mod(10, &[1009]), [15]);
+        // 2^128 = (2^32 - 1)^2 = -2^32 mod 2^64 - 2^32 + 1
+        assert_eq!(pow2_mod(128, &[GOLDILOCKS]), [GOLDILOCKS - (1 << 32)]);
+    }
+}
@@ -0,0 +1,480 @@
+//! Number theoretic transforms over prime fields.
+//!
+//! Domains whose size is a power of two use an in-place radix-2 Cooley-Tukey
+//! transform. Other sizes dividing `p - 1` use a mixed-radix Cooley-Tukey
+//! transform, which is needed for fields such as `secp256k1::Fq` whose
+//! 2-adicity is too small for power-of-two domains of useful size.
+//!
+//! With the `multicore` feature large butterfly groups of the radix-2
+//! transform and large sub-transforms of the mixed-radix one are run in
+//! parallel.
+//!
+//! Field elements are assumed to have a little endian `PrimeField::Repr`, as
+//! all fields of this crate do.
+
+use ff::PrimeField;
+
+/// Butterfly groups and sub-transforms smaller than this are not split across
+/// threads.
+#[cfg(feature = "multicore")]
+const MIN_PARALLEL_LEN: usize = 1 << 10;
+
+/// Computes the discrete Fourier transform of `a` in place, where `omega` is
+/// a primitive `2^log_n`-th root of unity and `a` has `2^log_n` elements.
+///
+/// The coefficient `a[i]` is taken as the coefficient of `X^i` and is replaced
+/// by the evaluation at `omega^i`.
+pub fn best_fft<F: PrimeField>(a: &mut [F], omega: F, log_n: u32) {
+    let n = a.len();
+    assert_eq!(n, 1 << log_n);
+    if n == 1 {
+        return;
+    }
+
+    for i in 0..n {
+        let rev = i.reverse_bits() >> (usize::BITS - log_n);
+        if i < rev {
+            a.swap(i, rev);
+        }
+    }
+
+    let mut twiddles = Vec::with_capacity(n / 2);
+    let mut w = F::ONE;
+    for _ in 0..n / 2 {
+        twiddles.push(w);
+        w *= omega;
+    }
+
+    for s in 1..=log_n {
+        let half = 1 << (s - 1);
+        let stride = n >> s;
+        let butterflies = |chunk: &mut [F]| {
+            let (lo, hi) = chunk.split_at_mut(half);
+            let butterfly = |j: usize, (u, v): (&mut F, &mut F)| {
+                let t = *v * twiddles[j * stride];
+                *v = *u - t;
+                *u += t;
+            };
+
+            #[cfg(feature = "multicore")]
+            if half >= MIN_PARALLEL_LEN {
+                use rayon::prelude::*;
+                lo.par_iter_mut()
+                    .zip(hi.par_iter_mut())
+                    .enumerate()
+                    .for_each(|(j, pair)| butterfly(j, pair));
+                return;
+            }
+
+            lo.iter_mut()
+                .zip(hi.iter_mut())
+                .enumerate()
+                .for_each(|(j, pair)| butterfly(j, pair));
+        };
+
+        #[cfg(feature = "multicore")]
+        {
+            use rayon::prelude::*;
+            a.par_chunks_mut(2 * half).for_each(butterflies);
+        }
+        #[cfg(not(feature = "multicore"))]
+        a.chunks_mut(2 * half).for_each(butterflies);
+    }
+}
+
+/// Writes the discrete Fourier transform of `input[0], input[stride], ...`
+/// to `output`, where `omega` is a primitive root of unity of order
+/// `output.len()`, the product of `radices`.
+///
+/// With `n = r * m` for the first radix `r`, the `r` interleaved
+/// subsequences are transformed recursively, giving `Y_k` for `k < r`, and
+/// `X[j + s * m] = sum_k (omega^(k * j) * Y_k[j]) * (omega^m)^(k * s)` is
+/// a DFT of size `r` for every `j < m`, computed naively in `O(r^2)`.
+fn mixed_radix_fft<F: PrimeField>(
+    input: &[F],
+    stride: usize,
+    output: &mut [F],
+    omega: F,
+    radices: &[usize],
+) {
+    let n = output.len();
+    let (r, radices) = match radices.split_first() {
+        Some((r, radices)) => (*r, radices),
+        None => {
+            output[0] = input[0];
+            return;
+        }
+    };
+    let m = n / r;
+
+    let omega_r = omega.pow_vartime([r as u64]);
+    let sub_fft = |(k, chunk): (usize, &mut [F])| {
+        mixed_radix_fft(&input[k * stride..], stride * r, chunk, omega_r, radices)
+    };
+    let mut sub = vec![F::ZERO; n];
+    #[cfg(feature = "multicore")]
+    if m >= MIN_PARALLEL_LEN {
+        use rayon::prelude::*;
+        sub.par_chunks_mut(m).enumerate().for_each(sub_fft);
+    } else {
+        sub.chunks_mut(m).enumerate().for_each(sub_fft);
+    }
+    #[cfg(not(feature = "multicore"))]
+    sub.chunks_mut(m).enumerate().for_each(sub_fft);
+
+    combine(&sub, output, omega, r);
+}
+
+/// Combines the `r` transforms of size `m` laid out one after the other in
+/// `sub` into the transform of size `r * m` in `output`.
+fn combine<F: PrimeField>(sub: &[F], output: &mut [F], omega: F, r: usize) {
+    let n = output.len();
+    let m = n / r;
+
+    // Powers of omega^m, a primitive r-th root of unity.
+    let omega_m = omega.pow_vartime([m as u64]);
+    let mut roots = Vec::with_capacity(r);
+    let mut w = F::ONE;
+    for _ in 0..r {
+        roots.push(w);
+        w *= omega_m;
+    }
+
+    let mut t = vec![F::ZERO; r];
+    let mut omega_j = F::ONE;
+    for j in 0..m {
+        let mut w = F::ONE;
+        for (k, t) in t.iter_mut().enumerate() {
+            *t = sub[k * m + j] * w;
+            w *= omega_j;
+        }
+        for s in 0..r {
+            output[j + s * m] = t
+                .iter()
+                .enumerate()
+                .fold(F::ZERO, |acc, (k, t)| acc + *t * roots[(k * s) % r]);
+        }
+        omega_j *= omega;
+    }
+}
+
+/// Returns `p - 1` as little endian limbs.
+fn modulus_minus_one<F: PrimeField>() -> Vec<u64> {
+    let repr = (-F::ONE).to_repr();
+    repr.as_ref()
+        .chunks(8)
+        .map(|chunk| {
+            let mut limb = [0u8; 8];
+            limb[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(limb)
+        })
+        .collect()
+}
+
+/// Divides the little endian limbs `a` by `d`, returning the quotient and the
+/// remainder.
+fn div_rem_small(a: &[u64], d: u64) -> (Vec<u64>, u64) {
+    let mut quotient = vec![0u64; a.len()];
+    let mut rem = 0u128;
+    for (q, limb) in quotient.iter_mut().zip(a.iter()).rev() {
+        let cur = (rem << 64) | *limb as u128;
+        *q = (cur / d as u128) as u64;
+        rem = cur % d as u128;
+    }
+    (quotient, rem as u64)
+}
+
+/// Returns the prime factors of `n` with multiplicity, in increasing order.
+fn prime_factors(mut n: usize) -> Vec<usize> {
+    let mut factors = vec![];
+    let mut d = 2;
+    while d * d <= n {
+        let (quotient, rem) = (n / d, n % d);
+        if rem == 0 {
+            factors.push(d);
+            n = quotient;
+        } else {
+            d += 1;
+        }
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+/// The multiplicative subgroup of order `n` of a prime field, on which
+/// polynomials of degree less than `n` are evaluated and interpolated.
+///
+/// The domain is generated by `omega = MULTIPLICATIVE_GENERATOR^((p - 1) / n)`,
+/// which for powers of two is the matching power of `ROOT_OF_UNITY`. Coset
+/// transforms evaluate on `g * <omega>` with `g = MULTIPLICATIVE_GENERATOR`,
+/// which lies outside of every proper subgroup.
+///
+/// Sizes that are not powers of two are transformed with one step per prime
+/// factor of `n`, each costing `O(n * q)` for the prime `q`, so they are only
+/// practical when `n` is smooth.
+#[derive(Clone, Debug)]
+pub struct EvaluationDomain<F: PrimeField> {
+    n: usize,
+    omega: F,
+    omega_inv: F,
+    n_inv: F,
+    coset_shift: F,
+    coset_shift_inv: F,
+    /// Prime factors of `n`, empty for powers of two, which use the radix-2
+    /// transform.
+    radices: Vec<usize>,
+}
+
+impl<F: PrimeField> EvaluationDomain<F> {
+    /// Returns the domain of size `n`, or `None` if `n` is zero or does not
+    /// divide `p - 1`.
+    pub fn new(n: usize) -> Option<Self> {
+        if n == 0 {
+            return None;
+        }
+
+        let (omega, radices) = if n.is_power_of_two() {
+            let log_n = n.trailing_zeros();
+            if log_n > F::S {
+                return None;
+            }
+            let mut omega = F::ROOT_OF_UNITY;
+            for _ in log_n..F::S {
+                omega = omega.square();
+            }
+            (omega, vec![])
+        } else {
+            let (exp, rem) = div_rem_small(&modulus_minus_one::<F>(), n as u64);
+            if rem != 0 {
+                return None;
+            }
+            (
+                F::MULTIPLICATIVE_GENERATOR.pow_vartime(exp),
+                prime_factors(n),
+            )
+        };
+
+        Some(EvaluationDomain {
+            n,
+            omega,
+            omega_inv: omega.invert().unwrap(),
+            n_inv: F::from(n as u64).invert().unwrap(),
+            coset_shift: F::MULTIPLICATIVE_GENERATOR,
+            coset_shift_inv: F::MULTIPLICATIVE_GENERATOR.invert().unwrap(),
+            radices,
+        })
+    }
+
+    /// Returns the size of the domain.
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the generator `omega` of the domain.
+    pub fn omega(&self) -> F {
+        self.omega
+    }
+
+    /// Returns the inverse of `omega`.
+    pub fn omega_inv(&self) -> F {
+        self.omega_inv
+    }
+
+    /// Returns the shift `g` of the coset `g * <omega>`.
+    pub fn coset_shift(&self) -> F {
+        self.coset_shift
+    }
+
+    /// Returns the elements `omega^i` of the domain in order.
+    pub fn elements(&self) -> Vec<F> {
+        powers(F::ONE, self.omega, self.n)
+    }
+
+    fn transform(&self, a: &mut [F], omega: F) {
+        assert_eq!(a.len(), self.n);
+        if self.radices.is_empty() {
+            best_fft(a, omega, self.n.trailing_zeros());
+        } else {
+            let input = a.to_vec();
+            mixed_radix_fft(&input, 1, a, omega, &self.radices);
+        }
+    }
+
+    /// Replaces the coefficients `a` of a polynomial of degree less than `n`
+    /// by its evaluations at `omega^i`.
+    pub fn fft(&self, a: &mut [F]) {
+        self.transform(a, self.omega);
+    }
+
+    /// Replaces the evaluations `a` at `omega^i` of a polynomial of degree
+    /// less than `n` by its coefficients.
+    pub fn ifft(&self, a: &mut [F]) {
+        self.transform(a, self.omega_inv);
+        scale(a, F::ONE, self.n_inv);
+    }
+
+    /// Replaces the coefficients `a` of a polynomial of degree less than `n`
+    /// by its evaluations at `g * omega^i`.
+    pub fn coset_fft(&self, a: &mut [F]) {
+        scale(a, self.coset_shift, F::ONE);
+        self.fft(a);
+    }
+
+    /// Replaces the evaluations `a` at `g * omega^i` of a polynomial of degree
+    /// less than `n` by its coefficients.
+    pub fn coset_ifft(&self, a: &mut [F]) {
+        self.transform(a, self.omega_inv);
+        scale(a, self.coset_shift_inv, self.n_inv);
+    }
+}
+
+/// Returns `[c, c * x, ..., c * x^(n - 1)]`.
+fn powers<F: PrimeField>(c: F, x: F, n: usize) -> Vec<F> {
+    let mut res = Vec::with_capacity(n);
+    let mut acc = c;
+    for _ in 0..n {
+        res.push(acc);
+        acc *= x;
+    }
+    res
+}
+
+/// Multiplies `a[i]` by `c * x^i`.
+fn scale<F: PrimeField>(a: &mut [F], x: F, c: F) {
+    crate::arithmetic::parallelize(a, |a, start| {
+        let mut acc = c * x.pow_vartime([start as u64]);
+        for a in a.iter_mut() {
+            *a *= acc;
+            acc *= x;
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::{best_fft, prime_factors, EvaluationDomain};
+    use crate::bn256::Fr;
+    use crate::secp256k1::Fq;
+    use ff::{Field, PrimeField};
+    use rand_core::OsRng;
+
+    fn naive_dft<F: PrimeField>(a: &[F], omega: F) -> Vec<F> {
+        let mut x = F::ONE;
+        (0..a.len())
+            .map(|_| {
+                let eval = a.iter().rev().fold(F::ZERO, |acc, c| acc * x + c);
+                x *= omega;
+                eval
+            })
+            .collect()
+    }
+
+    fn run_domain_test<F: PrimeField>(n: usize) {
+        let domain = EvaluationDomain::<F>::new(n).unwrap();
+        assert_eq!(domain.size(), n);
+
+        // omega has order exactly n.
+        let omega = domain.omega();
+        assert_eq!(omega.pow_vartime([n as u64]), F::ONE);
+        for q in prime_factors(n) {
+            assert_ne!(omega.pow_vartime([(n / q) as u64]), F::ONE);
+        }
+        assert_eq!(omega * domain.omega_inv(), F::ONE);
+
+        let coeffs: Vec<F> = (0..n).map(|_| F::random(OsRng)).collect();
+
+        let mut evals = coeffs.clone();
+        domain.fft(&mut evals);
+        assert_eq!(evals, naive_dft(&coeffs, omega));
+        domain.ifft(&mut evals);
+        assert_eq!(evals, coeffs);
+
+        let mut coset_evals = coeffs.clone();
+        domain.coset_fft(&mut coset_evals);
+        let shift = domain.coset_shift();
+        for (x, eval) in domain.elements().iter().zip(coset_evals.iter()) {
+            let x = shift * x;
+            assert_eq!(
+                *eval,
+                coeffs.iter().rev().fold(F::ZERO, |acc, c| acc * x + c)
+            );
+        }
+        domain.coset_ifft(&mut coset_evals);
+        assert_eq!(coset_evals, coeffs);
+    }
+
+    #[test]
+    fn test_radix2_fft() {
+        for log_n in 0..8 {
+            run_domain_test::<Fr>(1 << log_n);
+        }
+
+        let log_n = 12;
+        let domain = EvaluationDomain::<Fr>::new(1 << log_n).unwrap();
+        let coeffs: Vec<Fr> = (0..1 << log_n).map(|_| Fr::random(OsRng)).collect();
+        let mut a = coeffs.clone();
+        best_fft(&mut a, domain.omega(), log_n);
+        best_fft(&mut a, domain.omega_inv(), log_n);
+        let n_inv = Fr::from(1 << log_n).invert().unwrap();
+        assert!(a.iter().zip(coeffs.iter()).all(|(a, c)| *a * n_inv == *c));
+
+        // The largest domain is generated by ROOT_OF_UNITY.
+        assert_eq!(
+            EvaluationDomain::<Fr>::new(1 << Fr::S).unwrap().omega(),
+            Fr::ROOT_OF_UNITY
+        );
+    }
+
+    #[test]
+    fn test_mixed_radix_fft() {
+        // r - 1 = 2^28 * 3^2 * 13 * 29 * ...
+        for n in [3, 9, 13, 6, 24, 36, 29 * 3, 13 * 29 * 4] {
+            run_domain_test::<Fr>(n);
+        }
+        // q - 1 = 2^6 * 3 * 149 * 631 * ...
+        for n in [2, 3, 6, 64, 192, 149, 631, 2 * 3 * 149, 4 * 631] {
+            run_domain_test::<Fq>(n);
+        }
+    }
+
+    #[test]
+    fn test_unsupported_domains() {
+        assert!(EvaluationDomain::<Fq>::new(0).is_none());
+        assert!(EvaluationDomain::<Fq>::new(128).is_none());
+        assert!(EvaluationDomain::<Fq>::new(5).is_none());
+        assert!(EvaluationDomain::<Fq>::new(9).is_none());
+        assert!(EvaluationDomain::<Fr>::new(1 << (Fr::S + 1)).is_none());
+        assert!(EvaluationDomain::<Fr>::new(27).is_none());
+    }
+
+    #[test]
+    fn test_polynomial_multiplication() {
+        // A mixed-radix domain of size 3 * 2^6.
+        let domain = EvaluationDomain::<Fq>::new(192).unwrap();
+        let a: Vec<Fq> = (0..96).map(|_| Fq::random(OsRng)).collect();
+        let b: Vec<Fq> = (0..96).map(|_| Fq::random(OsRng)).collect();
+
+        let mut expected = vec![Fq::ZERO; 192];
+        for (i, a) in a.iter().enumerate() {
+            for (j, b) in b.iter().enumerate() {
+                expected[i + j] += *a * b;
+            }
+        }
+
+        let mut a_evals = a.clone();
+        a_evals.resize(192, Fq::ZERO);
+        let mut b_evals = b.clone();
+        b_evals.resize(192, Fq::ZERO);
+        domain.coset_fft(&mut a_evals);
+        domain.coset_fft(&mut b_evals);
+        let mut product: Vec<Fq> = a_evals
+            .iter()
+            .zip(b_evals.iter())
+            .map(|(a, b)| *a * b)
+            .collect();
+        domain.coset_ifft(&mut product);
+        assert_eq!(product, expected);
+    }
+}
@@ -0,0 +1,116 @@
+//! Scalar multiplication of a fixed base point using precomputed multiples.
+//!
+//! The scalar is recoded into signed digits of `WINDOW` bits and the table
+//! holds `[j]([2^(WINDOW * i)]P)` for every window `i` and `1 <= j <=
+//! 2^(WINDOW - 1)`, so a multiplication is one table lookup and one mixed
+//! addition per window and needs no doublings.
+
+use crate::arithmetic::{lookup_ct, recode_signed_ct};
+use crate::msm::signed_digits;
+use crate::CurveAffineExt;
+use ff::PrimeField;
+use group::{Curve, Group};
+
+/// Width in bits of the signed digits.
+const WINDOW: usize = 6;
+
+/// Precomputed multiples of a base point for fast scalar multiplication.
+#[derive(Clone, Debug)]
+pub struct FixedBaseTable<C: CurveAffineExt> {
+    /// `windows[i][j] = [(j + 1) * 2^(WINDOW * i)]P`
+    windows: Vec<Vec<C>>,
+}
+
+impl<C: CurveAffineExt> FixedBaseTable<C> {
+    /// Builds the table of multiples of `base`.
+    pub fn new(base: C) -> Self {
+        let num_windows = Self::num_windows();
+        let window_len = 1 << (WINDOW - 1);
+
+        let mut multiples = Vec::with_capacity(num_windows * window_len);
+        let mut window_base = base.to_curve();
+        for _ in 0..num_windows {
+            let mut acc = window_base;
+            multiples.push(acc);
+            for _ in 1..window_len {
+                acc += window_base;
+                multiples.push(acc);
+            }
+            // [2^(WINDOW - 1)]B doubled once gives the base of the next window.
+            window_base = acc.double();
+        }
+
+        let mut affine = vec![C::identity(); multiples.len()];
+        C::Curve::batch_normalize(&multiples, &mut affine);
+        FixedBaseTable {
+            windows: affine.chunks(window_len).map(|w| w.to_vec()).collect(),
+        }
+    }
+
+    /// Number of windows, with one extra bit for the carry out of the top
+    /// window.
+    fn num_windows() -> usize {
+        C::Scalar::NUM_BITS as usize / WINDOW + 1
+    }
+
+    /// Multiplies the base point by `scalar` in constant time.
+    pub fn mul(&self, scalar: &C::Scalar) -> C::Curve {
+        let mut digits = vec![0; Self::num_windows()];
+        recode_signed_ct(scalar.to_repr().as_ref(), WINDOW, &mut digits);
+
+        let mut acc = C::Curve::identity();
+        for (window, digit) in self.windows.iter().zip(digits.iter()) {
+            acc += lookup_ct(window, *digit);
+        }
+        acc
+    }
+
+    /// Multiplies the base point by `scalar` in variable time.
+    ///
+    /// This is not constant time and must only be used with public scalars.
+    pub fn mul_vartime(&self, scalar: &C::Scalar) -> C::Curve {
+        let mut digits = vec![0; Self::num_windows()];
+        signed_digits(scalar.to_repr().as_ref(), WINDOW, &mut digits);
+
+        let mut acc = C::Curve::identity();
+        for (window, digit) in self.windows.iter().zip(digits.iter()) {
+            if *digit > 0 {
+                acc += window[(*digit - 1) as usize];
+            } else if *digit < 0 {
+                acc -= window[(-*digit - 1) as usize];
+            }
+        }
+        acc
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FixedBaseTable;
+    use crate::bn256::{G1Affine, G2Affine, G1_GENERATOR_TABLE, G2_GENERATOR_TABLE};
+    use crate::secp256k1::{Secp256k1Affine, SECP256K1_GENERATOR_TABLE};
+    use crate::CurveAffineExt;
+    use ff::Field;
+    use group::{Curve, Group};
+    use rand_core::OsRng;
+
+    fn run_fixed_base_test<C: CurveAffineExt>(table: &FixedBaseTable<C>, base: C) {
+        let mut scalars = vec![C::Scalar::ZERO, C::Scalar::ONE, -C::Scalar::ONE];
+        scalars.extend((0..20).map(|_| C::Scalar::random(OsRng)));
+        for scalar in scalars {
+            let expected = base * scalar;
+            assert_eq!(table.mul(&scalar), expected);
+            assert_eq!(table.mul_vartime(&scalar), expected);
+        }
+    }
+
+    #[test]
+    fn test_fixed_base_table() {
+        run_fixed_base_test(&G1_GENERATOR_TABLE, G1Affine::generator());
+        run_fixed_base_test(&G2_GENERATOR_TABLE, G2Affine::generator());
+        run_fixed_base_test(&SECP256K1_GENERATOR_TABLE, Secp256k1Affine::generator());
+
+        let base = crate::bn256::G1::random(OsRng).to_affine();
+        run_fixed_base_test(&FixedBaseTable::new(base), base);
+    }
+}
@@ -0,0 +1,267 @@
+use crate::arithmetic::EndoParameters;
+use crate::ff::WithSmallOrderMulGroup;
+use crate::ff::{Field, PrimeField};
+use crate::fixed_base::FixedBaseTable;
+use crate::group::Curve;
+use crate::group::{prime::PrimeCurveAffine, Group, GroupEncoding};
+use crate::grumpkin::{Fq, Fr};
+use crate::hash_to_curve::svdw_hash_to_curve;
+use crate::{Coordinates, CurveAffine, CurveAffineExt, CurveExt};
+use core::cmp;
+use core::fmt::Debug;
+use core::iter::Sum;
+use core::ops::{Add, Mul, Neg, Sub};
+use rand::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+#[cfg(feature = "derive_serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    batch_add, endo, impl_add_binop_specify_output, impl_binops_additive,
+    impl_binops_additive_specify_output, impl_binops_multiplicative,
+    impl_binops_multiplicative_mixed, impl_sub_binop_specify_output, new_curve_impl,
+};
+
+new_curve_impl!(
+    (pub),
+    G1,
+    G1Affine,
+    false,
+    Fq,
+    Fr,
+    (G1_GENERATOR_X, G1_GENERATOR_Y),
+    G1_B,
+    "grumpkin_g1",
+    glv,
+    |domain_prefix| svdw_hash_to_curve::<G1>(G1_SVDW_SUITE, domain_prefix, G1_SVDW_Z),
+);
+
+endo!(G1, Fr, ENDO_PARAMS_GRUMPKIN);
+
+impl CurveAffineExt for G1Affine {
+    batch_add!();
+
+    fn into_coordinates(self) -> (Self::Base, Self::Base) {
+        (self.x, self.y)
+    }
+}
+
+lazy_static::lazy_static! {
+    /// Precomputed multiples of the Grumpkin generator, built on first use.
+    pub static ref G1_GENERATOR_TABLE: FixedBaseTable<G1Affine> =
+        FixedBaseTable::new(G1Affine::generator());
+}
+
+impl group::cofactor::CofactorGroup for G1 {
+    type Subgroup = G1;
+
+    fn clear_cofactor(&self) -> Self {
+        *self
+    }
+
+    fn into_subgroup(self) -> CtOption<Self::Subgroup> {
+        CtOption::new(self, 1.into())
+    }
+
+    fn is_torsion_free(&self) -> Choice {
+        1.into()
+    }
+}
+
+// Grumpkin is y^2 = x^3 - 17 over the scalar field of bn256, with the base
+// field of bn256 as its own scalar field, so that the two curves form a cycle.
+const G1_GENERATOR_X: Fq = Fq::one();
+const G1_GENERATOR_Y: Fq = Fq::from_raw([
+    0x833fc48d823f272c,
+    0x2d270d45f1181294,
+    0xcf135e7506a45d63,
+    0x0000000000000002,
+]);
+const G1_B: Fq = Fq::from_raw([
+    0x43e1f593effffff0,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+]);
+
+/// GLV decomposition parameters for scalars of `G1`, where the endomorphism
+/// acts as multiplication by `Fr::ZETA`.
+const ENDO_PARAMS_GRUMPKIN: EndoParameters = EndoParameters {
+    gamma1: [
+        0xd91d232ec7e0b3d2,
+        0x0000000000000002,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    gamma2: [
+        0x7a7bd9d4391eb18e,
+        0x4ccef014a773d2cf,
+        0x0000000000000002,
+        0x0000000000000000,
+    ],
+    a1: [
+        0x89d3256894d213e2,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    b1: [
+        0xba0ed02b5b2dec1e,
+        0x2833e84879b97090,
+        0xb85045b68181585d,
+        0x30644e72e131a029,
+    ],
+    a2: [
+        0x0be4e1541221250b,
+        0x6f4d8248eeb859fd,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    b2: [
+        0x89d3256894d213e2,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+};
+
+/// Suite identifier of the hash to `G1` suite, following the naming of
+/// RFC 9380 as the RFC does not define one for Grumpkin.
+const G1_SVDW_SUITE: &str = "grumpkin_XMD:SHA-256_SVDW_RO_";
+/// `Z` parameter of the Shallue-van de Woestijne map to `G1`, the first
+/// value accepted by the procedure of RFC 9380, appendix H.1.
+const G1_SVDW_Z: Fq = Fq::one();
+
+#[cfg(test)]
+mod tests {
+    use super::G1_SVDW_SUITE;
+    use crate::grumpkin::{Fq, Fr, G1Affine, G1};
+    use crate::hash_to_curve::hash_to_field;
+    use crate::tests::group::reference_mul;
+    use crate::{CurveAffine, CurveExt};
+    use ff::{Field, PrimeField};
+    use group::{Curve, Group};
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_curve() {
+        crate::tests::curve::curve_tests::<G1>();
+    }
+
+    #[test]
+    fn test_endo_consistency() {
+        crate::tests::curve::endo_consistency_test::<G1>();
+    }
+
+    #[test]
+    fn test_endo_decomposition() {
+        crate::tests::curve::endo_decomposition_test::<G1>();
+    }
+
+    #[test]
+    fn test_serialization() {
+        crate::tests::curve::random_serialization_test::<G1>();
+        #[cfg(feature = "derive_serde")]
+        crate::tests::curve::random_serde_test::<G1>();
+    }
+
+    fn fq_from_hex(hex: &str) -> Fq {
+        let mut repr = <Fq as PrimeField>::Repr::default();
+        for (i, b) in repr.as_mut().iter_mut().rev().enumerate() {
+            *b = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        Fq::from_repr(repr).unwrap()
+    }
+
+    #[test]
+    fn test_hash_to_curve() {
+        crate::tests::curve::hash_to_curve_test::<G1>();
+
+        // Vectors for the suite grumpkin_XMD:SHA-256_SVDW_RO_, with tags built
+        // like those of the RFC 9380 examples. There is no published suite for
+        // Grumpkin: these are self-generated with the same Python script as the
+        // BN254 vectors and only pin the current output.
+        const DOMAIN_PREFIX: &str = "QUUX-V01-CS02-with";
+        let vectors: [(&[u8], [&str; 4]); 5] = [
+            (
+                b"",
+                [
+                    "286d50d026eb0fb371da5d76dd021a18949acf5cdeb3606a0c898b48a4c021cf",
+                    "2a6a6db4fb58abe47aaba2eb5d90d3650ed87aff8505695829e4dde20b625670",
+                    "27aff6ffd2d7594ac576ed76358b2a23622603d5a780f1a753ccfc76d027f90b",
+                    "137a32ce5a1678a15ea77285dbe1bd0936b154bc2acface1522cc73633568414",
+                ],
+            ),
+            (
+                b"abc",
+                [
+                    "270b23313df7cda72afc4d65912840c1ea2b25e20e5935032f38f79c6e6601c4",
+                    "230030ff5a772cbb38545f3436266c59bd4aa14ced2df4d22721b0756d18b46b",
+                    "04655961a025a26dbc3c0324d9c913c6a951b0dd6b4513e11eb86b1a726f7d3a",
+                    "0f1ec4b428acdf15c426f61d906beef4f10382650e3fac9e031becc7abb338ef",
+                ],
+            ),
+            (
+                b"abcdef0123456789",
+                [
+                    "22e66254992080845f23c298e9a218c82b89fe5601b0051abe7647780c464a9f",
+                    "1256b3149537e0fb4574765c198f2c4a086338b587ffdedca18718f2c31797fc",
+                    "09321670edb275610a27bc55af7eafd999ddc02dec2e704b746a3b4a2c63b250",
+                    "1f6ad6aea6b63c175ca81b2aef0aa61c37479d1d82e15a064b825816768c5b82",
+                ],
+            ),
+            (
+                &[b"q128_".as_slice(), &[b'q'; 128]].concat(),
+                [
+                    "07e6e6d8a9bd1ad93a55d0a6f7d279ce2d51f36d10cfe1e7623a1b0c8afc147d",
+                    "1fc11bc385ae2743bc5b55b0edf62299524f521622a76261f04f2b6506347765",
+                    "194930363df3a166583b89df38dd5bfb84c34c121187eca03ad757e80522e865",
+                    "0dac60b175aa74d02366f52f5271601be1679b74b85faadcd5b4bb4b69a63dab",
+                ],
+            ),
+            (
+                &[b"a512_".as_slice(), &[b'a'; 512]].concat(),
+                [
+                    "0f3c666ff2f04b5c39be513ed548b6ab2c6222d9458733b5fa5a3b210f0ec338",
+                    "17c15d7fd66aa39d36d7f06f7db8dff5f93c0a5b50a8215d478c243720377348",
+                    "28ee130bf42c2d466f9b76e85f6c41e3cda76f486c0a0aabb3712bd01bc868b9",
+                    "2bccf011d55e1244b711fab2b0ae9181b1a9deebf8d581d1160b7f58f3e4c919",
+                ],
+            ),
+        ];
+
+        let hasher = G1::hash_to_curve(DOMAIN_PREFIX);
+        for (message, [u0, u1, x, y]) in vectors.iter() {
+            let mut us = [Fq::ZERO; 2];
+            hash_to_field(G1_SVDW_SUITE, DOMAIN_PREFIX, message, &mut us);
+            assert_eq!(us, [fq_from_hex(u0), fq_from_hex(u1)]);
+
+            let expected = G1Affine::from_xy(fq_from_hex(x), fq_from_hex(y)).unwrap();
+            assert_eq!(hasher(message).to_affine(), expected);
+        }
+    }
+
+    #[test]
+    fn test_curve_constants() {
+        // The generator is (1, sqrt(-16)) on y^2 = x^3 - 17.
+        assert_eq!(G1::b(), -Fq::from(17));
+        let g = G1Affine::generator();
+        assert_eq!(g.x, Fq::ONE);
+        assert_eq!(g.y.square(), -Fq::from(16));
+        assert!(bool::from(g.is_on_curve()));
+    }
+
+    #[test]
+    fn test_cycle_with_bn256() {
+        // The base and scalar fields are swapped with respect to bn256.
+        assert_eq!(Fq::MODULUS, crate::bn256::Fr::MODULUS);
+        assert_eq!(Fr::MODULUS, crate::bn256::Fq::MODULUS);
+
+        // The group has prime order, that of the scalar field. The check uses
+        // a plain double-and-add as the GLV multiplication reduces the scalar
+        // modulo the order it is meant to check.
+        let g = G1::random(OsRng);
+        assert_eq!(reference_mul(g, -Fr::ONE) + g, G1::identity());
+    }
+}
@@ -0,0 +1,405 @@
+//! Hashing to elliptic curves as specified in
+//! [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html).
+//!
+//! The suites implemented here use `expand_message_xmd` with SHA-256 and
+//! produce uniformly random points (`_RO_` variants).
+
+use ff::{Field, FromUniformBytes};
+use group::cofactor::CofactorGroup;
+use pasta_curves::arithmetic::CurveExt;
+use sha2::{Digest, Sha256};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+/// Input block size of SHA-256 in bytes.
+const SHA256_BLOCK_SIZE: usize = 64;
+
+/// Output size of SHA-256 in bytes.
+const SHA256_OUTPUT_SIZE: usize = 32;
+
+/// Implements `expand_message_xmd` from section 5.3.1 of RFC 9380 instantiated
+/// with SHA-256.
+pub(crate) fn expand_message_xmd(message: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
+    // `ell = ceil(len_in_bytes / b_in_bytes)` must not exceed 255.
+    assert!(
+        len_in_bytes <= 255 * SHA256_OUTPUT_SIZE,
+        "requested output is too long"
+    );
+    assert!(dst.len() <= 255, "domain separation tag is too long");
+
+    let dst_len = [dst.len() as u8];
+
+    let b_0 = Sha256::new()
+        .chain_update([0u8; SHA256_BLOCK_SIZE])
+        .chain_update(message)
+        .chain_update((len_in_bytes as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+
+    let mut b_i = Sha256::new()
+        .chain_update(b_0)
+        .chain_update([1u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(len_in_bytes + SHA256_OUTPUT_SIZE);
+    uniform_bytes.extend_from_slice(&b_i);
+    let mut i = 1u8;
+    while uniform_bytes.len() < len_in_bytes {
+        i += 1;
+        let mut xored = [0u8; SHA256_OUTPUT_SIZE];
+        for (x, (a, b)) in xored.iter_mut().zip(b_0.iter().zip(b_i.iter())) {
+            *x = a ^ b;
+        }
+        b_i = Sha256::new()
+            .chain_update(xored)
+            .chain_update([i])
+            .chain_update(dst)
+            .chain_update(dst_len)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    uniform_bytes
+}
+
+/// Implements `hash_to_field` from section 5.2 of RFC 9380 for prime fields
+/// with `HashToField::HASH_LEN` of at most `N` bytes, filling `buf` with
+/// `buf.len()` field elements.
+///
+/// The domain separation tag is `domain_prefix || "-" || suite`.
+pub(crate) fn hash_to_field<const N: usize, F: FromUniformBytes<N> + HashToField>(
+    suite: &str,
+    domain_prefix: &str,
+    message: &[u8],
+    buf: &mut [F],
+) {
+    assert!(F::HASH_LEN <= N, "field is too large");
+
+    let dst = [domain_prefix.as_bytes(), b"-", suite.as_bytes()].concat();
+    let uniform_bytes = expand_message_xmd(message, &dst, buf.len() * F::HASH_LEN);
+
+    for (chunk, e) in uniform_bytes.chunks_exact(F::HASH_LEN).zip(buf.iter_mut()) {
+        // The chunk is a big endian integer, reduce it as a little endian one of
+        // `N` bytes.
+        let mut repr = [0u8; N];
+        for (r, b) in repr.iter_mut().zip(chunk.iter().rev()) {
+            *r = *b;
+        }
+        *e = F::from_uniform_bytes(&repr);
+    }
+}
+
+/// Fields that curve points can be hashed over.
+pub(crate) trait HashToField: Field {
+    /// Length `L` in bytes of the uniform string used to derive one element of
+    /// the prime field, `ceil((ceil(log2(p)) + k) / 8)` with `k = 128`.
+    const HASH_LEN: usize;
+
+    /// Implements `hash_to_field` from section 5.2 of RFC 9380, filling `buf`
+    /// with `buf.len()` field elements.
+    fn hash_to_field(suite: &str, domain_prefix: &str, message: &[u8], buf: &mut [Self]);
+
+    /// The `sgn0` function of RFC 9380, section 4.1.
+    fn sgn0(&self) -> Choice;
+}
+
+/// Precomputed constants of the Shallue-van de Woestijne map for a curve
+/// `y^2 = g(x) = x^3 + A * x + B` and a fixed non-zero `Z`.
+///
+/// See section 6.6.1 of RFC 9380.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SvdwConstants<F> {
+    z: F,
+    /// `g(Z)`
+    c1: F,
+    /// `-Z / 2`
+    c2: F,
+    /// `sqrt(-g(Z) * (3 * Z^2 + 4 * A))` with `sgn0(c3) == 0`
+    c3: F,
+    /// `-4 * g(Z) / (3 * Z^2 + 4 * A)`
+    c4: F,
+}
+
+impl<F: HashToField> SvdwConstants<F> {
+    pub(crate) fn new(z: F, a: F, b: F) -> Self {
+        let g = |x: &F| x.square() * x + a * x + b;
+        let z2 = z.square();
+        let three_z2_four_a = z2.double() + z2 + a.double().double();
+
+        let c1 = g(&z);
+        let c2 = -z * F::ONE.double().invert().unwrap();
+        let c3 = (-c1 * three_z2_four_a)
+            .sqrt()
+            .expect("Z is not a valid SvdW parameter");
+        let c3 = F::conditional_select(&c3, &-c3, c3.sgn0());
+        let c4 = -c1.double().double()
+            * three_z2_four_a
+                .invert()
+                .expect("Z is not a valid SvdW parameter");
+
+        SvdwConstants { z, c1, c2, c3, c4 }
+    }
+}
+
+/// Maps a field element to a point on the curve `C` in constant time using the
+/// straight-line Shallue-van de Woestijne method of RFC 9380, section 6.6.1.
+pub(crate) fn svdw_map_to_curve<C>(u: C::Base, c: &SvdwConstants<C::Base>) -> C
+where
+    C: CurveExt,
+    C::Base: HashToField,
+{
+    let g = |x: &C::Base| x.square() * x + C::a() * x + C::b();
+
+    // 1. tv1 = u^2
+    let tv1 = u.square();
+    // 2. tv1 = tv1 * c1
+    let tv1 = tv1 * c.c1;
+    // 3. tv2 = 1 + tv1
+    let tv2 = C::Base::ONE + tv1;
+    // 4. tv1 = 1 - tv1
+    let tv1 = C::Base::ONE - tv1;
+    // 5. tv3 = tv1 * tv2
+    // 6. tv3 = inv0(tv3)
+    let tv3 = (tv1 * tv2).invert().unwrap_or(C::Base::ZERO);
+    // 7. tv4 = u * tv1
+    // 8. tv4 = tv4 * tv3
+    // 9. tv4 = tv4 * c3
+    let tv4 = u * tv1 * tv3 * c.c3;
+    // 10. x1 = c2 - tv4
+    let x1 = c.c2 - tv4;
+    // 11. gx1 = g(x1)
+    // 12. e1 = is_square(gx1)
+    let y1 = g(&x1).sqrt();
+    let e1 = y1.is_some();
+    // 13. x2 = c2 + tv4
+    let x2 = c.c2 + tv4;
+    // 14. gx2 = g(x2)
+    // 15. e2 = is_square(gx2) AND NOT e1
+    let y2 = g(&x2).sqrt();
+    let e2 = y2.is_some() & !e1;
+    // 16. x3 = tv2^2
+    // 17. x3 = x3 * tv3
+    // 18. x3 = x3^2
+    // 19. x3 = x3 * c4
+    // 20. x3 = x3 + Z
+    let x3 = (tv2.square() * tv3).square() * c.c4 + c.z;
+    // One of g(x1), g(x2) and g(x3) is guaranteed to be square.
+    let y3 = g(&x3).sqrt();
+
+    // 21. x = CMOV(x3, x1, e1)
+    // 22. x = CMOV(x, x2, e2)
+    // 23. gx = g(x)
+    // 24. y = sqrt(gx)
+    let x = C::Base::conditional_select(&x3, &x1, e1);
+    let x = C::Base::conditional_select(&x, &x2, e2);
+    let y = C::Base::conditional_select(
+        &y3.unwrap_or(C::Base::ZERO),
+        &y1.unwrap_or(C::Base::ZERO),
+        e1,
+    );
+    let y = C::Base::conditional_select(&y, &y2.unwrap_or(C::Base::ZERO), e2);
+
+    // 25. e3 = sgn0(u) == sgn0(y)
+    // 26. y = CMOV(-y, y, e3)
+    let e3: Choice = u.sgn0().ct_eq(&y.sgn0());
+    let y = C::Base::conditional_select(&-y, &y, e3);
+
+    C::new_jacobian(x, y, C::Base::ONE).unwrap()
+}
+
+/// Returns the `hash_to_curve` function of an RFC 9380 suite using the
+/// Shallue-van de Woestijne map with parameter `z`.
+///
+/// The domain separation tag is `domain_prefix || "-" || suite`.
+#[allow(clippy::type_complexity)]
+pub(crate) fn svdw_hash_to_curve<'a, C>(
+    suite: &'a str,
+    domain_prefix: &'a str,
+    z: C::Base,
+) -> Box<dyn Fn(&[u8]) -> C + 'a>
+where
+    C: CurveExt + CofactorGroup<Subgroup = C>,
+    C::Base: HashToField,
+{
+    let constants = SvdwConstants::new(z, C::a(), C::b());
+    Box::new(move |message| {
+        let mut us = [C::Base::ZERO; 2];
+        C::Base::hash_to_field(suite, domain_prefix, message, &mut us);
+        let q0 = svdw_map_to_curve::<C>(us[0], &constants);
+        let q1 = svdw_map_to_curve::<C>(us[1], &constants);
+        (q0 + q1).clear_cofactor()
+    })
+}
+
+/// Precomputed constants of the simplified Shallue-van de Woestijne-Ulas map
+/// for a curve `y^2 = g(x) = x^3 + A * x + B` with `A * B != 0` and a fixed
+/// non-square `Z`.
+///
+/// See section 6.6.2 of RFC 9380.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SswuConstants<F> {
+    z: F,
+    a: F,
+    b: F,
+    /// `-B / A`
+    c1: F,
+    /// `B / (Z * A)`
+    c2: F,
+}
+
+impl<F: HashToField> SswuConstants<F> {
+    pub(crate) fn new(z: F, a: F, b: F) -> Self {
+        let a_inv = a.invert().expect("A must be non-zero");
+        let z_inv = z.invert().expect("Z must be non-zero");
+
+        let c1 = -b * a_inv;
+        let c2 = b * a_inv * z_inv;
+
+        SswuConstants { z, a, b, c1, c2 }
+    }
+}
+
+/// Maps a field element to the affine coordinates of a point on the curve
+/// described by `c` in constant time using the simplified
+/// Shallue-van de Woestijne-Ulas method of RFC 9380, section 6.6.2.
+pub(crate) fn sswu_map_to_curve<F: HashToField>(u: F, c: &SswuConstants<F>) -> (F, F) {
+    let g = |x: &F| x.square() * x + c.a * x + c.b;
+
+    // 1. tv1 = inv0(Z^2 * u^4 + Z * u^2)
+    let zu2 = c.z * u.square();
+    let tv1 = (zu2.square() + zu2).invert();
+    let exceptional = tv1.is_none();
+    let tv1 = tv1.unwrap_or(F::ZERO);
+    // 2. x1 = (-B / A) * (1 + tv1)
+    // 3. If tv1 == 0, set x1 = B / (Z * A)
+    let x1 = F::conditional_select(&(c.c1 * (F::ONE + tv1)), &c.c2, exceptional);
+    // 4. gx1 = x1^3 + A * x1 + B
+    let y1 = g(&x1).sqrt();
+    let e1 = y1.is_some();
+    // 5. x2 = Z * u^2 * x1
+    // 6. gx2 = x2^3 + A * x2 + B
+    // If g(x1) is not square, g(x2) is guaranteed to be.
+    let x2 = zu2 * x1;
+    let y2 = g(&x2).sqrt();
+
+    // 7. If is_square(gx1), set x = x1 and y = sqrt(gx1)
+    // 8. Else set x = x2 and y = sqrt(gx2)
+    let x = F::conditional_select(&x2, &x1, e1);
+    let y = F::conditional_select(&y2.unwrap_or(F::ZERO), &y1.unwrap_or(F::ZERO), e1);
+
+    // 9. If sgn0(u) != sgn0(y), set y = -y
+    let e3: Choice = u.sgn0().ct_eq(&y.sgn0());
+    let y = F::conditional_select(&-y, &y, e3);
+
+    (x, y)
+}
+
+/// Coefficients of an isogeny `(x, y) -> (x_num / x_den, y * y_num / y_den)`
+/// in increasing degree, as listed in RFC 9380, appendix E, that is
+/// `k_(1, i)`, `k_(2, i)`, `k_(3, i)` and `k_(4, i)`. The leading coefficients
+/// of `x_den` and `y_den` are `1` and omitted.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Isogeny<F: 'static> {
+    pub(crate) x_num: &'static [F],
+    pub(crate) x_den: &'static [F],
+    pub(crate) y_num: &'static [F],
+    pub(crate) y_den: &'static [F],
+}
+
+/// Evaluates the isogeny `iso` at the affine point `(x, y)` into the curve
+/// `C`.
+///
+/// Exceptional inputs where a denominator vanishes are sent to the identity.
+pub(crate) fn iso_map<C: CurveExt>(x: C::Base, y: C::Base, iso: &Isogeny<C::Base>) -> C {
+    // Horner's rule, where monic polynomials start from their implicit
+    // leading coefficient.
+    let horner = |coeffs: &[C::Base], leading: C::Base| {
+        coeffs
+            .iter()
+            .rev()
+            .fold(leading, |acc, coeff| acc * x + coeff)
+    };
+
+    let x_num = horner(iso.x_num, C::Base::ZERO);
+    let x_den = horner(iso.x_den, C::Base::ONE);
+    let y_num = horner(iso.y_num, C::Base::ZERO);
+    let y_den = horner(iso.y_den, C::Base::ONE);
+
+    // Jacobian coordinates with `Z = x_den * y_den`.
+    let z = x_den * y_den;
+    let x_den_y_den2 = x_den * y_den.square();
+    let x = x_num * x_den_y_den2;
+    let y = y * y_num * x_den.square() * x_den_y_den2;
+
+    C::new_jacobian(x, y, z).unwrap()
+}
+
+/// Returns the `hash_to_curve` function of an RFC 9380 suite using the
+/// simplified SWU map onto the curve `y^2 = x^3 + iso_a * x + iso_b` followed
+/// by the isogeny `iso` to `C`.
+///
+/// The domain separation tag is `domain_prefix || "-" || suite`.
+#[allow(clippy::type_complexity)]
+pub(crate) fn sswu_hash_to_curve<'a, C>(
+    suite: &'a str,
+    domain_prefix: &'a str,
+    z: C::Base,
+    iso_a: C::Base,
+    iso_b: C::Base,
+    iso: Isogeny<C::Base>,
+) -> Box<dyn Fn(&[u8]) -> C + 'a>
+where
+    C: CurveExt + CofactorGroup<Subgroup = C>,
+    C::Base: HashToField,
+{
+    let constants = SswuConstants::new(z, iso_a, iso_b);
+    Box::new(move |message| {
+        let mut us = [C::Base::ZERO; 2];
+        C::Base::hash_to_field(suite, domain_prefix, message, &mut us);
+        let q0 = sswu_map_to_curve(us[0], &constants);
+        let q1 = sswu_map_to_curve(us[1], &constants);
+        let q0 = iso_map::<C>(q0.0, q0.1, &iso);
+        let q1 = iso_map::<C>(q1.0, q1.1, &iso);
+        (q0 + q1).clear_cofactor()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::expand_message_xmd;
+
+    #[test]
+    fn test_expand_message_xmd() {
+        // Test vectors from RFC 9380, appendix K.1.
+        const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let vectors: [(&[u8], usize, &str); 3] = [
+            (
+                b"",
+                0x20,
+                "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+            ),
+            (
+                b"abc",
+                0x20,
+                "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+            ),
+            (
+                b"",
+                0x80,
+                "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbe\
+                 e0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18\
+                 eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dc\
+                 c541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+            ),
+        ];
+
+        for (message, len, expected) in vectors {
+            let uniform_bytes = expand_message_xmd(message, DST, len);
+            let hex: String = uniform_bytes.iter().map(|b| format!("{:02x}", b)).collect();
+            assert_eq!(hex, expected);
+        }
+    }
+}
@@ -1,8 +1,15 @@
 #![cfg_attr(feature = "asm", feature(asm_const))]
 
 mod arithmetic;
+mod hash_to_curve;
+mod safegcd;
 
+pub mod bls12_381;
 pub mod bn256;
+pub mod fft;
+pub mod fixed_base;
+pub mod grumpkin;
+pub mod msm;
 pub mod pairing;
 pub mod pasta;
 pub mod secp256k1;
@@ -10,7 +17,7 @@ pub mod serde;
 
 #[macro_use]
 mod derive;
-pub use arithmetic::CurveAffineExt;
+pub use arithmetic::{batch_invert, BatchInvertSlice, CurveAffineExt, CurveEndo};
 pub use pasta_curves::arithmetic::{Coordinates, CurveAffine, CurveExt};
 
 // Re-export ff and group to simplify down stream dependencies
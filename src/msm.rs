@@ -0,0 +1,290 @@
+//! Multi-scalar multiplication using the bucket method of Pippenger.
+//!
+//! Scalars are recoded into signed digits so that only `2^(c - 1)` buckets are
+//! needed per window of `c` bits, and the points falling into a bucket are
+//! summed in affine coordinates with `CurveAffineExt::batch_add`, sharing a
+//! single field inversion between all additions of a round.
+//!
+//! With the `multicore` feature the windows are processed in parallel.
+
+use crate::arithmetic::parallelize;
+use crate::CurveAffineExt;
+use ff::PrimeField;
+use group::Group;
+
+/// Marks a base position as negated for `CurveAffineExt::batch_add`.
+const NEGATE_FLAG: u32 = 0x8000_0000;
+
+/// Returns the window size in bits used for an MSM of `n` terms.
+fn window_size(n: usize) -> usize {
+    if n < 4 {
+        1
+    } else if n < 32 {
+        3
+    } else {
+        (f64::from(n as u32)).ln().ceil() as usize
+    }
+}
+
+/// Recodes little endian scalar bytes into `digits.len()` signed digits of `c`
+/// bits, each in `[-2^(c - 1), 2^(c - 1)]`, least significant first.
+pub(crate) fn signed_digits(repr: &[u8], c: usize, digits: &mut [i32]) {
+    let get_bits = |offset: usize| -> i32 {
+        let mut v = 0i32;
+        for i in 0..c {
+            let bit = offset + i;
+            let byte = bit / 8;
+            if byte < repr.len() {
+                v |= (((repr[byte] >> (bit % 8)) & 1) as i32) << i;
+            }
+        }
+        v
+    };
+
+    let half = 1i32 << (c - 1);
+    let mut carry = 0i32;
+    for (w, digit) in digits.iter_mut().enumerate() {
+        let d = get_bits(w * c) + carry;
+        if d > half {
+            carry = 1;
+            *digit = d - (1 << c);
+        } else {
+            carry = 0;
+            *digit = d;
+        }
+    }
+}
+
+/// Sums the points of each bucket, where a bucket is a list of positions into
+/// `bases` optionally tagged with `NEGATE_FLAG`.
+///
+/// Every round adds the points of all buckets pairwise with a single
+/// `batch_add` call, halving the size of each bucket.
+fn accumulate_buckets<C: CurveAffineExt>(bases: &[C], mut buckets: Vec<Vec<u32>>) -> Vec<C> {
+    let get_point = |bases: &[C], position: u32| -> C {
+        let point = bases[(position & !NEGATE_FLAG) as usize];
+        if position & NEGATE_FLAG != 0 {
+            -point
+        } else {
+            point
+        }
+    };
+
+    let mut round_bases: Option<Vec<C>> = None;
+    loop {
+        let src = round_bases.as_deref().unwrap_or(bases);
+        let num_pairs: usize = buckets.iter().map(|bucket| bucket.len() / 2).sum();
+
+        if num_pairs == 0 {
+            return buckets
+                .iter()
+                .map(|bucket| {
+                    bucket
+                        .first()
+                        .map(|position| get_point(src, *position))
+                        .unwrap_or_else(C::identity)
+                })
+                .collect();
+        }
+
+        // Pairs are loaded into the first `num_points` slots and their sums are
+        // written after them.
+        let num_points = 2 * num_pairs;
+        let mut base_positions = Vec::with_capacity(num_points);
+        for bucket in buckets.iter() {
+            base_positions.extend_from_slice(&bucket[..bucket.len() & !1]);
+        }
+        let output_indices: Vec<u32> = (num_points..num_points + num_pairs)
+            .map(|i| i as u32)
+            .collect();
+        let mut points = vec![C::identity(); num_points + num_pairs];
+        C::batch_add::<true, true>(
+            &mut points,
+            &output_indices,
+            num_points,
+            0,
+            src,
+            &base_positions,
+        );
+
+        // The sums come first in the next round, followed by the points left
+        // over from buckets of odd size.
+        let mut next_bases = points.split_off(num_points);
+        let mut sum_idx = 0;
+        for bucket in buckets.iter_mut() {
+            let leftover = if bucket.len() % 2 == 1 {
+                bucket.last().map(|position| get_point(src, *position))
+            } else {
+                None
+            };
+            let len = bucket.len() / 2;
+            bucket.clear();
+            bucket.extend((sum_idx..sum_idx + len).map(|i| i as u32));
+            sum_idx += len;
+            if let Some(point) = leftover {
+                bucket.push(next_bases.len() as u32);
+                next_bases.push(point);
+            }
+        }
+        round_bases = Some(next_bases);
+    }
+}
+
+/// Computes `sum_i coeffs[i] * bases[i]`.
+///
+/// # Panics
+///
+/// Panics if `coeffs` and `bases` have different lengths, or if there are 2^31
+/// or more terms.
+pub fn best_multiexp<C: CurveAffineExt>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve {
+    assert_eq!(coeffs.len(), bases.len());
+    assert!(bases.len() < NEGATE_FLAG as usize, "too many terms");
+
+    let c = window_size(bases.len());
+    // `ceil((NUM_BITS + 1) / c)`, with one extra bit for the carry out of the
+    // top window.
+    let num_windows = C::Scalar::NUM_BITS as usize / c + 1;
+
+    // The digits of all scalars are stored in a single buffer, `num_windows`
+    // per scalar.
+    let mut digits = vec![0i32; coeffs.len() * num_windows];
+    {
+        let mut rows: Vec<&mut [i32]> = digits.chunks_mut(num_windows).collect();
+        parallelize(&mut rows, |rows, start| {
+            for (row, coeff) in rows.iter_mut().zip(coeffs[start..].iter()) {
+                signed_digits(coeff.to_repr().as_ref(), c, row);
+            }
+        });
+    }
+
+    // Returns `sum_i digits[i][w] * bases[i]`.
+    let window_sum = |w: usize| -> C::Curve {
+        let mut buckets = vec![Vec::new(); 1 << (c - 1)];
+        for (i, digits) in digits.chunks(num_windows).enumerate() {
+            let digit = digits[w];
+            if digit > 0 {
+                buckets[(digit - 1) as usize].push(i as u32);
+            } else if digit < 0 {
+                buckets[(-digit - 1) as usize].push(i as u32 | NEGATE_FLAG);
+            }
+        }
+        let buckets = accumulate_buckets(bases, buckets);
+
+        // sum_j (j + 1) * buckets[j]
+        let mut sum = C::Curve::identity();
+        let mut running_sum = C::Curve::identity();
+        for bucket in buckets.into_iter().rev() {
+            running_sum += bucket;
+            sum += running_sum;
+        }
+        sum
+    };
+
+    #[cfg(feature = "multicore")]
+    let window_sums: Vec<C::Curve> = {
+        use rayon::prelude::*;
+        (0..num_windows).into_par_iter().map(window_sum).collect()
+    };
+    #[cfg(not(feature = "multicore"))]
+    let window_sums: Vec<C::Curve> = (0..num_windows).map(window_sum).collect();
+
+    window_sums
+        .into_iter()
+        .rev()
+        .fold(C::Curve::identity(), |mut acc, sum| {
+            for _ in 0..c {
+                acc = acc.double();
+            }
+            acc + sum
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{best_multiexp, signed_digits};
+    use crate::bn256::{Fr, G1Affine, G2Affine};
+    use crate::grumpkin;
+    use crate::secp256k1::Secp256k1Affine;
+    use crate::CurveAffineExt;
+    use ff::{Field, PrimeField};
+    use group::{Curve, Group};
+    use rand_core::{OsRng, RngCore};
+
+    fn naive_multiexp<C: CurveAffineExt>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve {
+        coeffs
+            .iter()
+            .zip(bases.iter())
+            .fold(C::Curve::identity(), |acc, (coeff, base)| {
+                acc + *base * *coeff
+            })
+    }
+
+    fn run_multiexp_test<C: CurveAffineExt>() {
+        for n in [0, 1, 2, 3, 4, 5, 31, 32, 33, 100, 257] {
+            let bases: Vec<C> = (0..n)
+                .map(|_| C::CurveExt::random(OsRng).to_affine())
+                .collect();
+            let coeffs: Vec<C::Scalar> = (0..n).map(|_| C::Scalar::random(OsRng)).collect();
+            assert_eq!(
+                best_multiexp(&coeffs, &bases),
+                naive_multiexp(&coeffs, &bases)
+            );
+        }
+
+        // Repeated bases, their negations, the identity and edge scalars
+        // exercise the doubling and cancellation paths of `batch_add`.
+        let p = C::CurveExt::random(OsRng).to_affine();
+        let q = C::CurveExt::random(OsRng).to_affine();
+        let bases = vec![p, p, -p, q, C::identity(), p, q, -q];
+        let coeffs = vec![
+            C::Scalar::ONE,
+            C::Scalar::ONE,
+            C::Scalar::ONE,
+            -C::Scalar::ONE,
+            C::Scalar::random(OsRng),
+            C::Scalar::ZERO,
+            C::Scalar::random(OsRng),
+            C::Scalar::random(OsRng),
+        ];
+        assert_eq!(
+            best_multiexp(&coeffs, &bases),
+            naive_multiexp(&coeffs, &bases)
+        );
+    }
+
+    #[test]
+    fn test_signed_digits() {
+        for c in 1..16 {
+            let num_windows = Fr::NUM_BITS as usize / c + 1;
+            for _ in 0..100 {
+                let mut repr = [0u8; 32];
+                OsRng.fill_bytes(&mut repr[..31]);
+                let mut digits = vec![0; num_windows];
+                signed_digits(&repr, c, &mut digits);
+
+                let half = 1i32 << (c - 1);
+                assert!(digits.iter().all(|d| -half <= *d && *d <= half));
+
+                let scalar = Fr::from_repr(repr).unwrap();
+                let radix = Fr::from(1 << c);
+                let recomposed = digits.iter().rev().fold(Fr::ZERO, |acc, d| {
+                    let d = if *d < 0 {
+                        -Fr::from(d.unsigned_abs() as u64)
+                    } else {
+                        Fr::from(*d as u64)
+                    };
+                    acc * radix + d
+                });
+                assert_eq!(scalar, recomposed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_multiexp() {
+        run_multiexp_test::<G1Affine>();
+        run_multiexp_test::<G2Affine>();
+        run_multiexp_test::<Secp256k1Affine>();
+        run_multiexp_test::<grumpkin::G1Affine>();
+    }
+}
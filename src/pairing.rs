@@ -5,6 +5,7 @@ use crate::group::{
 };
 use crate::CurveAffine;
 use core::ops::Mul;
+use subtle::Choice;
 
 pub trait Engine: Sized + 'static + Clone {
     /// This is the scalar field of the engine's groups.
@@ -76,6 +77,41 @@ pub trait MultiMillerLoop: Engine {
     /// Computes $$\sum_{i=1}^n \textbf{ML}(a_i, b_i)$$ given a series of terms
     /// $$(a_1, b_1), (a_2, b_2), ..., (a_n, b_n).$$
     fn multi_miller_loop(terms: &[(&Self::G1Affine, &Self::G2Prepared)]) -> Self::Result;
+
+    /// Computes the same result as [`MultiMillerLoop::multi_miller_loop`],
+    /// splitting the terms across threads when the `multicore` feature is
+    /// enabled and the engine supports it.
+    fn multi_miller_loop_parallel(terms: &[(&Self::G1Affine, &Self::G2Prepared)]) -> Self::Result {
+        Self::multi_miller_loop(terms)
+    }
+
+    /// Checks whether $$\prod_{i=1}^n e(a_i, b_i) = 1$$ given a series of terms
+    /// $$(a_1, b_1), (a_2, b_2), ..., (a_n, b_n).$$
+    ///
+    /// Implementations may return early, so this must only be used when the
+    /// result is public, e.g. when verifying.
+    fn pairing_check(terms: &[(&Self::G1Affine, &Self::G2Prepared)]) -> bool {
+        Self::pairing_check_ct(terms).into()
+    }
+
+    /// Constant time version of [`MultiMillerLoop::pairing_check`].
+    fn pairing_check_ct(terms: &[(&Self::G1Affine, &Self::G2Prepared)]) -> Choice {
+        Self::multi_miller_loop(terms)
+            .final_exponentiation()
+            .is_identity()
+    }
+
+    /// Checks whether $$e(a, b) = e(c, d)$$, computed as the single pairing
+    /// check $$e(a, b) \cdot e(-c, d) = 1$$ sharing one final exponentiation.
+    fn pairing_eq(
+        a: &Self::G1Affine,
+        b: &Self::G2Prepared,
+        c: &Self::G1Affine,
+        d: &Self::G2Prepared,
+    ) -> bool {
+        let neg_c = -*c;
+        Self::pairing_check(&[(a, b), (&neg_c, d)])
+    }
 }
 
 /// Represents results of a Miller loop, one of the most expensive portions of the pairing
@@ -0,0 +1,471 @@
+//! Constant-time modular inversion for odd moduli of any number of 64-bit
+//! limbs, following the "safegcd" algorithm of Bernstein and Yang, "Fast
+//! constant-time gcd computation and modular inversion" (2019), together with
+//! a Jacobi symbol computation over the same integer representation.
+//!
+//! Integers are kept in signed 62-bit limbs so that a batch of divsteps can be
+//! applied to the full values with a 2x2 transition matrix of 64-bit entries.
+//! Up to 256 bits, we use the "half-delta" divsteps and the refined iteration
+//! count of the libsecp256k1 authors: 590 divsteps, performed in ten batches
+//! of 59, are enough for any pair of inputs below `2^256`. Wider moduli use the
+//! original divsteps with the bound of Bernstein and Yang, Theorem 11.2.
+
+/// Mask of the low 62 bits of a limb.
+const M62: u64 = u64::MAX >> 2;
+
+/// Number of batches of half-delta divsteps performed for 256-bit inputs.
+const BATCHES_256: usize = 10;
+
+/// An integer `sum(v[i] * 2^(62*i))` whose limbs are signed. All limbs but the
+/// last are kept in `[0, 2^62)` between operations, the last carries the sign.
+///
+/// `L` must exceed the number of 64-bit limbs of the integers represented.
+#[derive(Clone, Copy, Debug)]
+struct Signed62<const L: usize>([i64; L]);
+
+impl<const L: usize> Signed62<L> {
+    /// Splits a little-endian integer of `N` 64-bit limbs into 62-bit limbs.
+    const fn from_u64s<const N: usize>(a: &[u64; N]) -> Self {
+        let mut res = [0; L];
+        let mut i = 0;
+        while i < L {
+            let (word, shift) = ((62 * i) / 64, (62 * i) % 64);
+            let mut limb = 0;
+            if word < N {
+                limb = a[word] >> shift;
+                if shift > 2 && word + 1 < N {
+                    limb |= a[word + 1] << (64 - shift);
+                }
+            }
+            res[i] = (limb & M62) as i64;
+            i += 1;
+        }
+        Signed62(res)
+    }
+
+    /// Packs non-negative limbs below `2^(64 * N)` back into 64-bit limbs.
+    fn to_u64s<const N: usize>(self) -> [u64; N] {
+        let mut res = [0; N];
+        for (i, &limb) in self.0.iter().enumerate() {
+            let (word, shift) = ((62 * i) / 64, (62 * i) % 64);
+            let limb = limb as u64;
+            if word < N {
+                res[word] |= limb << shift;
+            }
+            if shift > 2 && word + 1 < N {
+                res[word + 1] |= limb >> (64 - shift);
+            }
+        }
+        res
+    }
+}
+
+/// The transition matrix `[[u, v], [q, r]]` of a batch of divsteps, scaled
+/// by `2^62`.
+#[derive(Clone, Copy, Debug)]
+struct Matrix {
+    u: i64,
+    v: i64,
+    q: i64,
+    r: i64,
+}
+
+/// An odd modulus together with the data the inversion needs about it,
+/// stored in `L` signed 62-bit limbs.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Modulus62<const L: usize> {
+    modulus: Signed62<L>,
+    /// `modulus^-1 mod 2^62`
+    modulus_inv62: u64,
+}
+
+impl<const L: usize> Modulus62<L> {
+    /// Prepares an odd modulus given as `N` little-endian limbs, where `N`
+    /// must be less than `L`.
+    pub(crate) const fn new<const N: usize>(modulus: &[u64; N]) -> Self {
+        Modulus62 {
+            modulus: Signed62::from_u64s(modulus),
+            modulus_inv62: crate::arithmetic::inv_u64(modulus[0]) & M62,
+        }
+    }
+}
+
+/// Performs 59 half-delta divsteps on the low 64 bits of `f` and `g`,
+/// returning the updated `zeta = -(delta + 1/2)` and the transition matrix of
+/// the batch.
+///
+/// The matrix starts as the identity times 8 so that the result is scaled
+/// by `2^62` rather than `2^59`. Every step is branch-free.
+fn hddivsteps_59(mut zeta: i64, f0: u64, g0: u64) -> (i64, Matrix) {
+    let (mut u, mut v, mut q, mut r) = (8u64, 0u64, 0u64, 8u64);
+    let (mut f, mut g) = (f0, g0);
+
+    for _ in 3..62 {
+        // Masks for `zeta < 0` and for `g` being odd.
+        let c1 = (zeta >> 63) as u64;
+        let c2 = (g & 1).wrapping_neg();
+
+        // Conditionally negate f, u, v and add them to g, q, r.
+        let x = (f ^ c1).wrapping_sub(c1);
+        let y = (u ^ c1).wrapping_sub(c1);
+        let z = (v ^ c1).wrapping_sub(c1);
+        g = g.wrapping_add(x & c2);
+        q = q.wrapping_add(y & c2);
+        r = r.wrapping_add(z & c2);
+
+        // If both conditions held, the step was a swap: zeta becomes
+        // `-zeta - 2`, and the old g, q, r are recovered into f, u, v.
+        let c1 = c1 & c2;
+        zeta = (zeta ^ c1 as i64) - 1;
+        f = f.wrapping_add(g & c1);
+        u = u.wrapping_add(q & c1);
+        v = v.wrapping_add(r & c1);
+
+        g >>= 1;
+        u <<= 1;
+        v <<= 1;
+    }
+
+    let t = Matrix {
+        u: u as i64,
+        v: v as i64,
+        q: q as i64,
+        r: r as i64,
+    };
+    (zeta, t)
+}
+
+/// Performs 62 of the original divsteps on the low 64 bits of `f` and `g`,
+/// returning the updated `eta = -delta` and the transition matrix of the
+/// batch. Every step is branch-free.
+fn divsteps_62(mut eta: i64, f0: u64, g0: u64) -> (i64, Matrix) {
+    let (mut u, mut v, mut q, mut r) = (1u64, 0u64, 0u64, 1u64);
+    let (mut f, mut g) = (f0, g0);
+
+    for _ in 0..62 {
+        // Masks for `eta < 0` and for `g` being odd.
+        let c1 = (eta >> 63) as u64;
+        let c2 = (g & 1).wrapping_neg();
+
+        // Conditionally negate f, u, v and add them to g, q, r.
+        let x = (f ^ c1).wrapping_sub(c1);
+        let y = (u ^ c1).wrapping_sub(c1);
+        let z = (v ^ c1).wrapping_sub(c1);
+        g = g.wrapping_add(x & c2);
+        q = q.wrapping_add(y & c2);
+        r = r.wrapping_add(z & c2);
+
+        // If both conditions held, the step was a swap: eta becomes
+        // `-eta - 1`, and the old g, q, r are recovered into f, u, v.
+        let c1 = c1 & c2;
+        eta = (eta ^ c1 as i64) - (c1 as i64 + 1);
+        f = f.wrapping_add(g & c1);
+        u = u.wrapping_add(q & c1);
+        v = v.wrapping_add(r & c1);
+
+        g >>= 1;
+        u <<= 1;
+        v <<= 1;
+    }
+
+    let t = Matrix {
+        u: u as i64,
+        v: v as i64,
+        q: q as i64,
+        r: r as i64,
+    };
+    (eta, t)
+}
+
+/// Computes `(t * [d, e]) / 2^62 mod modulus`, keeping `d` and `e` in the
+/// range `(-2 * modulus, modulus)`.
+fn update_de<const L: usize>(
+    d: &mut Signed62<L>,
+    e: &mut Signed62<L>,
+    t: &Matrix,
+    m: &Modulus62<L>,
+) {
+    let Matrix { u, v, q, r } = *t;
+    let (d_in, e_in, modulus) = (d.0, e.0, m.modulus.0);
+
+    // Start [md, me] at zero, add [u, q] if d is negative and [v, r] if e is
+    // negative, so that the results stay within range.
+    let sd = d_in[L - 1] >> 63;
+    let se = e_in[L - 1] >> 63;
+    let mut md = (u & sd) + (v & se);
+    let mut me = (q & sd) + (r & se);
+
+    let mut cd = u as i128 * d_in[0] as i128 + v as i128 * e_in[0] as i128;
+    let mut ce = q as i128 * d_in[0] as i128 + r as i128 * e_in[0] as i128;
+
+    // Correct md, me so that `t * [d, e] + modulus * [md, me]` is divisible
+    // by 2^62.
+    md -= (m
+        .modulus_inv62
+        .wrapping_mul(cd as u64)
+        .wrapping_add(md as u64)
+        & M62) as i64;
+    me -= (m
+        .modulus_inv62
+        .wrapping_mul(ce as u64)
+        .wrapping_add(me as u64)
+        & M62) as i64;
+
+    cd += modulus[0] as i128 * md as i128;
+    ce += modulus[0] as i128 * me as i128;
+    debug_assert_eq!(cd as u64 & M62, 0);
+    debug_assert_eq!(ce as u64 & M62, 0);
+    cd >>= 62;
+    ce >>= 62;
+
+    for i in 1..L {
+        cd += u as i128 * d_in[i] as i128
+            + v as i128 * e_in[i] as i128
+            + modulus[i] as i128 * md as i128;
+        ce += q as i128 * d_in[i] as i128
+            + r as i128 * e_in[i] as i128
+            + modulus[i] as i128 * me as i128;
+        d.0[i - 1] = (cd as u64 & M62) as i64;
+        e.0[i - 1] = (ce as u64 & M62) as i64;
+        cd >>= 62;
+        ce >>= 62;
+    }
+    d.0[L - 1] = cd as i64;
+    e.0[L - 1] = ce as i64;
+}
+
+/// Computes `(t * [f, g]) / 2^62`, which is exact by construction of `t`.
+fn update_fg<const L: usize>(f: &mut Signed62<L>, g: &mut Signed62<L>, t: &Matrix) {
+    let Matrix { u, v, q, r } = *t;
+    let (f_in, g_in) = (f.0, g.0);
+
+    let mut cf = u as i128 * f_in[0] as i128 + v as i128 * g_in[0] as i128;
+    let mut cg = q as i128 * f_in[0] as i128 + r as i128 * g_in[0] as i128;
+    debug_assert_eq!(cf as u64 & M62, 0);
+    debug_assert_eq!(cg as u64 & M62, 0);
+    cf >>= 62;
+    cg >>= 62;
+
+    for i in 1..L {
+        cf += u as i128 * f_in[i] as i128 + v as i128 * g_in[i] as i128;
+        cg += q as i128 * f_in[i] as i128 + r as i128 * g_in[i] as i128;
+        f.0[i - 1] = (cf as u64 & M62) as i64;
+        g.0[i - 1] = (cg as u64 & M62) as i64;
+        cf >>= 62;
+        cg >>= 62;
+    }
+    f.0[L - 1] = cf as i64;
+    g.0[L - 1] = cg as i64;
+}
+
+/// Brings `r` from `(-2 * modulus, modulus)` into `[0, modulus)`, negating it
+/// first if `sign` is negative.
+fn normalize<const L: usize>(r: &mut Signed62<L>, sign: i64, m: &Modulus62<L>) {
+    let modulus = m.modulus.0;
+    let mut r_out = r.0;
+
+    let cond_add = r_out[L - 1] >> 63;
+    for (limb, m) in r_out.iter_mut().zip(modulus.iter()) {
+        *limb += m & cond_add;
+    }
+    let cond_negate = sign >> 63;
+    for limb in r_out.iter_mut() {
+        *limb = (*limb ^ cond_negate) - cond_negate;
+    }
+    carry_62(&mut r_out);
+
+    let cond_add = r_out[L - 1] >> 63;
+    for (limb, m) in r_out.iter_mut().zip(modulus.iter()) {
+        *limb += m & cond_add;
+    }
+    carry_62(&mut r_out);
+
+    r.0 = r_out;
+}
+
+/// Propagates the excess of all limbs but the last upwards so that they fit
+/// in 62 bits.
+fn carry_62<const L: usize>(r: &mut [i64; L]) {
+    for i in 0..L - 1 {
+        r[i + 1] += r[i] >> 62;
+        r[i] &= M62 as i64;
+    }
+}
+
+/// Returns the inverse of `x` modulo `m`, or zero if `x` is zero. The input
+/// must be reduced, i.e. `x < modulus`.
+///
+/// The running time does not depend on `x`.
+pub(crate) fn invert<const N: usize, const L: usize>(x: &[u64; N], m: &Modulus62<L>) -> [u64; N] {
+    let mut d = Signed62([0; L]);
+    let mut e = Signed62([0; L]);
+    e.0[0] = 1;
+    let mut f = m.modulus;
+    let mut g = Signed62::from_u64s(x);
+
+    if N <= 4 {
+        // zeta = -(delta + 1/2) with delta starting at 1/2.
+        let mut zeta = -1;
+        for _ in 0..BATCHES_256 {
+            let (z, t) = hddivsteps_59(zeta, f.0[0] as u64, g.0[0] as u64);
+            zeta = z;
+            update_de(&mut d, &mut e, &t, m);
+            update_fg(&mut f, &mut g, &t);
+        }
+    } else {
+        // floor((49 * d + 57) / 17) divsteps suffice for d-bit inputs, d >= 46.
+        let steps = (49 * 64 * N + 57) / 17;
+        // eta = -delta with delta starting at 1.
+        let mut eta = -1;
+        for _ in 0..steps / 62 + 1 {
+            let (z, t) = divsteps_62(eta, f.0[0] as u64, g.0[0] as u64);
+            eta = z;
+            update_de(&mut d, &mut e, &t, m);
+            update_fg(&mut f, &mut g, &t);
+        }
+    }
+
+    // Now g is zero and f is the gcd up to sign, that is `+-1` unless x was
+    // zero, and d is the matching multiple of the inverse.
+    normalize(&mut d, f.0[L - 1], m);
+    d.to_u64s()
+}
+
+/// Returns the Jacobi symbol `(a / n)` for an odd `n`, as `-1`, `0` or `1`.
+///
+/// This uses the binary algorithm and is variable time.
+pub(crate) fn jacobi<const N: usize>(a: &[u64; N], n: &[u64; N]) -> i64 {
+    let (mut a, mut n) = (*a, *n);
+    let mut symbol = 1;
+
+    loop {
+        if a.iter().all(|&limb| limb == 0) {
+            let is_one = n[0] == 1 && n[1..].iter().all(|&limb| limb == 0);
+            return if is_one { symbol } else { 0 };
+        }
+
+        // (2 / n) = -1 exactly when n = 3 or 5 mod 8.
+        let zeros = shr_trailing_zeros(&mut a);
+        let n_mod_8 = n[0] & 7;
+        if zeros & 1 == 1 && (n_mod_8 == 3 || n_mod_8 == 5) {
+            symbol = -symbol;
+        }
+
+        // Both are odd now; keep a >= n using quadratic reciprocity.
+        if is_less_than(&a, &n) {
+            core::mem::swap(&mut a, &mut n);
+            if a[0] & n[0] & 3 == 3 {
+                symbol = -symbol;
+            }
+        }
+
+        // (a / n) = ((a - n) / n)
+        let mut borrow = false;
+        for (a, n) in a.iter_mut().zip(n.iter()) {
+            let (d, b1) = a.overflowing_sub(*n);
+            let (d, b2) = d.overflowing_sub(borrow as u64);
+            *a = d;
+            borrow = b1 | b2;
+        }
+    }
+}
+
+/// Shifts a nonzero `a` right past its trailing zeros, returning their count.
+fn shr_trailing_zeros<const N: usize>(a: &mut [u64; N]) -> u32 {
+    let words = a.iter().take_while(|&&limb| limb == 0).count();
+    a.rotate_left(words);
+    for limb in a[N - words..].iter_mut() {
+        *limb = 0;
+    }
+
+    let bits = a[0].trailing_zeros();
+    if bits > 0 {
+        for i in 0..N - 1 {
+            a[i] = a[i] >> bits | a[i + 1] << (64 - bits);
+        }
+        a[N - 1] >>= bits;
+    }
+    words as u32 * 64 + bits
+}
+
+fn is_less_than<const N: usize>(x: &[u64; N], y: &[u64; N]) -> bool {
+    x.iter().rev().lt(y.iter().rev())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{invert, jacobi, Modulus62};
+    use crate::bls12_381;
+    use crate::bn256::{Fq, Fr};
+    use crate::secp256k1::Fp;
+    use core::convert::TryInto;
+    use ff::PrimeField;
+    use rand_core::OsRng;
+
+    fn to_u64s<F: PrimeField, const N: usize>(x: &F) -> [u64; N] {
+        let repr = x.to_repr();
+        let mut limbs = [0u64; N];
+        for (limb, bytes) in limbs.iter_mut().zip(repr.as_ref().chunks(8)) {
+            *limb = u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        limbs
+    }
+
+    fn from_u64s<F: PrimeField, const N: usize>(limbs: &[u64; N]) -> F {
+        let mut repr = F::Repr::default();
+        for (bytes, limb) in repr.as_mut().chunks_mut(8).zip(limbs.iter()) {
+            bytes.copy_from_slice(&limb.to_le_bytes());
+        }
+        F::from_repr(repr).unwrap()
+    }
+
+    fn modulus<F: PrimeField, const N: usize>() -> [u64; N] {
+        // p - 1 is even, so adding one does not carry.
+        let mut p = to_u64s(&-F::ONE);
+        p[0] += 1;
+        p
+    }
+
+    fn test_invert<F: PrimeField, const N: usize, const L: usize>() {
+        let m = Modulus62::<L>::new(&modulus::<F, N>());
+
+        assert_eq!(invert(&[0; N], &m), [0; N]);
+
+        let p_minus_one = -F::ONE;
+        let edge_cases = [F::ONE, F::from(2), p_minus_one, p_minus_one.double()];
+        let random = (0..1000).map(|_| F::random(OsRng));
+        for x in edge_cases.iter().cloned().chain(random) {
+            let inv: F = from_u64s(&invert::<N, L>(&to_u64s(&x), &m));
+            assert_eq!(inv * x, F::ONE);
+        }
+    }
+
+    fn test_jacobi<F: PrimeField, const N: usize>() {
+        let p = modulus::<F, N>();
+
+        assert_eq!(jacobi(&[0; N], &p), 0);
+        for _ in 0..1000 {
+            let x = F::random(OsRng);
+            // Euler's criterion
+            let euler = x.pow_vartime(to_u64s::<F, N>(&(-F::ONE * F::TWO_INV)));
+            let expected = if euler == F::ONE { 1 } else { -1 };
+            assert_eq!(jacobi(&to_u64s(&x), &p), expected);
+        }
+    }
+
+    #[test]
+    fn test_safegcd_invert() {
+        test_invert::<Fq, 4, 5>();
+        test_invert::<Fr, 4, 5>();
+        test_invert::<Fp, 4, 5>();
+        test_invert::<bls12_381::Fq, 6, 7>();
+    }
+
+    #[test]
+    fn test_safegcd_jacobi() {
+        test_jacobi::<Fq, 4>();
+        test_jacobi::<Fr, 4>();
+        test_jacobi::<Fp, 4>();
+        test_jacobi::<bls12_381::Fq, 6>();
+    }
+}
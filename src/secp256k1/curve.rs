@@ -1,6 +1,9 @@
+use crate::arithmetic::EndoParameters;
 use crate::ff::WithSmallOrderMulGroup;
 use crate::ff::{Field, PrimeField};
+use crate::fixed_base::FixedBaseTable;
 use crate::group::{prime::PrimeCurveAffine, Curve, Group as _, GroupEncoding};
+use crate::hash_to_curve::{sswu_hash_to_curve, Isogeny};
 use crate::secp256k1::Fp;
 use crate::secp256k1::Fq;
 use crate::{Coordinates, CurveAffine, CurveAffineExt, CurveExt};
@@ -45,8 +48,175 @@ const SECP_GENERATOR_Y: Fp = Fp::from_raw([
 ]);
 const SECP_B: Fp = Fp::from_raw([7, 0, 0, 0]);
 
+/// GLV decomposition parameters for scalars of `Secp256k1`, where the
+/// endomorphism acts as multiplication by `Fq::ZETA`.
+const ENDO_PARAMS_SECP: EndoParameters = EndoParameters {
+    gamma1: [
+        0xe86c90e49284eb15,
+        0x3086d221a7d46bcd,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    gamma2: [
+        0x6f547fa90abfe4c4,
+        0xe4437ed6010e8828,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    a1: [
+        0xe86c90e49284eb15,
+        0x3086d221a7d46bcd,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    b1: [
+        0x507ddee3c5765c7e,
+        0xd66b5e10ae3a1813,
+        0xfffffffffffffffd,
+        0xffffffffffffffff,
+    ],
+    a2: [
+        0x57c1108d9d44cfd8,
+        0x14ca50f7a8e2f3f6,
+        0x0000000000000001,
+        0x0000000000000000,
+    ],
+    b2: [
+        0xe86c90e49284eb15,
+        0x3086d221a7d46bcd,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+};
+
+/// Suite of RFC 9380 used by `Secp256k1::hash_to_curve`.
+const SECP_SSWU_SUITE: &str = "secp256k1_XMD:SHA-256_SSWU_RO_";
+
+/// `Z` parameter of the simplified SWU map for secp256k1, which is `-11`.
+const SECP_SSWU_Z: Fp = Fp::from_raw([
+    0xFFFFFFFEFFFFFC24,
+    0xFFFFFFFFFFFFFFFF,
+    0xFFFFFFFFFFFFFFFF,
+    0xFFFFFFFFFFFFFFFF,
+]);
+
+/// `A'` of the curve E' that is 3-isogenous to secp256k1.
+const ISO_SECP_A: Fp = Fp::from_raw([
+    0x405447C01A444533,
+    0xE953D363CB6F0E5D,
+    0xA08A5558F0F5D272,
+    0x3F8731ABDD661ADC,
+]);
+
+/// `B'` of the curve E' that is 3-isogenous to secp256k1.
+const ISO_SECP_B: Fp = Fp::from_raw([1771, 0, 0, 0]);
+
+/// Coefficients of the 3-isogeny from E' to secp256k1, see appendix E.1 of
+/// RFC 9380.
+const ISO_SECP_MAP: Isogeny<Fp> = Isogeny {
+    x_num: &[
+        // k_(1,0)
+        Fp::from_raw([
+            0x8E38E38DAAAAA8C7,
+            0x38E38E38E38E38E3,
+            0xE38E38E38E38E38E,
+            0x8E38E38E38E38E38,
+        ]),
+        // k_(1,1)
+        Fp::from_raw([
+            0xDFFF1044F17C6581,
+            0xD595D2FC0BF63B92,
+            0xB9F315CEA7FD44C5,
+            0x07D3D4C80BC321D5,
+        ]),
+        // k_(1,2)
+        Fp::from_raw([
+            0x4ECBD0B53D9DD262,
+            0xE4506144037C4031,
+            0xE2A413DECA25CAEC,
+            0x534C328D23F234E6,
+        ]),
+        // k_(1,3)
+        Fp::from_raw([
+            0x8E38E38DAAAAA88C,
+            0x38E38E38E38E38E3,
+            0xE38E38E38E38E38E,
+            0x8E38E38E38E38E38,
+        ]),
+    ],
+    x_den: &[
+        // k_(2,0)
+        Fp::from_raw([
+            0x9FE6B745781EB49B,
+            0x86CD409542F8487D,
+            0x9CA34CCBB7B640DD,
+            0xD35771193D94918A,
+        ]),
+        // k_(2,1)
+        Fp::from_raw([
+            0xC52A56612A8C6D14,
+            0x06D36B641F5E41BB,
+            0xF7C4B2D51B542254,
+            0xEDADC6F64383DC1D,
+        ]),
+    ],
+    y_num: &[
+        // k_(3,0)
+        Fp::from_raw([
+            0xA12F684B8E38E23C,
+            0x2F684BDA12F684BD,
+            0x684BDA12F684BDA1,
+            0x4BDA12F684BDA12F,
+        ]),
+        // k_(3,1)
+        Fp::from_raw([
+            0xDFFC90FC201D71A3,
+            0x647AB046D686DA6F,
+            0xA9D0A54B12A0A6D5,
+            0xC75E0C32D5CB7C0F,
+        ]),
+        // k_(3,2)
+        Fp::from_raw([
+            0xA765E85A9ECEE931,
+            0x722830A201BE2018,
+            0x715209EF6512E576,
+            0x29A6194691F91A73,
+        ]),
+        // k_(3,3)
+        Fp::from_raw([
+            0x84BDA12F38E38D84,
+            0xBDA12F684BDA12F6,
+            0xA12F684BDA12F684,
+            0x2F684BDA12F684BD,
+        ]),
+    ],
+    y_den: &[
+        // k_(4,0)
+        Fp::from_raw([
+            0xFFFFFFFEFFFFF93B,
+            0xFFFFFFFFFFFFFFFF,
+            0xFFFFFFFFFFFFFFFF,
+            0xFFFFFFFFFFFFFFFF,
+        ]),
+        // k_(4,1)
+        Fp::from_raw([
+            0xDFB425D2685C2573,
+            0x9467C1BFC8E8D978,
+            0xD5E9E6632722C298,
+            0x7A06534BB8BDB49F,
+        ]),
+        // k_(4,2)
+        Fp::from_raw([
+            0xA7BF8192BFD2A76F,
+            0x0A3D21162F0D6299,
+            0xF3A70C3FA8FE337E,
+            0x6484AA716545CA2C,
+        ]),
+    ],
+};
+
 use crate::{
-    batch_add, impl_add_binop_specify_output, impl_binops_additive,
+    batch_add, endo, impl_add_binop_specify_output, impl_binops_additive,
     impl_binops_additive_specify_output, impl_binops_multiplicative,
     impl_binops_multiplicative_mixed, impl_sub_binop_specify_output, new_curve_impl,
 };
@@ -61,8 +231,19 @@ new_curve_impl!(
     (SECP_GENERATOR_X,SECP_GENERATOR_Y),
     SECP_B,
     "secp256k1",
+    glv,
+    |domain_prefix| sswu_hash_to_curve::<Secp256k1>(
+        SECP_SSWU_SUITE,
+        domain_prefix,
+        SECP_SSWU_Z,
+        ISO_SECP_A,
+        ISO_SECP_B,
+        ISO_SECP_MAP,
+    ),
 );
 
+endo!(Secp256k1, Fq, ENDO_PARAMS_SECP);
+
 impl CurveAffineExt for Secp256k1Affine {
     batch_add!();
 
@@ -71,6 +252,12 @@ impl CurveAffineExt for Secp256k1Affine {
     }
 }
 
+lazy_static::lazy_static! {
+    /// Precomputed multiples of the secp256k1 generator, built on first use.
+    pub static ref SECP256K1_GENERATOR_TABLE: FixedBaseTable<Secp256k1Affine> =
+        FixedBaseTable::new(Secp256k1Affine::generator());
+}
+
 #[test]
 fn test_curve() {
     crate::tests::curve::curve_tests::<Secp256k1>();
@@ -85,8 +272,154 @@ fn test_serialization() {
 
 #[test]
 fn test_endo_consistency() {
-    let g = Secp256k1::generator();
-    assert_eq!(g * Fq::ZETA, g.endo());
+    crate::tests::curve::endo_consistency_test::<Secp256k1>();
+}
+
+#[test]
+fn test_endo_decomposition() {
+    crate::tests::curve::endo_decomposition_test::<Secp256k1>();
+}
+
+#[cfg(test)]
+fn fp_from_hex(hex: &str) -> Fp {
+    let mut repr = <Fp as PrimeField>::Repr::default();
+    for (i, b) in repr.as_mut().iter_mut().rev().enumerate() {
+        *b = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+    }
+    Fp::from_repr(repr).unwrap()
+}
+
+#[test]
+fn test_hash_to_curve_constants() {
+    // The constants of the map are built with `from_raw`, check them against
+    // their values in RFC 9380, section 8.7 and appendix E.1, parsed with
+    // `from_repr` instead.
+    assert_eq!(SECP_SSWU_Z, -Fp::from(11));
+    assert_eq!(
+        ISO_SECP_A,
+        fp_from_hex("3f8731abdd661adca08a5558f0f5d272e953d363cb6f0e5d405447c01a444533")
+    );
+    assert_eq!(ISO_SECP_B, Fp::from(1771));
+
+    let check = |coeffs: &[Fp], expected: &[&str]| {
+        assert_eq!(coeffs.len(), expected.len());
+        for (c, e) in coeffs.iter().zip(expected.iter()) {
+            assert_eq!(*c, fp_from_hex(e));
+        }
+    };
+    check(
+        ISO_SECP_MAP.x_num,
+        &[
+            // k_(1,0)
+            "8e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38daaaaa8c7",
+            // k_(1,1)
+            "07d3d4c80bc321d5b9f315cea7fd44c5d595d2fc0bf63b92dfff1044f17c6581",
+            // k_(1,2)
+            "534c328d23f234e6e2a413deca25caece4506144037c40314ecbd0b53d9dd262",
+            // k_(1,3)
+            "8e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38daaaaa88c",
+        ],
+    );
+    check(
+        ISO_SECP_MAP.x_den,
+        &[
+            // k_(2,0)
+            "d35771193d94918a9ca34ccbb7b640dd86cd409542f8487d9fe6b745781eb49b",
+            // k_(2,1)
+            "edadc6f64383dc1df7c4b2d51b54225406d36b641f5e41bbc52a56612a8c6d14",
+        ],
+    );
+    check(
+        ISO_SECP_MAP.y_num,
+        &[
+            // k_(3,0)
+            "4bda12f684bda12f684bda12f684bda12f684bda12f684bda12f684b8e38e23c",
+            // k_(3,1)
+            "c75e0c32d5cb7c0fa9d0a54b12a0a6d5647ab046d686da6fdffc90fc201d71a3",
+            // k_(3,2)
+            "29a6194691f91a73715209ef6512e576722830a201be2018a765e85a9ecee931",
+            // k_(3,3)
+            "2f684bda12f684bda12f684bda12f684bda12f684bda12f684bda12f38e38d84",
+        ],
+    );
+    check(
+        ISO_SECP_MAP.y_den,
+        &[
+            // k_(4,0)
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffff93b",
+            // k_(4,1)
+            "7a06534bb8bdb49fd5e9e6632722c2989467c1bfc8e8d978dfb425d2685c2573",
+            // k_(4,2)
+            "6484aa716545ca2cf3a70c3fa8fe337e0a3d21162f0d6299a7bf8192bfd2a76f",
+        ],
+    );
+}
+
+#[test]
+fn test_hash_to_curve() {
+    use crate::hash_to_curve::hash_to_field;
+
+    crate::tests::curve::hash_to_curve_test::<Secp256k1>();
+
+    // Test vectors from RFC 9380, appendix J.8.1.
+    const DOMAIN_PREFIX: &str = "QUUX-V01-CS02-with";
+    let vectors: [(&[u8], [&str; 4]); 5] = [
+        (
+            b"",
+            [
+                "6b0f9910dd2ba71c78f2ee9f04d73b5f4c5f7fc773a701abea1e573cab002fb3",
+                "1ae6c212e08fe1a5937f6202f929a2cc8ef4ee5b9782db68b0d5799fd8f09e16",
+                "c1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346",
+                "64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067",
+            ],
+        ),
+        (
+            b"abc",
+            [
+                "128aab5d3679a1f7601e3bdf94ced1f43e491f544767e18a4873f397b08a2b61",
+                "5897b65da3b595a813d0fdcc75c895dc531be76a03518b044daaa0f2e4689e00",
+                "3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b",
+                "7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6",
+            ],
+        ),
+        (
+            b"abcdef0123456789",
+            [
+                "ea67a7c02f2cd5d8b87715c169d055a22520f74daeb080e6180958380e2f98b9",
+                "7434d0d1a500d38380d1f9615c021857ac8d546925f5f2355319d823a478da18",
+                "bac54083f293f1fe08e4a70137260aa90783a5cb84d3f35848b324d0674b0e3a",
+                "4436476085d4c3c4508b60fcf4389c40176adce756b398bdee27bca19758d828",
+            ],
+        ),
+        (
+            &[b"q128_".as_slice(), &[b'q'; 128]].concat(),
+            [
+                "eda89a5024fac0a8207a87e8cc4e85aa3bce10745d501a30deb87341b05bcdf5",
+                "dfe78cd116818fc2c16f3837fedbe2639fab012c407eac9dfe9245bf650ac51d",
+                "e2167bc785333a37aa562f021f1e881defb853839babf52a7f72b102e41890e9",
+                "f2401dd95cc35867ffed4f367cd564763719fbc6a53e969fb8496a1e6685d873",
+            ],
+        ),
+        (
+            &[b"a512_".as_slice(), &[b'a'; 512]].concat(),
+            [
+                "8d862e7e7e23d7843fe16d811d46d7e6480127a6b78838c277bca17df6900e9f",
+                "68071d2530f040f081ba818d3c7188a94c900586761e9115efa47ae9bd847938",
+                "e3c8d35aaaf0b9b647e88a0a0a7ee5d5bed5ad38238152e4e6fd8c1f8cb7c998",
+                "8446eeb6181bf12f56a9d24e262221cc2f0c4725c7e3803024b5888ee5823aa6",
+            ],
+        ),
+    ];
+
+    let hasher = Secp256k1::hash_to_curve(DOMAIN_PREFIX);
+    for (message, [u0, u1, x, y]) in vectors.iter() {
+        let mut us = [Fp::ZERO; 2];
+        hash_to_field(SECP_SSWU_SUITE, DOMAIN_PREFIX, message, &mut us);
+        assert_eq!(us, [fp_from_hex(u0), fp_from_hex(u1)]);
+
+        let expected = Secp256k1Affine::from_xy(fp_from_hex(x), fp_from_hex(y)).unwrap();
+        assert_eq!(hasher(message).to_affine(), expected);
+    }
 }
 
 #[test]
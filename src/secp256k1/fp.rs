@@ -1,5 +1,6 @@
 use crate::arithmetic::{adc, mac, sbb};
 use crate::ff::{FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
+use crate::hash_to_curve::{hash_to_field, HashToField};
 use core::convert::TryInto;
 use core::fmt;
 use core::ops::{Add, Mul, Neg, Sub};
@@ -140,7 +141,7 @@ impl ff::Field for Fp {
     const ONE: Self = Self::one();
 
     fn random(mut rng: impl RngCore) -> Self {
-        Self::from_u512([
+        Self::from_wide(&[
             rng.next_u64(),
             rng.next_u64(),
             rng.next_u64(),
@@ -176,14 +177,7 @@ impl ff::Field for Fp {
     /// Computes the multiplicative inverse of this element,
     /// failing if the element is zero.
     fn invert(&self) -> CtOption<Self> {
-        let tmp = self.pow_vartime(&[
-            0xfffffffefffffc2d,
-            0xffffffffffffffff,
-            0xffffffffffffffff,
-            0xffffffffffffffff,
-        ]);
-
-        CtOption::new(tmp, !self.ct_eq(&Self::zero()))
+        self.invert_safegcd()
     }
 
     fn pow_vartime<S: AsRef<[u64]>>(&self, exp: S) -> Self {
@@ -275,7 +269,7 @@ impl FromUniformBytes<64> for Fp {
     /// Converts a 512-bit little endian integer into
     /// an `Fp` by reducing by the modulus.
     fn from_uniform_bytes(bytes: &[u8; 64]) -> Self {
-        Self::from_u512([
+        Self::from_wide(&[
             u64::from_le_bytes(bytes[0..8].try_into().unwrap()),
             u64::from_le_bytes(bytes[8..16].try_into().unwrap()),
             u64::from_le_bytes(bytes[16..24].try_into().unwrap()),
@@ -288,6 +282,19 @@ impl FromUniformBytes<64> for Fp {
     }
 }
 
+impl HashToField for Fp {
+    // ceil((256 + 128) / 8)
+    const HASH_LEN: usize = 48;
+
+    fn hash_to_field(suite: &str, domain_prefix: &str, message: &[u8], buf: &mut [Self]) {
+        hash_to_field(suite, domain_prefix, message, buf)
+    }
+
+    fn sgn0(&self) -> Choice {
+        self.is_odd()
+    }
+}
+
 impl WithSmallOrderMulGroup<3> for Fp {
     const ZETA: Self = ZETA;
 }
@@ -296,7 +303,7 @@ impl WithSmallOrderMulGroup<3> for Fp {
 mod test {
     use super::*;
     use ff::Field;
-    use rand_core::OsRng;
+    use rand_core::{OsRng, RngCore};
 
     #[test]
     fn test_sqrt() {
@@ -348,6 +355,33 @@ mod test {
         assert_eq!(Fp::ROOT_OF_UNITY_INV, Fp::ROOT_OF_UNITY.invert().unwrap());
     }
 
+    #[test]
+    fn test_from_raw() {
+        // `from_raw` has a separate constant evaluation path under the `asm`
+        // feature, check it against `from_repr`.
+        // RFC 9380 k_(1,0) of the 3-isogeny to secp256k1, whose top limbs
+        // exercise the final carry of the reduction.
+        let k_1_0 = [
+            0x8e38e38daaaaa8c7,
+            0x38e38e38e38e38e3,
+            0xe38e38e38e38e38e,
+            0x8e38e38e38e38e38,
+        ];
+        for limbs in std::iter::once(k_1_0).chain((0..1000).map(|_| {
+            let mut limbs = [0u64; 4];
+            limbs.iter_mut().for_each(|l| *l = OsRng.next_u64());
+            limbs
+        })) {
+            let mut repr = <Fp as PrimeField>::Repr::default();
+            for (bytes, limb) in repr.as_mut().chunks_exact_mut(8).zip(limbs.iter()) {
+                bytes.copy_from_slice(&limb.to_le_bytes());
+            }
+            if let Some(expected) = Option::<Fp>::from(Fp::from_repr(repr)) {
+                assert_eq!(Fp::from_raw(limbs), expected);
+            }
+        }
+    }
+
     #[test]
     fn test_field() {
         crate::tests::field::random_field_tests::<Fp>("secp256k1 base".to_string());
@@ -152,7 +152,7 @@ impl ff::Field for Fq {
     const ONE: Self = Self::one();
 
     fn random(mut rng: impl RngCore) -> Self {
-        Self::from_u512([
+        Self::from_wide(&[
             rng.next_u64(),
             rng.next_u64(),
             rng.next_u64(),
@@ -176,14 +176,7 @@ impl ff::Field for Fq {
     /// Computes the multiplicative inverse of this element,
     /// failing if the element is zero.
     fn invert(&self) -> CtOption<Self> {
-        let tmp = self.pow_vartime(&[
-            0xbfd25e8cd036413f,
-            0xbaaedce6af48a03b,
-            0xfffffffffffffffe,
-            0xffffffffffffffff,
-        ]);
-
-        CtOption::new(tmp, !self.ct_eq(&Self::zero()))
+        self.invert_safegcd()
     }
 
     fn pow_vartime<S: AsRef<[u64]>>(&self, exp: S) -> Self {
@@ -282,7 +275,7 @@ impl FromUniformBytes<64> for Fq {
     /// Converts a 512-bit little endian integer into
     /// an `Fq` by reducing by the modulus.
     fn from_uniform_bytes(bytes: &[u8; 64]) -> Self {
-        Self::from_u512([
+        Self::from_wide(&[
             u64::from_le_bytes(bytes[0..8].try_into().unwrap()),
             u64::from_le_bytes(bytes[8..16].try_into().unwrap()),
             u64::from_le_bytes(bytes[16..24].try_into().unwrap()),
@@ -303,7 +296,7 @@ impl WithSmallOrderMulGroup<3> for Fq {
 mod test {
     use super::*;
     use ff::Field;
-    use rand_core::OsRng;
+    use rand_core::{OsRng, RngCore};
 
     #[test]
     fn test_sqrt() {
@@ -355,6 +348,33 @@ mod test {
         assert_eq!(Fq::ROOT_OF_UNITY_INV, Fq::ROOT_OF_UNITY.invert().unwrap());
     }
 
+    #[test]
+    fn test_from_raw() {
+        // `from_raw` has a separate constant evaluation path under the `asm`
+        // feature, check it against `from_repr`.
+        // Starts with the modulus minus two, whose top limbs exercise the final
+        // carry of the reduction.
+        for limbs in std::iter::once([
+            0xbfd25e8cd036413f,
+            0xbaaedce6af48a03b,
+            0xfffffffffffffffe,
+            0xffffffffffffffff,
+        ])
+        .chain((0..1000).map(|_| {
+            let mut limbs = [0u64; 4];
+            limbs.iter_mut().for_each(|l| *l = OsRng.next_u64());
+            limbs
+        })) {
+            let mut repr = <Fq as PrimeField>::Repr::default();
+            for (bytes, limb) in repr.as_mut().chunks_exact_mut(8).zip(limbs.iter()) {
+                bytes.copy_from_slice(&limb.to_le_bytes());
+            }
+            if let Some(expected) = Option::<Fq>::from(Fq::from_repr(repr)) {
+                assert_eq!(Fq::from_raw(limbs), expected);
+            }
+        }
+    }
+
     #[test]
     fn test_field() {
         crate::tests::field::random_field_tests::<Fq>("secp256k1 scalar".to_string());
@@ -1,15 +1,18 @@
 #![allow(clippy::eq_op)]
 
-use crate::ff::Field;
+use crate::ff::{Field, PrimeField, WithSmallOrderMulGroup};
 use crate::group::prime::PrimeCurveAffine;
+use crate::tests::group::reference_mul;
 use crate::{group::GroupEncoding, serde::SerdeObject};
-use crate::{CurveAffine, CurveExt};
-use rand_core::OsRng;
+use crate::{CurveAffine, CurveEndo, CurveExt};
+use rand_core::{OsRng, RngCore};
+use subtle::ConditionallySelectable;
 
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
 pub fn curve_tests<G: CurveExt>() {
+    crate::tests::group::group_tests::<G>();
     is_on_curve::<G>();
     equality::<G>();
     projective_to_affine_affine_to_projective::<G>();
@@ -251,9 +254,9 @@ fn batch_normalize<G: CurveExt>() {
     let b = a.double();
     let c = b.double();
 
-    for a_identity in (0..1).map(|n| n == 1) {
-        for b_identity in (0..1).map(|n| n == 1) {
-            for c_identity in (0..1).map(|n| n == 1) {
+    for a_identity in (0..2).map(|n| n == 1) {
+        for b_identity in (0..2).map(|n| n == 1) {
+            for c_identity in (0..2).map(|n| n == 1) {
                 let mut v = [a, b, c];
                 if a_identity {
                     v[0] = G::identity()
@@ -282,9 +285,42 @@ fn batch_normalize<G: CurveExt>() {
             }
         }
     }
+
+    // Enough points to be split into several chunks with `multicore`.
+    let mut acc = G::random(OsRng);
+    let v: Vec<G> = (0..3000)
+        .map(|i| {
+            acc += G::generator();
+            if i % 100 == 0 {
+                G::identity()
+            } else {
+                acc
+            }
+        })
+        .collect();
+    let mut t = vec![G::AffineExt::identity(); v.len()];
+    G::batch_normalize(&v[..], &mut t[..]);
+    for (p, q) in v.iter().zip(t.iter()) {
+        assert_eq!(G::AffineExt::from(*p), *q);
+    }
 }
 
 fn multiplication<G: CurveExt>() {
+    let a = G::random(OsRng);
+    for s in [
+        G::ScalarExt::ZERO,
+        G::ScalarExt::ONE,
+        -G::ScalarExt::ONE,
+        G::ScalarExt::ZETA,
+        -G::ScalarExt::ZETA,
+    ] {
+        assert_eq!(a * s, reference_mul(a, s));
+    }
+    for _ in 0..100 {
+        let s = G::ScalarExt::random(OsRng);
+        assert_eq!(a * s, reference_mul(a, s));
+    }
+
     for _ in 1..1000 {
         let s1 = G::ScalarExt::random(OsRng);
         let s2 = G::ScalarExt::random(OsRng);
@@ -314,3 +350,48 @@ fn multiplication<G: CurveExt>() {
         assert_eq!(t0, t1);
     }
 }
+
+pub fn hash_to_curve_test<G: CurveExt>() {
+    let hasher = G::hash_to_curve("test");
+    let mut rng = OsRng;
+    for _ in 0..1000 {
+        let mut message = vec![0u8; (rng.next_u32() % 128) as usize];
+        rng.fill_bytes(&mut message);
+
+        let p = hasher(&message);
+        assert!(bool::from(p.is_on_curve()));
+        assert!(!bool::from(p.is_identity()));
+        assert_eq!(p, hasher(&message));
+    }
+}
+
+/// Checks that the endomorphism acts as multiplication by `ZETA` on the prime
+/// order subgroup.
+pub fn endo_consistency_test<G: CurveExt>() {
+    let g = G::generator();
+    assert_eq!(g.endo(), reference_mul(g, G::ScalarExt::ZETA));
+    for _ in 0..10 {
+        let p = G::random(OsRng);
+        assert_eq!(p.endo(), reference_mul(p, G::ScalarExt::ZETA));
+    }
+}
+
+pub fn endo_decomposition_test<G: CurveEndo>() {
+    let check = |k: G::ScalarExt| {
+        let (k1, k1_neg, k2, k2_neg) = G::decompose_scalar(&k);
+        let k1 = G::ScalarExt::from_u128(k1);
+        let k2 = G::ScalarExt::from_u128(k2);
+        let k1 = G::ScalarExt::conditional_select(&k1, &-k1, k1_neg);
+        let k2 = G::ScalarExt::conditional_select(&k2, &-k2, k2_neg);
+        assert_eq!(k1 + k2 * G::ScalarExt::ZETA, k);
+    };
+
+    check(G::ScalarExt::ZERO);
+    check(G::ScalarExt::ONE);
+    check(-G::ScalarExt::ONE);
+    check(G::ScalarExt::ZETA);
+    check(G::ScalarExt::TWO_INV);
+    for _ in 0..1000 {
+        check(G::ScalarExt::random(OsRng));
+    }
+}
@@ -0,0 +1,111 @@
+#![allow(clippy::eq_op)]
+
+use crate::ff::{Field, PrimeField};
+use crate::group::Group;
+use rand_core::OsRng;
+
+pub fn group_tests<G: Group>() {
+    generator::<G>();
+    equality::<G>();
+    addition::<G>();
+    multiplication::<G>();
+}
+
+/// Multiplies `p` by `s` with a plain double-and-add over the bits of the
+/// scalar, independently of the group's own scalar multiplication.
+pub fn reference_mul<G: Group>(p: G, s: G::Scalar) -> G {
+    let mut acc = G::identity();
+    for byte in s.to_repr().as_ref().iter().rev() {
+        for i in (0..8).rev() {
+            acc = acc.double();
+            if (byte >> i) & 1 == 1 {
+                acc += p;
+            }
+        }
+    }
+    acc
+}
+
+fn generator<G: Group>() {
+    assert!(!bool::from(G::generator().is_identity()));
+    assert!(bool::from(
+        (reference_mul(G::generator(), -G::Scalar::ONE) + G::generator()).is_identity()
+    ));
+
+    for _ in 0..10 {
+        assert!(!bool::from(G::random(OsRng).is_identity()));
+    }
+}
+
+fn equality<G: Group>() {
+    let a = G::generator();
+    let b = G::identity();
+
+    assert!(a == a);
+    assert!(b == b);
+    assert!(a != b);
+    assert!(b != a);
+
+    for _ in 0..100 {
+        let a = G::random(OsRng);
+        let b = G::random(OsRng);
+
+        assert!(a == a);
+        assert!(b == b);
+        assert!(a != b);
+        assert!(b != a);
+    }
+}
+
+fn addition<G: Group>() {
+    let a = G::identity();
+    let b = G::identity();
+    assert!(bool::from((a + b).is_identity()));
+    assert!(bool::from((a - b).is_identity()));
+    assert!(bool::from((-a).is_identity()));
+    assert!(bool::from(a.double().is_identity()));
+
+    let a = G::random(OsRng);
+    assert!(a == a + G::identity());
+    assert!(a == G::identity() + a);
+    assert!(-a == G::identity() - a);
+    assert!(bool::from((a - a).is_identity()));
+    assert_eq!(a.double(), a + a);
+    assert!(a.double() - a == a);
+
+    let a = G::random(OsRng);
+    let b = G::random(OsRng);
+    let c = G::random(OsRng);
+    assert!(a + b == b + a);
+    assert!(a - b == -(b - a));
+    assert!(c + (a + b) == a + (c + b));
+    assert!((a - b) - c == (a - c) - b);
+
+    let a = G::generator().double().double(); // 4P
+    let b = G::generator().double(); // 2P
+    let c = a + b;
+
+    let mut d = G::generator();
+    for _ in 0..5 {
+        d += G::generator();
+    }
+    assert!(c == d);
+    assert!(!bool::from(c.is_identity()));
+}
+
+fn multiplication<G: Group>() {
+    let a = G::random(OsRng);
+    for s in [G::Scalar::ZERO, G::Scalar::ONE, -G::Scalar::ONE] {
+        assert_eq!(a * s, reference_mul(a, s));
+    }
+
+    for _ in 0..10 {
+        let s1 = G::Scalar::random(OsRng);
+        let s2 = G::Scalar::random(OsRng);
+
+        assert_eq!(a * s1, reference_mul(a, s1));
+        assert!(bool::from((G::identity() * s1).is_identity()));
+        assert_eq!(a * s1 + a * s2, a * (s1 + s2));
+        assert_eq!((a * s1) * s2, a * (s1 * s2));
+    }
+}